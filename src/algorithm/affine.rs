@@ -0,0 +1,273 @@
+use num_traits::Float;
+use types::Point;
+use algorithm::map_coords::MapCoords;
+
+/// A two-dimensional affine transform
+///
+/// A Point `(x, y)` is transformed to `(a * x + b * y + xoff, d * x + e * y + yoff)`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AffineTransform<T>
+    where T: Float
+{
+    pub a: T,
+    pub b: T,
+    pub xoff: T,
+    pub d: T,
+    pub e: T,
+    pub yoff: T,
+}
+
+impl<T> AffineTransform<T>
+    where T: Float
+{
+    /// Creates a transform shifting Points by the given offsets
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::affine::AffineTransform;
+    ///
+    /// assert_eq!(AffineTransform::translate(1., -2.).apply(&Point::new(3., 4.)), Point::new(4., 2.));
+    /// ```
+    pub fn translate(xoff: T, yoff: T) -> AffineTransform<T> {
+        AffineTransform { a: T::one(), b: T::zero(), xoff, d: T::zero(), e: T::one(), yoff }
+    }
+
+    /// Creates a transform scaling Points about the origin, by the given factors along each axis
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::affine::AffineTransform;
+    ///
+    /// assert_eq!(AffineTransform::scale(2., -1.).apply(&Point::new(3., 4.)), Point::new(6., -4.));
+    /// ```
+    pub fn scale(xfact: T, yfact: T) -> AffineTransform<T> {
+        AffineTransform { a: xfact, b: T::zero(), xoff: T::zero(), d: T::zero(), e: yfact, yoff: T::zero() }
+    }
+
+    /// Creates a transform rotating Points about the origin, by an angle given in degrees
+    ///
+    /// Positive angles are counter-clockwise, as for `rotate`.
+    ///
+    /// ```
+    /// # extern crate geo;
+    /// # #[macro_use] extern crate approx;
+    /// # fn main() {
+    /// use geo::Point;
+    /// use geo::algorithm::affine::AffineTransform;
+    ///
+    /// let p = AffineTransform::rotate(90.).apply(&Point::new(1., 0.));
+    /// assert_relative_eq!(p.x(), 0., epsilon = 1e-10);
+    /// assert_relative_eq!(p.y(), 1., epsilon = 1e-10);
+    /// # }
+    /// ```
+    pub fn rotate(angle: T) -> AffineTransform<T> {
+        let (sin, cos) = angle.to_radians().sin_cos();
+        AffineTransform { a: cos, b: -sin, xoff: T::zero(), d: sin, e: cos, yoff: T::zero() }
+    }
+
+    /// Returns the transform applying this one, then another
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::affine::AffineTransform;
+    ///
+    /// // scale by two, then shift right by one
+    /// let transform = AffineTransform::scale(2., 2.).compose(&AffineTransform::translate(1., 0.));
+    /// assert_eq!(transform.apply(&Point::new(3., 4.)), Point::new(7., 8.));
+    /// ```
+    pub fn compose(&self, other: &AffineTransform<T>) -> AffineTransform<T> {
+        AffineTransform {
+            a: other.a * self.a + other.b * self.d,
+            b: other.a * self.b + other.b * self.e,
+            xoff: other.a * self.xoff + other.b * self.yoff + other.xoff,
+            d: other.d * self.a + other.e * self.d,
+            e: other.d * self.b + other.e * self.e,
+            yoff: other.d * self.xoff + other.e * self.yoff + other.yoff,
+        }
+    }
+
+    /// Applies the transform to a Point
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::affine::AffineTransform;
+    ///
+    /// // scale by two, then shift right by one
+    /// let transform = AffineTransform { a: 2., b: 0., xoff: 1., d: 0., e: 2., yoff: 0. };
+    /// assert_eq!(transform.apply(&Point::new(3., 4.)), Point::new(7., 8.));
+    /// ```
+    pub fn apply(&self, p: &Point<T>) -> Point<T> {
+        Point::new(self.a * p.x() + self.b * p.y() + self.xoff,
+                   self.d * p.x() + self.e * p.y() + self.yoff)
+    }
+}
+
+/// Applies an affine transform to a geometry.
+pub trait Transform<T> {
+    /// Applies an affine transform to every coordinate of a Geometry
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::affine::{AffineTransform, Transform};
+    ///
+    /// let ls: LineString<f64> = vec![(0., 0.), (1., 2.)].into();
+    /// let transformed = ls.transform(&AffineTransform::scale(2., 2.).compose(&AffineTransform::translate(1., 0.)));
+    ///
+    /// assert_eq!(transformed, vec![(1., 0.), (3., 4.)].into());
+    /// ```
+    fn transform(&self, transform: &AffineTransform<T>) -> Self where T: Float;
+}
+
+impl<T, G> Transform<T> for G
+    where T: Float,
+        G: MapCoords<T, T, Output=G>
+{
+    fn transform(&self, transform: &AffineTransform<T>) -> Self {
+        self.map_coords(&|&(x, y)| {
+            let p = transform.apply(&Point::new(x, y));
+            (p.x(), p.y())
+        })
+    }
+}
+
+/// Fits an affine transform to pairs of control points
+///
+/// Returns the transform mapping each Point in `from` as closely as possible, in the
+/// least-squares sense, onto the Point at the same index in `to`. This is how a scanned
+/// map is georeferenced from a handful of known locations.
+///
+/// At least three control point pairs are required, and the `from` Points must not all be
+/// collinear. Returns `None` if this isn't the case, or if `from` and `to` differ in length.
+///
+/// ```
+/// # extern crate geo;
+/// # #[macro_use] extern crate approx;
+/// #
+/// use geo::Point;
+/// use geo::algorithm::affine::estimate_affine;
+///
+/// # fn main() {
+/// let from = vec![Point::new(0., 0.), Point::new(1., 0.), Point::new(0., 1.)];
+/// let to = vec![Point::new(10., 20.), Point::new(12., 20.), Point::new(10., 23.)];
+/// let transform = estimate_affine(&from, &to).unwrap();
+///
+/// let p = transform.apply(&Point::new(1., 1.));
+/// assert_relative_eq!(p.x(), 12., epsilon = 1.0e-10);
+/// assert_relative_eq!(p.y(), 23., epsilon = 1.0e-10);
+/// # }
+/// ```
+pub fn estimate_affine<T>(from: &[Point<T>], to: &[Point<T>]) -> Option<AffineTransform<T>>
+    where T: Float
+{
+    if from.len() < 3 || from.len() != to.len() {
+        return None;
+    }
+    let n = T::from(from.len()).unwrap();
+    let mean = |points: &[Point<T>]| {
+        let (sx, sy) = points.iter().fold((T::zero(), T::zero()), |(sx, sy), p| (sx + p.x(), sy + p.y()));
+        (sx / n, sy / n)
+    };
+    let (fx, fy) = mean(from);
+    let (tx, ty) = mean(to);
+    // sums of products of the centred coordinates, for the normal equations
+    let (mut sxx, mut sxy, mut syy) = (T::zero(), T::zero(), T::zero());
+    let (mut sxu, mut syu, mut sxv, mut syv) = (T::zero(), T::zero(), T::zero(), T::zero());
+    for (p, q) in from.iter().zip(to.iter()) {
+        let (x, y) = (p.x() - fx, p.y() - fy);
+        let (u, v) = (q.x() - tx, q.y() - ty);
+        sxx = sxx + x * x;
+        sxy = sxy + x * y;
+        syy = syy + y * y;
+        sxu = sxu + x * u;
+        syu = syu + y * u;
+        sxv = sxv + x * v;
+        syv = syv + y * v;
+    }
+    // the determinant vanishes exactly when the centred points are collinear (or coincident)
+    let det = sxx * syy - sxy * sxy;
+    if det.is_nan() || det <= T::epsilon().sqrt() * sxx * syy {
+        return None;
+    }
+    let a = (sxu * syy - syu * sxy) / det;
+    let b = (syu * sxx - sxu * sxy) / det;
+    let d = (sxv * syy - syv * sxy) / det;
+    let e = (syv * sxx - sxv * sxy) / det;
+    Some(AffineTransform {
+             a,
+             b,
+             xoff: tx - a * fx - b * fy,
+             d,
+             e,
+             yoff: ty - d * fx - e * fy,
+         })
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, Polygon};
+    use algorithm::rotate::RotatePoint;
+    use algorithm::translate::Translate;
+    use super::*;
+
+    #[test]
+    fn compose_rotate_translate_test() {
+        let poly = Polygon::new(vec![(0., 0.), (2., 0.), (2., 1.), (0., 1.), (0., 0.)].into(), vec![]);
+        let transform = AffineTransform::rotate(30.).compose(&AffineTransform::translate(5., -1.));
+        let composed = poly.transform(&transform);
+        let sequential = poly.transform(&AffineTransform::rotate(30.)).transform(&AffineTransform::translate(5., -1.));
+        for (p, q) in composed.exterior.0.iter().zip(&sequential.exterior.0) {
+            assert_relative_eq!(p.x(), q.x(), epsilon = 1e-10);
+            assert_relative_eq!(p.y(), q.y(), epsilon = 1e-10);
+        }
+        // and matches rotating about the origin, then translating
+        let rotated = poly.rotate_around_point(30., &Point::new(0., 0.)).translate(5., -1.);
+        for (p, q) in composed.exterior.0.iter().zip(&rotated.exterior.0) {
+            assert_relative_eq!(p.x(), q.x(), epsilon = 1e-10);
+            assert_relative_eq!(p.y(), q.y(), epsilon = 1e-10);
+        }
+        // the order matters
+        let reversed = AffineTransform::translate(5., -1.).compose(&AffineTransform::rotate(30.));
+        assert!(reversed.xoff != transform.xoff);
+        let scale = AffineTransform::scale(3., 0.5);
+        assert_eq!(scale.compose(&AffineTransform::scale(1., 1.)), scale);
+        assert_eq!(AffineTransform::translate(0., 0.).compose(&scale), scale);
+    }
+
+    #[test]
+    fn estimate_rotate_translate_test() {
+        // rotate by 30 degrees then translate by (100, -50)
+        let (sin, cos) = 30f64.to_radians().sin_cos();
+        let known = AffineTransform { a: cos, b: -sin, xoff: 100., d: sin, e: cos, yoff: -50. };
+        let from = vec![Point::new(0., 0.), Point::new(10., 0.), Point::new(10., 10.), Point::new(3., 7.)];
+        let to: Vec<Point<f64>> = from.iter().map(|p| known.apply(p)).collect();
+        let estimated = estimate_affine(&from, &to).unwrap();
+        assert_relative_eq!(estimated.a, known.a, epsilon = 1e-10);
+        assert_relative_eq!(estimated.b, known.b, epsilon = 1e-10);
+        assert_relative_eq!(estimated.xoff, known.xoff, epsilon = 1e-10);
+        assert_relative_eq!(estimated.d, known.d, epsilon = 1e-10);
+        assert_relative_eq!(estimated.e, known.e, epsilon = 1e-10);
+        assert_relative_eq!(estimated.yoff, known.yoff, epsilon = 1e-10);
+    }
+    #[test]
+    fn estimate_least_squares_test() {
+        // the unit square squeezed horizontally about its centre, x' = 0.8x + 0.1
+        let from = vec![Point::new(0., 0.), Point::new(1., 0.), Point::new(1., 1.), Point::new(0., 1.)];
+        let to = vec![Point::new(0.1, 0.), Point::new(0.9, 0.), Point::new(0.9, 1.), Point::new(0.1, 1.)];
+        let estimated = estimate_affine(&from, &to).unwrap();
+        assert_relative_eq!(estimated.a, 0.8, epsilon = 1e-10);
+        assert_relative_eq!(estimated.xoff, 0.1, epsilon = 1e-10);
+        assert_relative_eq!(estimated.e, 1.0, epsilon = 1e-10);
+        assert_relative_eq!(estimated.b, 0.0, epsilon = 1e-10);
+    }
+    #[test]
+    fn estimate_degenerate_test() {
+        let collinear = vec![Point::new(0., 0.), Point::new(1., 1.), Point::new(2., 2.)];
+        assert!(estimate_affine(&collinear, &collinear).is_none());
+        let two = vec![Point::new(0., 0.), Point::new(1., 0.)];
+        assert!(estimate_affine(&two, &two).is_none());
+        let three = vec![Point::new(0., 0.), Point::new(1., 0.), Point::new(0., 1.)];
+        assert!(estimate_affine(&three, &three[..2]).is_none());
+        let same = vec![Point::new(1., 1.); 3];
+        assert!(estimate_affine(&same, &three).is_none());
+    }
+}
@@ -1,5 +1,6 @@
 use num_traits::Float;
-use types::{Line, LineString, Polygon, MultiPolygon, Bbox};
+use types::{Line, LineString, Polygon, MultiPolygon, Bbox, Triangle};
+use algorithm::kernels::cross;
 
 /// Calculation of the area.
 
@@ -31,6 +32,53 @@ fn get_linestring_area<T>(linestring: &LineString<T>) -> T where T: Float {
     tmp / (T::one() + T::one())
 }
 
+/// Signed area and orientation of rings.
+pub trait Winding<T> where T: Float
+{
+    /// Signed area of a ring, by the shoelace formula: positive if it runs counter-clockwise,
+    /// negative if it runs clockwise
+    ///
+    /// For a Polygon, this is the signed area of its exterior, less the area of its holes,
+    /// whatever their orientation.
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::area::Winding;
+    ///
+    /// let clockwise: LineString<f64> = vec![(0., 0.), (0., 2.), (2., 2.), (2., 0.), (0., 0.)].into();
+    /// assert_eq!(clockwise.signed_area(), -4.);
+    /// ```
+    fn signed_area(&self) -> T;
+
+    /// Whether the geometry runs counter-clockwise; a ring enclosing no area runs neither way
+    fn is_ccw(&self) -> bool {
+        self.signed_area() > T::zero()
+    }
+
+    /// Whether the geometry runs clockwise; a ring enclosing no area runs neither way
+    fn is_cw(&self) -> bool {
+        self.signed_area() < T::zero()
+    }
+}
+
+impl<T> Winding<T> for LineString<T>
+    where T: Float
+{
+    fn signed_area(&self) -> T {
+        get_linestring_area(self)
+    }
+}
+
+impl<T> Winding<T> for Polygon<T>
+    where T: Float
+{
+    fn signed_area(&self) -> T {
+        let exterior = get_linestring_area(&self.exterior);
+        let holes = self.interiors.iter().fold(T::zero(), |total, next| total + get_linestring_area(next).abs());
+        exterior - exterior.signum() * holes
+    }
+}
+
 impl<T> Area<T> for Line<T>
     where T: Float
 {
@@ -43,8 +91,8 @@ impl<T> Area<T> for Polygon<T>
     where T: Float
 {
     fn area(&self) -> T {
-        self.interiors.iter().fold(get_linestring_area(&self.exterior),
-                                   |total, next| total - get_linestring_area(next))
+        self.interiors.iter().fold(get_linestring_area(&self.exterior).abs(),
+                                   |total, next| total - get_linestring_area(next).abs())
     }
 }
 
@@ -56,6 +104,14 @@ impl<T> Area<T> for MultiPolygon<T>
     }
 }
 
+impl<T> Area<T> for Triangle<T>
+    where T: Float
+{
+    fn area(&self) -> T {
+        cross(&self.0, &self.1, &self.2).abs() / (T::one() + T::one())
+    }
+}
+
 impl<T> Area<T> for Bbox<T>
     where T: Float
 {
@@ -66,8 +122,8 @@ impl<T> Area<T> for Bbox<T>
 
 #[cfg(test)]
 mod test {
-    use types::{Coordinate, Point, Line, LineString, Polygon, MultiPolygon, Bbox};
-    use algorithm::area::Area;
+    use types::{Coordinate, Point, Line, LineString, Polygon, MultiPolygon, Bbox, Triangle};
+    use algorithm::area::{Area, Winding};
 
     // Area of the polygon
     #[test]
@@ -124,4 +180,41 @@ mod test {
         let line1 = Line::new(p(0.0, 0.0), p(1.0, 1.0));
         assert_eq!(line1.area(), 0.);
     }
+    #[test]
+    fn triangle_area_test() {
+        // the area of a triangle doesn't depend on its orientation, like that of a Polygon
+        let triangle = Triangle(Point::new(0., 0.), Point::new(4., 0.), Point::new(0., 3.));
+        assert_relative_eq!(triangle.area(), 6.);
+        let clockwise = Triangle(Point::new(0., 0.), Point::new(0., 3.), Point::new(4., 0.));
+        assert_relative_eq!(clockwise.area(), 6.);
+    }
+    #[test]
+    fn signed_area_test() {
+        let ccw: LineString<f64> = vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)].into();
+        let cw = LineString(ccw.0.iter().rev().cloned().collect());
+        assert_relative_eq!(ccw.signed_area(), 4.);
+        assert_relative_eq!(cw.signed_area(), -4.);
+        assert!(ccw.is_ccw() && !ccw.is_cw());
+        assert!(cw.is_cw() && !cw.is_ccw());
+        let flat: LineString<f64> = vec![(0., 0.), (1., 0.), (0., 0.)].into();
+        assert!(!flat.is_ccw() && !flat.is_cw());
+        assert!(!LineString::<f64>(vec![]).is_ccw());
+    }
+    #[test]
+    fn polygon_signed_area_test() {
+        let exterior: LineString<f64> = vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into();
+        let hole: LineString<f64> = vec![(1., 1.), (1., 2.), (2., 2.), (2., 1.), (1., 1.)].into();
+        let reversed = |ls: &LineString<f64>| LineString(ls.0.iter().rev().cloned().collect());
+        let poly = Polygon::new(exterior.clone(), vec![hole.clone()]);
+        assert_relative_eq!(poly.signed_area(), 15.);
+        assert!(poly.is_ccw());
+        // the orientation of the holes doesn't matter
+        assert_relative_eq!(Polygon::new(exterior.clone(), vec![reversed(&hole)]).signed_area(), 15.);
+        let clockwise = Polygon::new(reversed(&exterior), vec![hole]);
+        assert_relative_eq!(clockwise.signed_area(), -15.);
+        assert!(clockwise.is_cw());
+        // whereas the area doesn't depend on the orientation of any ring
+        assert_relative_eq!(clockwise.area(), 15.);
+        assert_relative_eq!(Polygon::new(reversed(&exterior), vec![]).area(), 16.);
+    }
 }
@@ -0,0 +1,66 @@
+use num_traits::Float;
+use types::Point;
+
+/// Returns the bearing from one Point to another.
+pub trait Bearing<T: Float> {
+    /// Returns the initial bearing from a Point to another, in degrees clockwise from north
+    ///
+    /// The Points' x and y are longitudes and latitudes in degrees, and the bearing is that of
+    /// the great circle path between them as it leaves this Point, in the range `0` to `360`.
+    /// The bearing from a Point to itself is `0`.
+    ///
+    /// ```
+    /// # extern crate geo;
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::Point;
+    /// use geo::algorithm::bearing::Bearing;
+    ///
+    /// # fn main() {
+    /// let london = Point::new(-0.1278, 51.5074);
+    /// let paris = Point::new(2.3522, 48.8566);
+    ///
+    /// assert_relative_eq!(london.bearing(&paris), 148.1156, epsilon = 1e-4);
+    /// # }
+    /// ```
+    fn bearing(&self, other: &Point<T>) -> T;
+}
+
+impl<T> Bearing<T> for Point<T>
+    where T: Float
+{
+    fn bearing(&self, other: &Point<T>) -> T {
+        let (lat1, lat2) = (self.y().to_radians(), other.y().to_radians());
+        let delta_lng = (other.x() - self.x()).to_radians();
+        let y = delta_lng.sin() * lat2.cos();
+        let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * delta_lng.cos();
+        let full_turn = T::from(360.).unwrap();
+        (y.atan2(x).to_degrees() + full_turn) % full_turn
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::Point;
+    use super::*;
+
+    #[test]
+    fn bearing_compass_test() {
+        let origin = Point::new(0., 0.);
+        assert_eq!(origin.bearing(&Point::new(0., 1.)), 0.);
+        assert_relative_eq!(origin.bearing(&Point::new(1., 0.)), 90.);
+        assert_relative_eq!(origin.bearing(&Point::new(0., -1.)), 180.);
+        assert_relative_eq!(origin.bearing(&Point::new(-1., 0.)), 270.);
+        assert_eq!(origin.bearing(&origin), 0.);
+    }
+    #[test]
+    fn bearing_test() {
+        // just short of 45 degrees, as meridians converge away from the equator
+        assert_relative_eq!(Point::new(0., 0.).bearing(&Point::new(1., 1.)), 44.99564, epsilon = 1e-5);
+        let new_york = Point::new(-74.006, 40.7128);
+        let london = Point::new(-0.1278, 51.5074);
+        assert_relative_eq!(new_york.bearing(&london), 51.2126, epsilon = 1e-4);
+        // across the antimeridian, heading east
+        assert_relative_eq!(Point::new(179.5, 0.).bearing(&Point::new(-179.5, 0.)), 90., epsilon = 1e-10);
+    }
+}
@@ -0,0 +1,666 @@
+use std::cmp::Ordering;
+use num_traits::Float;
+use types::{Point, Line, LineString, Polygon, MultiPolygon};
+use algorithm::area::Winding;
+use algorithm::contains::Contains;
+use algorithm::remove_collinear::RemoveCollinear;
+use algorithm::kernels::{cross, lexicographic, point_along, straddle};
+
+// An edge of one of the two operands of an overlay, directed so that the interior of the operand
+// lies on its left
+struct Edge<T>
+    where T: Float
+{
+    start: Point<T>,
+    end: Point<T>,
+    operand: usize,
+}
+
+// A piece of an operand edge, between two consecutive nodes. `lo` precedes `hi` in lexicographic
+// order, and `left` and `right` record whether the interior of each operand lies on that side of
+// the piece, looking from `lo` to `hi`. `on` records which operands' boundaries include it.
+struct Piece<T>
+    where T: Float
+{
+    lo: Point<T>,
+    hi: Point<T>,
+    left: [bool; 2],
+    right: [bool; 2],
+    on: [bool; 2],
+}
+
+// the position of p along an edge, from 0 at its start to 1 at its end
+fn param<T>(edge: &Edge<T>, p: &Point<T>) -> T
+    where T: Float
+{
+    let (dx, dy) = (edge.end.x() - edge.start.x(), edge.end.y() - edge.start.y());
+    ((p.x() - edge.start.x()) * dx + (p.y() - edge.start.y()) * dy) / (dx * dx + dy * dy)
+}
+
+// true if p, which lies on the edge's line, falls strictly between its endpoints
+fn between<T>(edge: &Edge<T>, p: &Point<T>) -> bool
+    where T: Float
+{
+    let t = param(edge, p);
+    t > T::zero() && t < T::one()
+}
+
+fn bboxes_overlap<T>(a: &Edge<T>, b: &Edge<T>) -> bool
+    where T: Float
+{
+    a.start.x().min(a.end.x()) <= b.start.x().max(b.end.x()) &&
+        b.start.x().min(b.end.x()) <= a.start.x().max(a.end.x()) &&
+        a.start.y().min(a.end.y()) <= b.start.y().max(b.end.y()) &&
+        b.start.y().min(b.end.y()) <= a.start.y().max(a.end.y())
+}
+
+// Appends the edges of each polygon's rings. Whatever the orientation of the input rings, the
+// edges are directed so that the polygon's interior lies on their left, which is the case for a
+// counter-clockwise exterior and for clockwise interiors.
+fn push_edges<T>(polygons: &[Polygon<T>], operand: usize, edges: &mut Vec<Edge<T>>)
+    where T: Float
+{
+    for polygon in polygons {
+        let rings = Some(&polygon.exterior).into_iter().chain(polygon.interiors.iter());
+        for (idx, ring) in rings.enumerate() {
+            let forwards = (ring.signed_area() > T::zero()) == (idx == 0);
+            for line in ring.lines().filter(|line| line.start != line.end) {
+                let (start, end) = if forwards { (line.start, line.end) } else { (line.end, line.start) };
+                edges.push(Edge { start, end, operand });
+            }
+        }
+    }
+}
+
+// Returns the nodes along each edge: its endpoints, and the points where an edge of the other
+// operand touches or crosses it. Where edges are collinear, each is split at the endpoints of
+// the other which lie along it, so that their shared extent becomes the same piece.
+fn node<T>(edges: &[Edge<T>]) -> Vec<Vec<Point<T>>>
+    where T: Float
+{
+    let mut nodes: Vec<Vec<Point<T>>> = edges.iter().map(|edge| vec![edge.start, edge.end]).collect();
+    for (i, a) in edges.iter().enumerate() {
+        for (j, b) in edges.iter().enumerate().skip(i + 1) {
+            if a.operand == b.operand || !bboxes_overlap(a, b) {
+                continue;
+            }
+            let (o1, o2) = (cross(&a.start, &a.end, &b.start), cross(&a.start, &a.end, &b.end));
+            let (o3, o4) = (cross(&b.start, &b.end, &a.start), cross(&b.start, &b.end, &a.end));
+            if straddle(o1, o2) && straddle(o3, o4) {
+                let t = o3 / (o3 - o4);
+                let p = point_along(&Line::new(a.start, a.end), t);
+                nodes[i].push(p);
+                nodes[j].push(p);
+                continue;
+            }
+            let collinear = o1 == T::zero() && o2 == T::zero();
+            for &(o, p) in &[(o1, b.start), (o2, b.end)] {
+                if (collinear || o == T::zero()) && between(a, &p) {
+                    nodes[i].push(p);
+                }
+            }
+            for &(o, p) in &[(o3, a.start), (o4, a.end)] {
+                if (collinear || o == T::zero()) && between(b, &p) {
+                    nodes[j].push(p);
+                }
+            }
+        }
+    }
+    nodes
+}
+
+// Splits the edges at their nodes, and merges the pieces which coincide, so that each piece
+// records which operands it bounds and on which side their interiors lie.
+fn pieces<T>(edges: &[Edge<T>]) -> Vec<Piece<T>>
+    where T: Float
+{
+    let mut pieces = vec![];
+    for (edge, mut nodes) in edges.iter().zip(node(edges)) {
+        nodes.sort_by(|p, q| param(edge, p).partial_cmp(&param(edge, q)).unwrap_or(Ordering::Equal));
+        nodes.dedup();
+        for pair in nodes.windows(2) {
+            let forwards = lexicographic(&pair[0], &pair[1]) == Ordering::Less;
+            let (lo, hi) = if forwards { (pair[0], pair[1]) } else { (pair[1], pair[0]) };
+            let (mut left, mut right, mut on) = ([false; 2], [false; 2], [false; 2]);
+            left[edge.operand] = forwards;
+            right[edge.operand] = !forwards;
+            on[edge.operand] = true;
+            pieces.push(Piece { lo, hi, left, right, on });
+        }
+    }
+    pieces.sort_by(|a, b| lexicographic(&a.lo, &b.lo).then(lexicographic(&a.hi, &b.hi)));
+    let mut merged: Vec<Piece<T>> = vec![];
+    for piece in pieces {
+        match merged.last_mut() {
+            Some(ref mut last) if last.lo == piece.lo && last.hi == piece.hi => {
+                for k in 0..2 {
+                    last.left[k] |= piece.left[k];
+                    last.right[k] |= piece.right[k];
+                    last.on[k] |= piece.on[k];
+                }
+                continue;
+            }
+            _ => {}
+        }
+        merged.push(piece);
+    }
+    merged
+}
+
+// Links directed edges into closed rings. At a node with several outgoing edges, the one
+// reached first turning clockwise from the incoming edge is followed, which keeps the region on
+// the left of the ring as small as possible.
+fn link<T>(mut edges: Vec<(Point<T>, Point<T>)>) -> Vec<Vec<Point<T>>>
+    where T: Float
+{
+    let two_pi = T::from(2. * ::std::f64::consts::PI).unwrap();
+    edges.sort_by(|a, b| lexicographic(&a.0, &b.0));
+    let mut used = vec![false; edges.len()];
+    let mut rings = vec![];
+    for first in 0..edges.len() {
+        if used[first] {
+            continue;
+        }
+        used[first] = true;
+        let mut ring = vec![edges[first].0];
+        let mut current = first;
+        loop {
+            let (start, end) = edges[current];
+            ring.push(end);
+            if end == edges[first].0 {
+                break;
+            }
+            let back = (start.y() - end.y()).atan2(start.x() - end.x());
+            let turn = |edge: &(Point<T>, Point<T>)| {
+                let angle = back - (edge.1.y() - edge.0.y()).atan2(edge.1.x() - edge.0.x());
+                if angle <= T::zero() { angle + two_pi } else { angle }
+            };
+            let from = match edges.binary_search_by(|edge| lexicographic(&edge.0, &end).then(Ordering::Greater)) {
+                Ok(idx) | Err(idx) => idx,
+            };
+            let next = (from..edges.len())
+                .take_while(|&idx| edges[idx].0 == end)
+                .filter(|&idx| !used[idx])
+                .min_by(|&a, &b| turn(&edges[a]).partial_cmp(&turn(&edges[b])).unwrap_or(Ordering::Equal));
+            match next {
+                Some(idx) => {
+                    used[idx] = true;
+                    current = idx;
+                }
+                None => {
+                    // an unclosed chain, which can only come from degenerate input
+                    ring.clear();
+                    break;
+                }
+            }
+        }
+        rings.extend(split(ring));
+    }
+    rings
+}
+
+// Splits a closed ring which passes through the same node more than once into simple rings
+fn split<T>(ring: Vec<Point<T>>) -> Vec<Vec<Point<T>>>
+    where T: Float
+{
+    let mut rings = vec![];
+    let mut stack: Vec<Point<T>> = vec![];
+    for p in ring {
+        if let Some(idx) = stack.iter().position(|q| *q == p) {
+            let mut part = stack.split_off(idx);
+            part.push(p);
+            rings.push(part);
+        }
+        stack.push(p);
+    }
+    rings
+}
+
+// Computes a boolean operation on two sets of non-overlapping polygons. `keep` decides, from
+// whether a point lies inside each operand, if it lies inside the result. The result's
+// boundary is made up of the pieces of the operands' boundaries with the result on one side but
+// not the other, which are linked into counter-clockwise exteriors and clockwise interiors.
+fn overlay<T, F>(a: &[Polygon<T>], b: &[Polygon<T>], keep: F) -> MultiPolygon<T>
+    where T: Float,
+          F: Fn(bool, bool) -> bool
+{
+    let mut edges = vec![];
+    push_edges(a, 0, &mut edges);
+    push_edges(b, 1, &mut edges);
+    let operands = [a, b];
+    let two = T::one() + T::one();
+    let mut boundary = vec![];
+    for mut piece in pieces(&edges) {
+        let mid = Point::new((piece.lo.x() + piece.hi.x()) / two, (piece.lo.y() + piece.hi.y()) / two);
+        for (k, operand) in operands.iter().enumerate() {
+            if !piece.on[k] {
+                let inside = operand.iter().any(|polygon| polygon.contains(&mid));
+                piece.left[k] = inside;
+                piece.right[k] = inside;
+            }
+        }
+        let (left, right) = (keep(piece.left[0], piece.left[1]), keep(piece.right[0], piece.right[1]));
+        if left && !right {
+            boundary.push((piece.lo, piece.hi));
+        } else if right && !left {
+            boundary.push((piece.hi, piece.lo));
+        }
+    }
+    let rings: Vec<LineString<T>> = link(boundary)
+        .into_iter()
+        .map(LineString)
+        .filter(|ring| ring.signed_area() != T::zero())
+        .collect();
+    let (exteriors, interiors): (Vec<_>, Vec<_>) = rings.into_iter().partition(|ring| ring.signed_area() > T::zero());
+    let mut polygons: Vec<Polygon<T>> = exteriors.into_iter()
+        .map(|ring| Polygon::new(ring, vec![]))
+        .collect();
+    for ring in interiors {
+        // interiors only share isolated vertices with exteriors, so the middle of an edge of one
+        // lies strictly inside the exterior enclosing it
+        let mid = Point::new((ring.0[0].x() + ring.0[1].x()) / two, (ring.0[0].y() + ring.0[1].y()) / two);
+        let enclosing = polygons.iter_mut()
+            .filter(|polygon| Polygon::new(polygon.exterior.clone(), vec![]).contains(&mid))
+            .min_by(|p, q| {
+                p.exterior.signed_area().partial_cmp(&q.exterior.signed_area()).unwrap_or(Ordering::Equal)
+            });
+        if let Some(polygon) = enclosing {
+            polygon.interiors.push(ring);
+        }
+    }
+    // drop the vertices left behind along straight edges, such as where a shared boundary was
+    // dissolved
+    MultiPolygon(polygons.iter().map(|polygon| polygon.remove_collinear(T::zero())).collect())
+}
+
+/// Merges a slice of Polygons into the region covered by any of them
+///
+/// Overlapping and touching Polygons are dissolved into a single outline, and gaps enclosed by
+/// them become interior rings. The Polygons are merged in pairs, then those results in pairs,
+/// and so on, which keeps the intermediate geometries small and is much faster than merging
+/// them into a single accumulated result one at a time.
+///
+/// The exteriors of the resulting Polygons are counter-clockwise and their interiors clockwise,
+/// and vertices lying on the straight line between their neighbours are dropped. An empty
+/// slice gives an empty MultiPolygon.
+///
+/// ```
+/// use geo::Polygon;
+/// use geo::algorithm::boolean::cascaded_union;
+///
+/// let left = Polygon::new(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)].into(), vec![]);
+/// let right = Polygon::new(vec![(1., 0.), (3., 0.), (3., 2.), (1., 2.), (1., 0.)].into(), vec![]);
+/// let union = cascaded_union(&[left, right]);
+///
+/// assert_eq!(union.0.len(), 1);
+/// assert_eq!(union.0[0].exterior.0.len(), 5);
+/// ```
+pub fn cascaded_union<T>(polygons: &[Polygon<T>]) -> MultiPolygon<T>
+    where T: Float
+{
+    if polygons.len() < 2 {
+        return overlay(polygons, &[], |a, b| a || b);
+    }
+    let (first, second) = polygons.split_at(polygons.len() / 2);
+    overlay(&cascaded_union(first).0, &cascaded_union(second).0, |a, b| a || b)
+}
+
+/// Computes the intersection of two geometries.
+pub trait Intersection<T: Float> {
+    /// Returns the region covered by both a Polygon and another
+    ///
+    /// Holes in either Polygon are respected, and boundaries the two only share, without their
+    /// interiors overlapping, don't contribute to the result. If the Polygons don't overlap, the
+    /// result is empty. The exteriors of the resulting Polygons are counter-clockwise and their
+    /// interiors clockwise.
+    ///
+    /// ```
+    /// use geo::Polygon;
+    /// use geo::algorithm::area::Area;
+    /// use geo::algorithm::boolean::Intersection;
+    ///
+    /// let left = Polygon::new(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)].into(), vec![]);
+    /// let right = Polygon::new(vec![(1., 1.), (3., 1.), (3., 3.), (1., 3.), (1., 1.)].into(), vec![]);
+    /// let overlap = left.intersection(&right);
+    ///
+    /// assert_eq!(overlap.0.len(), 1);
+    /// assert_eq!(overlap.0[0].area(), 1.);
+    /// ```
+    fn intersection(&self, other: &Polygon<T>) -> MultiPolygon<T>;
+}
+
+impl<T> Intersection<T> for Polygon<T>
+    where T: Float
+{
+    fn intersection(&self, other: &Polygon<T>) -> MultiPolygon<T> {
+        overlay(::std::slice::from_ref(self), ::std::slice::from_ref(other), |a, b| a && b)
+    }
+}
+
+/// Computes the union of two geometries.
+pub trait Union<T: Float, Rhs = Self> {
+    /// Returns the region covered by either of two geometries
+    ///
+    /// Shared boundaries are dissolved, so touching Polygons merge into a single outline, and
+    /// gaps enclosed by the geometries become interior rings. The Polygons of a MultiPolygon may
+    /// overlap each other, and are merged with those of the other geometry as by
+    /// `cascaded_union`. The exteriors of the resulting Polygons are counter-clockwise and their
+    /// interiors clockwise, and vertices lying on the straight line between their neighbours are
+    /// dropped.
+    ///
+    /// ```
+    /// use geo::Polygon;
+    /// use geo::algorithm::boolean::Union;
+    ///
+    /// let left = Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into(), vec![]);
+    /// let right = Polygon::new(vec![(1., 0.), (2., 0.), (2., 1.), (1., 1.), (1., 0.)].into(), vec![]);
+    /// let union = left.union(&right);
+    ///
+    /// assert_eq!(union.0.len(), 1);
+    /// assert_eq!(union.0[0].exterior, vec![(0., 0.), (2., 0.), (2., 1.), (0., 1.), (0., 0.)].into());
+    /// ```
+    fn union(&self, other: &Rhs) -> MultiPolygon<T>;
+}
+
+impl<T> Union<T> for Polygon<T>
+    where T: Float
+{
+    fn union(&self, other: &Polygon<T>) -> MultiPolygon<T> {
+        overlay(::std::slice::from_ref(self), ::std::slice::from_ref(other), |a, b| a || b)
+    }
+}
+
+impl<T> Union<T, Polygon<T>> for MultiPolygon<T>
+    where T: Float
+{
+    fn union(&self, other: &Polygon<T>) -> MultiPolygon<T> {
+        let mut polygons = self.0.clone();
+        polygons.push(other.clone());
+        cascaded_union(&polygons)
+    }
+}
+
+impl<T> Union<T> for MultiPolygon<T>
+    where T: Float
+{
+    fn union(&self, other: &MultiPolygon<T>) -> MultiPolygon<T> {
+        let mut polygons = self.0.clone();
+        polygons.extend_from_slice(&other.0);
+        cascaded_union(&polygons)
+    }
+}
+
+/// Computes the difference of two geometries.
+pub trait Difference<T: Float> {
+    /// Returns the region covered by a Polygon but not by another
+    ///
+    /// Subtracting a Polygon lying strictly inside this one leaves a hole, one overlapping it
+    /// clips it, and one which doesn't overlap it leaves it as it was. The exteriors of the
+    /// resulting Polygons are counter-clockwise and their interiors clockwise, and vertices
+    /// lying on the straight line between their neighbours are dropped.
+    ///
+    /// ```
+    /// use geo::Polygon;
+    /// use geo::algorithm::boolean::Difference;
+    ///
+    /// let square = Polygon::new(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into(), vec![]);
+    /// let window = Polygon::new(vec![(1., 1.), (3., 1.), (3., 3.), (1., 3.), (1., 1.)].into(), vec![]);
+    /// let frame = square.difference(&window);
+    ///
+    /// assert_eq!(frame.0.len(), 1);
+    /// assert_eq!(frame.0[0].exterior, square.exterior);
+    /// assert_eq!(frame.0[0].interiors, vec![vec![(1., 1.), (1., 3.), (3., 3.), (3., 1.), (1., 1.)].into()]);
+    /// ```
+    fn difference(&self, other: &Polygon<T>) -> MultiPolygon<T>;
+}
+
+impl<T> Difference<T> for Polygon<T>
+    where T: Float
+{
+    fn difference(&self, other: &Polygon<T>) -> MultiPolygon<T> {
+        overlay(::std::slice::from_ref(self), ::std::slice::from_ref(other), |a, b| a && !b)
+    }
+}
+
+/// Computes the symmetric difference of two geometries.
+pub trait SymmetricDifference<T: Float> {
+    /// Returns the region covered by exactly one of a geometry and a Polygon
+    ///
+    /// This is their union less their intersection. The Polygons of a MultiPolygon may overlap
+    /// each other, and are merged first, as by `cascaded_union`. The exteriors of the resulting
+    /// Polygons are counter-clockwise and their interiors clockwise, and vertices lying on the
+    /// straight line between their neighbours are dropped.
+    ///
+    /// ```
+    /// use geo::{Polygon, MultiPolygon};
+    /// use geo::algorithm::area::Area;
+    /// use geo::algorithm::boolean::SymmetricDifference;
+    ///
+    /// let left = Polygon::new(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)].into(), vec![]);
+    /// let right = Polygon::new(vec![(1., 0.), (3., 0.), (3., 2.), (1., 2.), (1., 0.)].into(), vec![]);
+    /// let either = MultiPolygon(vec![left]).symmetric_difference(&right);
+    ///
+    /// assert_eq!(either.0.len(), 2);
+    /// assert_eq!(either.area(), 4.);
+    /// ```
+    fn symmetric_difference(&self, other: &Polygon<T>) -> MultiPolygon<T>;
+}
+
+impl<T> SymmetricDifference<T> for Polygon<T>
+    where T: Float
+{
+    fn symmetric_difference(&self, other: &Polygon<T>) -> MultiPolygon<T> {
+        overlay(::std::slice::from_ref(self), ::std::slice::from_ref(other), |a, b| a != b)
+    }
+}
+
+impl<T> SymmetricDifference<T> for MultiPolygon<T>
+    where T: Float
+{
+    fn symmetric_difference(&self, other: &Polygon<T>) -> MultiPolygon<T> {
+        overlay(&cascaded_union(&self.0).0, ::std::slice::from_ref(other), |a, b| a != b)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{LineString, Polygon, MultiPolygon};
+    use algorithm::area::{Area, Winding};
+    use algorithm::kernels::square;
+    use super::*;
+
+    #[test]
+    fn cascaded_union_grid_test() {
+        // a 4 by 3 grid of overlapping squares, with sides of 1.5 and 1 apart
+        let squares: Vec<Polygon<f64>> = (0..12).map(|idx| square((idx % 4) as f64, (idx / 4) as f64, 1.5)).collect();
+        let union = cascaded_union(&squares);
+        assert_eq!(union.0.len(), 1);
+        assert!(union.0[0].interiors.is_empty());
+        assert_eq!(union.0[0].exterior.0.len(), 5);
+        assert_relative_eq!(union.0[0].area(), 4.5 * 3.5, epsilon = 1e-10);
+    }
+    #[test]
+    fn cascaded_union_hole_test() {
+        // a ring of unit squares around a gap in the middle
+        let squares: Vec<Polygon<f64>> = (0..9)
+            .filter(|&idx| idx != 4)
+            .map(|idx| square((idx % 3) as f64, (idx / 3) as f64, 1.))
+            .collect();
+        let union = cascaded_union(&squares);
+        assert_eq!(union.0.len(), 1);
+        assert_eq!(union.0[0].exterior, square(0., 0., 3.).exterior);
+        assert_eq!(union.0[0].interiors.len(), 1);
+        assert_relative_eq!(union.0[0].interiors[0].signed_area(), -1.);
+    }
+    #[test]
+    fn cascaded_union_disjoint_test() {
+        let union = cascaded_union(&[square(0., 0., 1.), square(2., 0., 1.), square(0.5, 0.5, 0.25)]);
+        assert_eq!(union.0.len(), 2);
+        assert!(cascaded_union::<f64>(&[]).0.is_empty());
+    }
+    #[test]
+    fn cascaded_union_many_test() {
+        // 400 overlapping squares, rotated slightly so that their edges cross rather than align
+        let (sin, cos) = 0.1f64.sin_cos();
+        let squares: Vec<Polygon<f64>> = (0..400)
+            .map(|idx| {
+                let (x, y) = ((idx % 20) as f64, (idx / 20) as f64);
+                let corners = [(0., 0.), (1.5, 0.), (1.5, 1.5), (0., 1.5), (0., 0.)];
+                Polygon::new(corners.iter().map(|&(u, v)| (x + u * cos - v * sin, y + u * sin + v * cos)).collect(),
+                             vec![])
+            })
+            .collect();
+        let union = cascaded_union(&squares);
+        assert_eq!(union.0.len(), 1);
+        assert!(union.0[0].interiors.is_empty());
+        // folding the squares into the result one at a time covers the same area
+        let folded = squares.iter().fold(MultiPolygon(vec![]), |acc, square| {
+            overlay(&acc.0, ::std::slice::from_ref(square), |a, b| a || b)
+        });
+        assert_eq!(folded.0.len(), 1);
+        assert_relative_eq!(union.0[0].area(), folded.0[0].area(), epsilon = 1e-6);
+    }
+    #[test]
+    fn intersection_overlapping_test() {
+        let strip = Polygon::new(vec![(1., 0.5), (4., 0.5), (4., 1.5), (1., 1.5), (1., 0.5)].into(), vec![]);
+        let overlap = square(0., 0., 2.).intersection(&strip);
+        assert_eq!(overlap.0.len(), 1);
+        let correct: LineString<f64> = vec![(1., 0.5), (2., 0.5), (2., 1.5), (1., 1.5), (1., 0.5)].into();
+        assert_relative_eq!(overlap.0[0].area(), 1.);
+        assert!(correct.0.iter().all(|p| overlap.0[0].exterior.0.contains(p)));
+        assert_eq!(overlap.0[0].exterior.0.len(), 5);
+    }
+    #[test]
+    fn intersection_disjoint_test() {
+        assert!(square(0., 0., 1.).intersection(&square(2., 0., 1.)).0.is_empty());
+        // sharing an edge, or a corner
+        assert!(square(0., 0., 1.).intersection(&square(1., 0., 1.)).0.is_empty());
+        assert!(square(0., 0., 1.).intersection(&square(1., 1., 1.)).0.is_empty());
+    }
+    #[test]
+    fn intersection_contained_test() {
+        let (outer, inner) = (square(0., 0., 4.), square(1., 1., 1.));
+        assert_eq!(outer.intersection(&inner).0, vec![inner.clone()]);
+        assert_eq!(inner.intersection(&outer).0, vec![inner.clone()]);
+        // an inner square overlapping the outer one along part of an edge
+        let flush = square(0., 1., 1.);
+        assert_eq!(outer.intersection(&flush).0.len(), 1);
+        assert_relative_eq!(outer.intersection(&flush).0[0].area(), 1.);
+    }
+    #[test]
+    fn intersection_holes_test() {
+        let frame = Polygon::new(square(0., 0., 4.).exterior, vec![square(1., 1., 2.).exterior]);
+        // a strip across the frame is cut in two by the hole
+        let strip = Polygon::new(vec![(-1., 1.5), (5., 1.5), (5., 2.5), (-1., 2.5), (-1., 1.5)].into(), vec![]);
+        let pieces = frame.intersection(&strip);
+        assert_eq!(pieces.0.len(), 2);
+        assert!(pieces.0.iter().all(|piece| (piece.area() - 1.).abs() < 1e-10));
+        // a square within the hole misses the frame, and one around it keeps the hole
+        assert!(frame.intersection(&square(1.5, 1.5, 1.)).0.is_empty());
+        let around = frame.intersection(&square(0.5, 0.5, 3.));
+        assert_eq!(around.0.len(), 1);
+        assert_eq!(around.0[0].interiors.len(), 1);
+        assert_relative_eq!(around.0[0].area(), 9. - 4.);
+    }
+    #[test]
+    fn union_touching_test() {
+        let union = square(0., 0., 1.).union(&square(1., 0., 1.));
+        assert_eq!(union.0.len(), 1);
+        let rectangle: LineString<f64> = vec![(0., 0.), (2., 0.), (2., 1.), (0., 1.), (0., 0.)].into();
+        assert_eq!(union.0[0].exterior, rectangle);
+        assert!(union.0[0].interiors.is_empty());
+    }
+    #[test]
+    fn union_overlapping_test() {
+        let union = square(0., 0., 2.).union(&square(1., 1., 2.));
+        assert_eq!(union.0.len(), 1);
+        assert_eq!(union.0[0].exterior.0.len(), 9);
+        assert_relative_eq!(union.0[0].area(), 7.);
+        // a MultiPolygon whose members overlap each other
+        let multi = MultiPolygon(vec![square(0., 0., 2.), square(1., 0., 2.)]);
+        let top = Polygon::new(vec![(0., 1.), (3., 1.), (3., 3.), (0., 3.), (0., 1.)].into(), vec![]);
+        let union = multi.union(&top);
+        assert_eq!(union.0.len(), 1);
+        assert_eq!(union.0[0].exterior, square(0., 0., 3.).exterior);
+    }
+    #[test]
+    fn union_disjoint_test() {
+        let union = square(0., 0., 1.).union(&square(2., 0., 1.));
+        assert_eq!(union.0.len(), 2);
+        let others = MultiPolygon(vec![square(0., 3., 1.), square(3., 0., 1.)]);
+        let union = MultiPolygon(vec![square(0., 0., 1.)]).union(&others);
+        assert_eq!(union.0.len(), 3);
+    }
+    #[test]
+    fn union_hole_test() {
+        // four strips in a ring around a gap
+        let bottom = Polygon::new(vec![(0., 0.), (3., 0.), (3., 1.), (0., 1.), (0., 0.)].into(), vec![]);
+        let top = Polygon::new(vec![(0., 2.), (3., 2.), (3., 3.), (0., 3.), (0., 2.)].into(), vec![]);
+        let strips = MultiPolygon(vec![bottom, top]);
+        let sides = MultiPolygon(vec![square(0., 1., 1.), square(2., 1., 1.)]);
+        let union = strips.union(&sides);
+        assert_eq!(union.0.len(), 1);
+        assert_eq!(union.0[0].exterior, square(0., 0., 3.).exterior);
+        assert_eq!(union.0[0].interiors.len(), 1);
+        assert_relative_eq!(union.0[0].interiors[0].signed_area(), -1.);
+    }
+    #[test]
+    fn difference_hole_test() {
+        let frame = square(0., 0., 4.).difference(&square(1., 1., 2.));
+        assert_eq!(frame.0.len(), 1);
+        assert_eq!(frame.0[0].exterior, square(0., 0., 4.).exterior);
+        assert_eq!(frame.0[0].interiors.len(), 1);
+        assert_relative_eq!(frame.0[0].area(), 16. - 4.);
+        // and nothing is left of the smaller one
+        assert!(square(1., 1., 2.).difference(&square(0., 0., 4.)).0.is_empty());
+    }
+    #[test]
+    fn difference_overlapping_test() {
+        let clipped = square(0., 0., 2.).difference(&square(1., 1., 2.));
+        assert_eq!(clipped.0.len(), 1);
+        assert!(clipped.0[0].interiors.is_empty());
+        let correct: LineString<f64> = vec![(0., 0.), (2., 0.), (2., 1.), (1., 1.), (1., 2.), (0., 2.), (0., 0.)].into();
+        assert_eq!(clipped.0[0].exterior.0.len(), correct.0.len());
+        assert!(correct.0.iter().all(|p| clipped.0[0].exterior.0.contains(p)));
+        assert_relative_eq!(clipped.0[0].area(), 3.);
+        // a strip across the middle cuts it in two
+        let strip = Polygon::new(vec![(-1., 0.5), (3., 0.5), (3., 1.5), (-1., 1.5), (-1., 0.5)].into(), vec![]);
+        let halves = square(0., 0., 2.).difference(&strip);
+        assert_eq!(halves.0.len(), 2);
+        assert!(halves.0.iter().all(|half| (half.area() - 1.).abs() < 1e-10));
+    }
+    #[test]
+    fn difference_disjoint_test() {
+        let original = square(0., 0., 1.);
+        assert_eq!(original.difference(&square(2., 0., 1.)).0, vec![original.clone()]);
+        // touching along an edge removes nothing either
+        assert_eq!(original.difference(&square(1., 0., 1.)).0, vec![original.clone()]);
+        // and a clockwise input comes back counter-clockwise
+        let clockwise = Polygon::new(vec![(0., 0.), (0., 1.), (1., 1.), (1., 0.), (0., 0.)].into(), vec![]);
+        let oriented = clockwise.difference(&square(2., 0., 1.));
+        assert_relative_eq!(oriented.0[0].area(), 1.);
+        assert!(oriented.0[0].exterior.is_ccw());
+    }
+    #[test]
+    fn symmetric_difference_overlapping_test() {
+        // two squares overlapping at a corner leave two L shapes, meeting at two points
+        let (first, second) = (square(0., 0., 2.), square(1., 1., 2.));
+        let either = first.symmetric_difference(&second);
+        assert_eq!(either.0.len(), 2);
+        assert!(either.0.iter().all(|l| l.interiors.is_empty() && l.exterior.0.len() == 7));
+        assert_relative_eq!(either.0.iter().map(|l| l.area()).sum::<f64>(), 3. + 3.);
+        assert_eq!(second.symmetric_difference(&first).0.len(), 2);
+        // the union less the intersection
+        let union: f64 = first.union(&second).area();
+        let intersection: f64 = first.intersection(&second).area();
+        assert_relative_eq!(union - intersection, 6.);
+    }
+    #[test]
+    fn symmetric_difference_multipolygon_test() {
+        // overlapping members are merged first, rather than cancelling each other out
+        let multi = MultiPolygon(vec![square(0., 0., 2.), square(1., 0., 2.)]);
+        let either = multi.symmetric_difference(&square(1., 0., 1.));
+        assert_relative_eq!(either.area(), 6. - 1.);
+        assert_eq!(MultiPolygon(vec![]).symmetric_difference(&square(0., 0., 1.)).0, vec![square(0., 0., 1.)]);
+    }
+}
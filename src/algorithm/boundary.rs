@@ -0,0 +1,67 @@
+use num_traits::Float;
+use types::{MultiLineString, Polygon, MultiPolygon};
+
+/// Returns the boundary of a geometry.
+pub trait Boundary<T: Float> {
+    /// Returns the boundary of a Polygon: its exterior ring, followed by its interior rings
+    ///
+    /// This is the OGC boundary of a Polygon. The rings are returned as they are, so each is
+    /// closed if the Polygon's was.
+    ///
+    /// ```
+    /// use geo::{LineString, Polygon};
+    /// use geo::algorithm::boundary::Boundary;
+    ///
+    /// let exterior: LineString<f64> = vec![(0., 0.), (1., 0.), (1., 1.), (0., 0.)].into();
+    /// let poly = Polygon::new(exterior.clone(), vec![]);
+    ///
+    /// assert_eq!(poly.boundary().0, vec![exterior]);
+    /// ```
+    fn boundary(&self) -> MultiLineString<T>;
+}
+
+impl<T> Boundary<T> for Polygon<T>
+    where T: Float
+{
+    fn boundary(&self) -> MultiLineString<T> {
+        let mut rings = vec![self.exterior.clone()];
+        rings.extend_from_slice(&self.interiors);
+        MultiLineString(rings)
+    }
+}
+
+impl<T> Boundary<T> for MultiPolygon<T>
+    where T: Float
+{
+    /// The rings of every member, in order, each exterior followed by its interiors
+    fn boundary(&self) -> MultiLineString<T> {
+        MultiLineString(self.0.iter().flat_map(|poly| poly.boundary().0).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{LineString, Polygon, MultiPolygon};
+    use super::*;
+
+    #[test]
+    fn polygon_boundary_test() {
+        let exterior: LineString<f64> = vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into();
+        let hole: LineString<f64> = vec![(1., 1.), (1., 2.), (2., 2.), (2., 1.), (1., 1.)].into();
+        let boundary = Polygon::new(exterior.clone(), vec![hole.clone()]).boundary();
+        assert_eq!(boundary.0, vec![exterior, hole]);
+        assert!(boundary.0.iter().all(|ring| ring.0.first() == ring.0.last()));
+        let empty = Polygon::<f64>::new(LineString(vec![]), vec![]);
+        assert_eq!(empty.boundary().0, vec![LineString(vec![])]);
+    }
+    #[test]
+    fn multipolygon_boundary_test() {
+        let holed = Polygon::new(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into(),
+                                 vec![vec![(1., 1.), (1., 2.), (2., 2.), (2., 1.), (1., 1.)].into()]);
+        let solid = Polygon::new(vec![(5., 0.), (6., 0.), (6., 1.), (5., 0.)].into(), vec![]);
+        let boundary = MultiPolygon(vec![holed.clone(), solid.clone()]).boundary();
+        assert_eq!(boundary.0.len(), 3);
+        assert_eq!(boundary.0, vec![holed.exterior, holed.interiors[0].clone(), solid.exterior]);
+        assert!(MultiPolygon::<f64>(vec![]).boundary().0.is_empty());
+    }
+}
@@ -1,9 +1,10 @@
 use num_traits::Float;
 
-use types::{Bbox, Point, MultiPoint, Line, LineString, MultiLineString, Polygon, MultiPolygon};
+use types::{Bbox, Coordinate, Point, MultiPoint, Line, LineString, MultiLineString, Polygon, MultiPolygon,
+            GeometryCollection};
+use ::Geometry;
 
 /// Calculation of the bounding box of a geometry.
-
 pub trait BoundingBox<T: Float> {
     type Output;
 
@@ -138,11 +139,224 @@ impl<T> BoundingBox<T> for MultiPolygon<T>
 }
 
 
+/// Calculation of the centre of the bounding box of a geometry.
+pub trait BboxCenter<T: Float> {
+    /// Return the centre of the Bounding Box of a geometry, or `None` if it's empty
+    ///
+    /// This is cheaper to compute than the centroid, and unlike it doesn't depend on how the
+    /// geometry's mass is distributed, which can be preferable for placing a symmetric label or
+    /// marker.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::boundingbox::BboxCenter;
+    ///
+    /// let linestring: LineString<f64> = vec![(0., 0.), (1., 0.), (4., 2.)].into();
+    ///
+    /// assert_eq!(linestring.bbox_center(), Some(Point::new(2., 1.)));
+    /// ```
+    fn bbox_center(&self) -> Option<Point<T>>;
+}
+
+impl<T, G> BboxCenter<T> for G
+    where T: Float,
+          G: BoundingBox<T, Output = Option<Bbox<T>>>
+{
+    fn bbox_center(&self) -> Option<Point<T>> {
+        let two = T::one() + T::one();
+        self.bbox().map(|bbox| Point::new((bbox.xmin + bbox.xmax) / two, (bbox.ymin + bbox.ymax) / two))
+    }
+}
+
+/// Calculation of the componentwise extremes of the coordinates of a geometry.
+pub trait CoordMinMax<T: Float> {
+    /// Return the smallest `x` and smallest `y` of any coordinate of a geometry, or `None` if
+    /// it's empty
+    ///
+    /// This is the bottom left corner of the Bounding Box, which needn't be a coordinate of the
+    /// geometry itself.
+    ///
+    /// ```
+    /// use geo::{Coordinate, LineString};
+    /// use geo::algorithm::boundingbox::CoordMinMax;
+    ///
+    /// let linestring: LineString<f64> = vec![(0., 3.), (1., 0.), (4., 2.)].into();
+    ///
+    /// assert_eq!(linestring.coord_min(), Some(Coordinate { x: 0., y: 0. }));
+    /// ```
+    fn coord_min(&self) -> Option<Coordinate<T>>;
+
+    /// Return the largest `x` and largest `y` of any coordinate of a geometry, or `None` if it's
+    /// empty
+    ///
+    /// This is the top right corner of the Bounding Box.
+    ///
+    /// ```
+    /// use geo::{Coordinate, LineString};
+    /// use geo::algorithm::boundingbox::CoordMinMax;
+    ///
+    /// let linestring: LineString<f64> = vec![(0., 3.), (1., 0.), (4., 2.)].into();
+    ///
+    /// assert_eq!(linestring.coord_max(), Some(Coordinate { x: 4., y: 3. }));
+    /// ```
+    fn coord_max(&self) -> Option<Coordinate<T>>;
+}
+
+// the bottom left and top right corners of a Bbox
+fn min_corner<T>(bbox: Bbox<T>) -> Coordinate<T>
+    where T: Float
+{
+    Coordinate { x: bbox.xmin, y: bbox.ymin }
+}
+fn max_corner<T>(bbox: Bbox<T>) -> Coordinate<T>
+    where T: Float
+{
+    Coordinate { x: bbox.xmax, y: bbox.ymax }
+}
+
+// the componentwise extreme of two optional coordinates, chosen by `pick`
+fn combine<T, F>(a: Option<Coordinate<T>>, b: Option<Coordinate<T>>, pick: F) -> Option<Coordinate<T>>
+    where T: Float,
+          F: Fn(T, T) -> T
+{
+    match (a, b) {
+        (Some(a), Some(b)) => Some(Coordinate { x: pick(a.x, b.x), y: pick(a.y, b.y) }),
+        (a, None) => a,
+        (None, b) => b,
+    }
+}
+
+impl<T> CoordMinMax<T> for Point<T>
+    where T: Float
+{
+    fn coord_min(&self) -> Option<Coordinate<T>> {
+        Some(self.0)
+    }
+
+    fn coord_max(&self) -> Option<Coordinate<T>> {
+        Some(self.0)
+    }
+}
+
+impl<T> CoordMinMax<T> for Line<T>
+    where T: Float
+{
+    fn coord_min(&self) -> Option<Coordinate<T>> {
+        Some(min_corner(self.bbox()))
+    }
+
+    fn coord_max(&self) -> Option<Coordinate<T>> {
+        Some(max_corner(self.bbox()))
+    }
+}
+
+impl<T> CoordMinMax<T> for MultiPoint<T>
+    where T: Float
+{
+    fn coord_min(&self) -> Option<Coordinate<T>> {
+        self.bbox().map(min_corner)
+    }
+
+    fn coord_max(&self) -> Option<Coordinate<T>> {
+        self.bbox().map(max_corner)
+    }
+}
+
+impl<T> CoordMinMax<T> for LineString<T>
+    where T: Float
+{
+    fn coord_min(&self) -> Option<Coordinate<T>> {
+        self.bbox().map(min_corner)
+    }
+
+    fn coord_max(&self) -> Option<Coordinate<T>> {
+        self.bbox().map(max_corner)
+    }
+}
+
+impl<T> CoordMinMax<T> for MultiLineString<T>
+    where T: Float
+{
+    fn coord_min(&self) -> Option<Coordinate<T>> {
+        self.bbox().map(min_corner)
+    }
+
+    fn coord_max(&self) -> Option<Coordinate<T>> {
+        self.bbox().map(max_corner)
+    }
+}
+
+impl<T> CoordMinMax<T> for Polygon<T>
+    where T: Float
+{
+    fn coord_min(&self) -> Option<Coordinate<T>> {
+        self.bbox().map(min_corner)
+    }
+
+    fn coord_max(&self) -> Option<Coordinate<T>> {
+        self.bbox().map(max_corner)
+    }
+}
+
+impl<T> CoordMinMax<T> for MultiPolygon<T>
+    where T: Float
+{
+    fn coord_min(&self) -> Option<Coordinate<T>> {
+        self.bbox().map(min_corner)
+    }
+
+    fn coord_max(&self) -> Option<Coordinate<T>> {
+        self.bbox().map(max_corner)
+    }
+}
+
+impl<T> CoordMinMax<T> for Geometry<T>
+    where T: Float
+{
+    fn coord_min(&self) -> Option<Coordinate<T>> {
+        match *self {
+            Geometry::Point(ref g) => g.coord_min(),
+            Geometry::LineString(ref g) => g.coord_min(),
+            Geometry::Polygon(ref g) => g.coord_min(),
+            Geometry::MultiPoint(ref g) => g.coord_min(),
+            Geometry::MultiLineString(ref g) => g.coord_min(),
+            Geometry::MultiPolygon(ref g) => g.coord_min(),
+            Geometry::GeometryCollection(ref g) => g.coord_min(),
+        }
+    }
+
+    fn coord_max(&self) -> Option<Coordinate<T>> {
+        match *self {
+            Geometry::Point(ref g) => g.coord_max(),
+            Geometry::LineString(ref g) => g.coord_max(),
+            Geometry::Polygon(ref g) => g.coord_max(),
+            Geometry::MultiPoint(ref g) => g.coord_max(),
+            Geometry::MultiLineString(ref g) => g.coord_max(),
+            Geometry::MultiPolygon(ref g) => g.coord_max(),
+            Geometry::GeometryCollection(ref g) => g.coord_max(),
+        }
+    }
+}
+
+impl<T> CoordMinMax<T> for GeometryCollection<T>
+    where T: Float
+{
+    fn coord_min(&self) -> Option<Coordinate<T>> {
+        self.0.iter().fold(None, |min, g| combine(min, g.coord_min(), T::min))
+    }
+
+    fn coord_max(&self) -> Option<Coordinate<T>> {
+        self.0.iter().fold(None, |max, g| combine(max, g.coord_max(), T::max))
+    }
+}
 
 #[cfg(test)]
 mod test {
-    use types::{Bbox, Coordinate, Point, MultiPoint, Line, LineString, MultiLineString, Polygon, MultiPolygon};
-    use algorithm::boundingbox::BoundingBox;
+    use types::{Bbox, Coordinate, Point, MultiPoint, Line, LineString, MultiLineString, Polygon, MultiPolygon,
+                GeometryCollection};
+    use algorithm::boundingbox::{BoundingBox, BboxCenter, CoordMinMax};
+    use ::Geometry;
+    use algorithm::centroid::Centroid;
 
     #[test]
     fn empty_linestring_test() {
@@ -193,6 +407,16 @@ mod test {
         assert_eq!(line_bbox, poly.bbox().unwrap());
     }
     #[test]
+    fn l_shaped_polygon_test() {
+        // the bounding box spans both arms, including the empty corner, and ignores the hole
+        let exterior: LineString<f64> = vec![(0., 0.), (4., 0.), (4., 1.), (1., 1.), (1., 3.), (0., 3.), (0., 0.)].into();
+        let hole: LineString<f64> = vec![(2., 0.25), (2., 0.75), (3., 0.75), (3., 0.25), (2., 0.25)].into();
+        let poly = Polygon::new(exterior, vec![hole]);
+        assert_eq!(poly.bbox(), Some(Bbox {xmin: 0., xmax: 4., ymin: 0., ymax: 3.}));
+        assert!(Polygon::<f64>::new(LineString(vec![]), vec![]).bbox().is_none());
+        assert!(MultiPolygon::<f64>(vec![]).bbox().is_none());
+    }
+    #[test]
     fn multipolygon_test(){
         let p = |x, y| Point(Coordinate { x: x, y: y });
         let mpoly = MultiPolygon(vec![Polygon::new(LineString(vec![p(0., 0.), p(50., 0.), p(0., -70.), p(0., 0.)]), Vec::new()),
@@ -210,4 +434,40 @@ mod test {
         assert_eq!(line1.bbox(), Bbox {xmin: 0., xmax: 2., ymin: 1., ymax: 3.});
         assert_eq!(line2.bbox(), Bbox {xmin: 0., xmax: 2., ymin: 1., ymax: 3.});
     }
+    #[test]
+    fn bbox_center_test() {
+        // an L shape, whose mass is concentrated towards its bottom left corner
+        let poly = Polygon::new(vec![(0., 0.), (2., 0.), (2., 1.), (1., 1.), (1., 2.), (0., 2.), (0., 0.)].into(),
+                                vec![]);
+        assert_eq!(poly.bbox_center(), Some(Point::new(1., 1.)));
+        let centroid = poly.centroid().unwrap();
+        assert_relative_eq!(centroid.x(), 5. / 6.);
+        assert_relative_eq!(centroid.y(), 5. / 6.);
+        assert!(LineString::<f64>(vec![]).bbox_center().is_none());
+    }
+    #[test]
+    fn coord_min_max_test() {
+        let exterior: LineString<f64> = vec![(1., -2.), (5., 0.), (3., 4.), (-1., 3.), (1., -2.)].into();
+        let poly = Polygon::new(exterior, vec![]);
+        assert_eq!(poly.coord_min(), Some(Coordinate { x: -1., y: -2. }));
+        assert_eq!(poly.coord_max(), Some(Coordinate { x: 5., y: 4. }));
+        let line = Line::new(Point::new(2., 0.), Point::new(0., 1.));
+        assert_eq!(line.coord_min(), Some(Coordinate { x: 0., y: 0. }));
+        assert_eq!(Point::new(2., 3.).coord_max(), Some(Coordinate { x: 2., y: 3. }));
+        assert_eq!(Polygon::<f64>::new(LineString(vec![]), vec![]).coord_min(), None);
+        assert_eq!(MultiPoint::<f64>(vec![]).coord_max(), None);
+    }
+    #[test]
+    fn coord_min_max_collection_test() {
+        let line: LineString<f64> = vec![(1., 5.), (2., -1.)].into();
+        let empty = Geometry::MultiPoint(MultiPoint(vec![]));
+        let nested = Geometry::GeometryCollection(GeometryCollection(vec![Point::new(-3., 2.).into(), empty.clone()]));
+        let collection = GeometryCollection(vec![line.into(), empty.clone(), nested]);
+        assert_eq!(collection.coord_min(), Some(Coordinate { x: -3., y: -1. }));
+        assert_eq!(collection.coord_max(), Some(Coordinate { x: 2., y: 5. }));
+        let geometry = Geometry::GeometryCollection(collection);
+        assert_eq!(geometry.coord_max(), Some(Coordinate { x: 2., y: 5. }));
+        assert_eq!(empty.coord_min(), None);
+        assert_eq!(GeometryCollection::<f64>(vec![empty]).coord_max(), None);
+    }
 }
@@ -0,0 +1,341 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use types::{Point, LineString, Polygon, MultiPoint, MultiLineString, MultiPolygon, GeometryCollection};
+use ::Geometry;
+
+const POINT: u8 = 1;
+const LINESTRING: u8 = 2;
+const POLYGON: u8 = 3;
+const MULTIPOINT: u8 = 4;
+const MULTILINESTRING: u8 = 5;
+const MULTIPOLYGON: u8 = 6;
+const GEOMETRYCOLLECTION: u8 = 7;
+
+// how deeply GeometryCollections may be nested when decoding, so that malicious input can't
+// exhaust the stack
+const MAX_DEPTH: usize = 32;
+
+/// The ways in which decoding a geometry from bytes can fail.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum BytesError {
+    /// The bytes ended part way through a geometry
+    UnexpectedEnd,
+    /// A geometry started with a tag which doesn't name a geometry type
+    UnknownTag(u8),
+    /// A Polygon had no rings, so no exterior
+    MissingExterior,
+    /// Bytes were left over after the geometry
+    TrailingBytes,
+    /// GeometryCollections were nested more deeply than `from_bytes` allows
+    TooDeep,
+}
+
+impl fmt::Display for BytesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BytesError::UnexpectedEnd => write!(f, "the bytes ended part way through a geometry"),
+            BytesError::UnknownTag(tag) => write!(f, "unknown geometry tag {}", tag),
+            BytesError::MissingExterior => write!(f, "a polygon had no exterior ring"),
+            BytesError::TrailingBytes => write!(f, "bytes were left over after the geometry"),
+            BytesError::TooDeep => write!(f, "geometry collections were nested too deeply"),
+        }
+    }
+}
+
+impl Error for BytesError {}
+
+fn write_count(count: usize, bytes: &mut Vec<u8>) {
+    let count = u32::try_from(count).expect("too many members to encode as bytes");
+    bytes.extend_from_slice(&count.to_le_bytes());
+}
+
+fn write_point(p: &Point<f64>, bytes: &mut Vec<u8>) {
+    bytes.extend_from_slice(&p.x().to_le_bytes());
+    bytes.extend_from_slice(&p.y().to_le_bytes());
+}
+
+fn write_points(points: &[Point<f64>], bytes: &mut Vec<u8>) {
+    write_count(points.len(), bytes);
+    for p in points {
+        write_point(p, bytes);
+    }
+}
+
+fn write_polygon(poly: &Polygon<f64>, bytes: &mut Vec<u8>) {
+    write_count(1 + poly.interiors.len(), bytes);
+    write_points(&poly.exterior.0, bytes);
+    for ring in &poly.interiors {
+        write_points(&ring.0, bytes);
+    }
+}
+
+// reads values from the front of a slice of bytes
+struct Reader<'a> {
+    bytes: &'a [u8],
+    // the number of GeometryCollections enclosing the geometry being read
+    depth: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8], BytesError> {
+        if self.bytes.len() < count {
+            return Err(BytesError::UnexpectedEnd);
+        }
+        let (taken, rest) = self.bytes.split_at(count);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn tag(&mut self) -> Result<u8, BytesError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn count(&mut self) -> Result<usize, BytesError> {
+        let mut count = [0; 4];
+        count.copy_from_slice(self.take(4)?);
+        Ok(u32::from_le_bytes(count) as usize)
+    }
+
+    fn float(&mut self) -> Result<f64, BytesError> {
+        let mut float = [0; 8];
+        float.copy_from_slice(self.take(8)?);
+        Ok(f64::from_le_bytes(float))
+    }
+
+    fn point(&mut self) -> Result<Point<f64>, BytesError> {
+        let x = self.float()?;
+        Ok(Point::new(x, self.float()?))
+    }
+
+    fn points(&mut self) -> Result<Vec<Point<f64>>, BytesError> {
+        (0..self.count()?).map(|_| self.point()).collect()
+    }
+
+    fn polygon(&mut self) -> Result<Polygon<f64>, BytesError> {
+        let count = self.count()?;
+        if count == 0 {
+            return Err(BytesError::MissingExterior);
+        }
+        let exterior = LineString(self.points()?);
+        let interiors = (1..count).map(|_| self.points().map(LineString)).collect::<Result<_, _>>()?;
+        Ok(Polygon::new(exterior, interiors))
+    }
+
+    fn geometry(&mut self) -> Result<Geometry<f64>, BytesError> {
+        Ok(match self.tag()? {
+            POINT => Geometry::Point(self.point()?),
+            LINESTRING => Geometry::LineString(LineString(self.points()?)),
+            POLYGON => Geometry::Polygon(self.polygon()?),
+            MULTIPOINT => Geometry::MultiPoint(MultiPoint(self.points()?)),
+            MULTILINESTRING => {
+                let lines = (0..self.count()?).map(|_| self.points().map(LineString)).collect::<Result<_, _>>()?;
+                Geometry::MultiLineString(MultiLineString(lines))
+            }
+            MULTIPOLYGON => {
+                let polygons = (0..self.count()?).map(|_| self.polygon()).collect::<Result<_, _>>()?;
+                Geometry::MultiPolygon(MultiPolygon(polygons))
+            }
+            GEOMETRYCOLLECTION => {
+                if self.depth == MAX_DEPTH {
+                    return Err(BytesError::TooDeep);
+                }
+                self.depth += 1;
+                let geometries = (0..self.count()?).map(|_| self.geometry()).collect::<Result<_, _>>()?;
+                self.depth -= 1;
+                Geometry::GeometryCollection(GeometryCollection(geometries))
+            }
+            tag => return Err(BytesError::UnknownTag(tag)),
+        })
+    }
+}
+
+/// Encodes a geometry in a compact binary layout, for caching.
+pub trait ToBytes {
+    /// Encodes a geometry as bytes, which `from_bytes` decodes
+    ///
+    /// The layout is simpler than WKB, having no byte order marker or dimensions, so it is
+    /// cheaper to produce and read back, but it is only meant for this crate's own use. All
+    /// numbers are little-endian. A geometry starts with a one byte tag: 1 for a Point, 2 for a
+    /// LineString, 3 for a Polygon, 4 for a MultiPoint, 5 for a MultiLineString, 6 for a
+    /// MultiPolygon and 7 for a GeometryCollection. It is followed by:
+    ///
+    /// - a Point: its `x` and `y`, as `f64`s
+    /// - a LineString or MultiPoint: a `u32` count of Points, then each Point
+    /// - a Polygon: a `u32` count of rings, then the exterior and each interior, as a
+    ///   LineString without its tag
+    /// - a MultiLineString or MultiPolygon: a `u32` count, then each member without its tag
+    /// - a GeometryCollection: a `u32` count, then each member with its tag
+    ///
+    /// This panics if a geometry has more members than a `u32` can count.
+    ///
+    /// ```
+    /// use geo::{Geometry, Point};
+    /// use geo::algorithm::bytes::{ToBytes, from_bytes};
+    ///
+    /// let point = Point::new(1.5, -2.);
+    /// let bytes = point.to_bytes();
+    ///
+    /// assert_eq!(bytes.len(), 17);
+    /// assert_eq!(from_bytes(&bytes), Ok(Geometry::Point(point)));
+    /// ```
+    fn to_bytes(&self) -> Vec<u8>;
+}
+
+impl ToBytes for Point<f64> {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![POINT];
+        write_point(self, &mut bytes);
+        bytes
+    }
+}
+
+impl ToBytes for LineString<f64> {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![LINESTRING];
+        write_points(&self.0, &mut bytes);
+        bytes
+    }
+}
+
+impl ToBytes for Polygon<f64> {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![POLYGON];
+        write_polygon(self, &mut bytes);
+        bytes
+    }
+}
+
+impl ToBytes for MultiPoint<f64> {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![MULTIPOINT];
+        write_points(&self.0, &mut bytes);
+        bytes
+    }
+}
+
+impl ToBytes for MultiLineString<f64> {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![MULTILINESTRING];
+        write_count(self.0.len(), &mut bytes);
+        for ls in &self.0 {
+            write_points(&ls.0, &mut bytes);
+        }
+        bytes
+    }
+}
+
+impl ToBytes for MultiPolygon<f64> {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![MULTIPOLYGON];
+        write_count(self.0.len(), &mut bytes);
+        for poly in &self.0 {
+            write_polygon(poly, &mut bytes);
+        }
+        bytes
+    }
+}
+
+impl ToBytes for GeometryCollection<f64> {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![GEOMETRYCOLLECTION];
+        write_count(self.0.len(), &mut bytes);
+        for geometry in &self.0 {
+            bytes.extend(geometry.to_bytes());
+        }
+        bytes
+    }
+}
+
+impl ToBytes for Geometry<f64> {
+    fn to_bytes(&self) -> Vec<u8> {
+        match *self {
+            Geometry::Point(ref p) => p.to_bytes(),
+            Geometry::LineString(ref ls) => ls.to_bytes(),
+            Geometry::Polygon(ref poly) => poly.to_bytes(),
+            Geometry::MultiPoint(ref mp) => mp.to_bytes(),
+            Geometry::MultiLineString(ref mls) => mls.to_bytes(),
+            Geometry::MultiPolygon(ref mpoly) => mpoly.to_bytes(),
+            Geometry::GeometryCollection(ref gc) => gc.to_bytes(),
+        }
+    }
+}
+
+/// Decodes a geometry encoded by `ToBytes`, which must use all of the bytes.
+///
+/// GeometryCollections may be nested at most 32 deep.
+pub fn from_bytes(bytes: &[u8]) -> Result<Geometry<f64>, BytesError> {
+    let mut reader = Reader { bytes, depth: 0 };
+    let geometry = reader.geometry()?;
+    if !reader.bytes.is_empty() {
+        return Err(BytesError::TrailingBytes);
+    }
+    Ok(geometry)
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon, MultiPoint, MultiLineString, MultiPolygon, GeometryCollection};
+    use ::Geometry;
+    use super::*;
+
+    #[test]
+    fn bytes_round_trip_test() {
+        let ls: LineString<f64> = vec![(0., 0.), (1.5, -2.25), (1e300, f64::MIN_POSITIVE)].into();
+        let poly = Polygon::new(vec![(0., 0.), (4., 0.), (4., 4.), (0., 0.)].into(),
+                                vec![vec![(1., 1.), (2., 1.), (2., 2.), (1., 1.)].into(), LineString(vec![])]);
+        let geometries: Vec<Geometry<f64>> = vec![Point::new(-0., 3.).into(),
+                                                  ls.clone().into(),
+                                                  poly.clone().into(),
+                                                  MultiPoint(vec![Point::new(1., 2.), Point::new(3., 4.)]).into(),
+                                                  MultiLineString(vec![ls.clone(), LineString(vec![])]).into(),
+                                                  MultiPolygon(vec![poly.clone(), poly.clone()]).into(),
+                                                  MultiPolygon(vec![]).into()];
+        let collection = Geometry::GeometryCollection(GeometryCollection(geometries.clone()));
+        let nested = Geometry::GeometryCollection(GeometryCollection(vec![collection.clone(), poly.into()]));
+        for geometry in geometries.iter().chain([collection, nested].iter()) {
+            let bytes = geometry.to_bytes();
+            let decoded = from_bytes(&bytes).unwrap();
+            assert_eq!(&decoded, geometry);
+            assert_eq!(decoded.to_bytes(), bytes);
+        }
+        // the sign of zero survives
+        match from_bytes(&Point::new(-0., 3.).to_bytes()) {
+            Ok(Geometry::Point(p)) => assert!(p.x().is_sign_negative()),
+            _ => panic!("expected a Point"),
+        }
+    }
+    #[test]
+    fn bytes_layout_test() {
+        let bytes = LineString(vec![Point::new(1., 2.)]).to_bytes();
+        let mut correct = vec![2, 1, 0, 0, 0];
+        correct.extend_from_slice(&1f64.to_le_bytes());
+        correct.extend_from_slice(&2f64.to_le_bytes());
+        assert_eq!(bytes, correct);
+    }
+    #[test]
+    fn bytes_error_test() {
+        let bytes = Point::new(1., 2.).to_bytes();
+        assert_eq!(from_bytes(&bytes[..10]), Err(BytesError::UnexpectedEnd));
+        assert_eq!(from_bytes(&[]), Err(BytesError::UnexpectedEnd));
+        assert_eq!(from_bytes(&[9]), Err(BytesError::UnknownTag(9)));
+        assert_eq!(from_bytes(&[3, 0, 0, 0, 0]), Err(BytesError::MissingExterior));
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(from_bytes(&trailing), Err(BytesError::TrailingBytes));
+        // a huge count doesn't allocate before running out of bytes
+        assert_eq!(from_bytes(&[2, 255, 255, 255, 255]), Err(BytesError::UnexpectedEnd));
+    }
+    #[test]
+    fn bytes_nesting_test() {
+        // collections of one member each, nested around a Point
+        let nested = |depth: usize| {
+            let mut bytes: Vec<u8> = (0..depth).flat_map(|_| vec![7, 1, 0, 0, 0]).collect();
+            bytes.extend(Point::new(1., 2.).to_bytes());
+            bytes
+        };
+        assert!(from_bytes(&nested(32)).is_ok());
+        assert_eq!(from_bytes(&nested(33)), Err(BytesError::TooDeep));
+        assert_eq!(from_bytes(&nested(100000)), Err(BytesError::TooDeep));
+    }
+}
@@ -0,0 +1,141 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use num_traits::Float;
+use algorithm::map_coords::MapCoords;
+
+/// A coordinate transform which remembers its results, for geometries sharing many vertices
+///
+/// Reprojecting a tiled or adjacent set of Polygons transforms each shared vertex once for every
+/// geometry it belongs to, and a projection can be expensive. Each result is cached, keyed by
+/// its input coordinates rounded to a multiple of `precision`, and reused for any input falling
+/// in the same cell, so `precision` should be smaller than the distances that matter. A
+/// `precision` which isn't positive, and coordinates which can't be rounded to an `i64`
+/// multiple of it, bypass the cache.
+///
+/// ```
+/// use std::cell::Cell;
+/// use geo::Polygon;
+/// use geo::algorithm::cached_transform::CachedTransform;
+///
+/// let calls = Cell::new(0);
+/// let transform = CachedTransform::new(|&(x, y): &(f64, f64)| {
+///     calls.set(calls.get() + 1);
+///     (x * 2., y * 2.)
+/// }, 1e-9);
+/// let a = Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into(), vec![]);
+/// let b = Polygon::new(vec![(1., 0.), (2., 0.), (2., 1.), (1., 1.), (1., 0.)].into(), vec![]);
+/// let projected = transform.project(&b);
+/// transform.project(&a);
+///
+/// assert_eq!(projected.exterior.0[1].x(), 4.);
+/// // six distinct vertices, (0, 0) and (1, 0) each repeated to close a ring
+/// assert_eq!(calls.get(), 6);
+/// ```
+pub struct CachedTransform<T, NT, F>
+    where F: Fn(&(T, T)) -> (NT, NT)
+{
+    transform: F,
+    precision: T,
+    cache: RefCell<HashMap<(i64, i64), (NT, NT)>>,
+}
+
+impl<T, NT, F> CachedTransform<T, NT, F>
+    where T: Float,
+          NT: Float,
+          F: Fn(&(T, T)) -> (NT, NT)
+{
+    /// Wraps a transform, caching its results for inputs within `precision` of each other.
+    pub fn new(transform: F, precision: T) -> CachedTransform<T, NT, F> {
+        CachedTransform {
+            transform,
+            precision,
+            cache: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Transforms a coordinate, reusing the cached result if there is one.
+    pub fn transform(&self, coord: &(T, T)) -> (NT, NT) {
+        let key = if self.precision > T::zero() {
+            (coord.0 / self.precision).round().to_i64().and_then(|x| {
+                (coord.1 / self.precision).round().to_i64().map(|y| (x, y))
+            })
+        } else {
+            None
+        };
+        let key = match key {
+            Some(key) => key,
+            None => return (self.transform)(coord),
+        };
+        if let Some(&cached) = self.cache.borrow().get(&key) {
+            return cached;
+        }
+        let result = (self.transform)(coord);
+        self.cache.borrow_mut().insert(key, result);
+        result
+    }
+
+    /// Transforms every coordinate of a geometry, as `map_coords` does, through the cache.
+    pub fn project<G>(&self, geometry: &G) -> G::Output
+        where G: MapCoords<T, NT>
+    {
+        geometry.map_coords(&|coord| self.transform(coord))
+    }
+
+    /// The number of results cached so far.
+    pub fn cached(&self) -> usize {
+        self.cache.borrow().len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::Cell;
+    use types::{Point, MultiPolygon};
+    use algorithm::kernels::square;
+    use super::*;
+
+    #[test]
+    fn cached_transform_tiles_test() {
+        // a 3 by 3 grid of tiles, sharing the 16 vertices of the grid between them
+        let tiles = MultiPolygon((0..9).map(|idx| square((idx % 3) as f64, (idx / 3) as f64, 1.)).collect());
+        let calls = Cell::new(0);
+        let project = |&(x, y): &(f64, f64)| {
+            calls.set(calls.get() + 1);
+            (x.to_radians().sin(), y.exp())
+        };
+        let transform = CachedTransform::new(project, 1e-9);
+        let cached = transform.project(&tiles);
+        assert_eq!(calls.get(), 16);
+        assert_eq!(transform.cached(), 16);
+        // identical to transforming every vertex directly
+        calls.set(0);
+        assert_eq!(cached, tiles.map_coords(&project));
+        assert_eq!(calls.get(), 45);
+        // and reused again on the next pass
+        calls.set(0);
+        assert_eq!(transform.project(&tiles), cached);
+        assert_eq!(calls.get(), 0);
+    }
+    #[test]
+    fn cached_transform_precision_test() {
+        let calls = Cell::new(0);
+        let transform = CachedTransform::new(|&(x, y): &(f64, f64)| {
+                                                 calls.set(calls.get() + 1);
+                                                 (x + 1., y + 1.)
+                                             },
+                                             0.01);
+        // nearby inputs share a cell, and its result
+        assert_eq!(transform.transform(&(1., 2.)), (2., 3.));
+        assert_eq!(transform.transform(&(1.001, 2.)), (2., 3.));
+        assert_eq!(transform.transform(&(1.1, 2.)), (2.1, 3.));
+        assert_eq!(calls.get(), 2);
+        // coordinates which can't be keyed are transformed every time
+        let p = Point::new(1e300, 0.);
+        assert_eq!(transform.project(&p), Point::new(1e300, 1.));
+        assert_eq!(transform.project(&p), Point::new(1e300, 1.));
+        assert_eq!(calls.get(), 4);
+        let uncached = CachedTransform::new(|&(x, y): &(f64, f64)| (x, y), 0.);
+        uncached.transform(&(1., 1.));
+        assert_eq!(uncached.cached(), 0);
+    }
+}
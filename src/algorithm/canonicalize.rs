@@ -0,0 +1,173 @@
+use std::cmp::Ordering;
+use num_traits::Float;
+use types::{Point, LineString, Polygon, MultiPolygon};
+use algorithm::orient::{Orient, Direction};
+use algorithm::kernels::lexicographic;
+
+// rotate a closed ring so that it starts (and ends) at its lexicographically smallest vertex
+fn rotate_ring<T>(ring: &LineString<T>) -> LineString<T>
+    where T: Float
+{
+    let points = &ring.0;
+    if points.len() < 2 || points.first() != points.last() {
+        return ring.clone();
+    }
+    // the closing point duplicates the first, so leave it out while rotating
+    let open = &points[..points.len() - 1];
+    let start = open.iter()
+        .enumerate()
+        .fold(0, |min, (idx, p)| if lexicographic(p, &open[min]) == Ordering::Less { idx } else { min });
+    let mut rotated: Vec<Point<T>> = open[start..].iter().chain(open[..start].iter()).cloned().collect();
+    rotated.push(open[start]);
+    LineString(rotated)
+}
+
+// order interior rings by their vertices, which after rotation begin with their smallest one
+fn compare_rings<T>(a: &LineString<T>, b: &LineString<T>) -> Ordering
+    where T: Float
+{
+    a.0.iter()
+        .zip(b.0.iter())
+        .map(|(p, q)| lexicographic(p, q))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or_else(|| a.0.len().cmp(&b.0.len()))
+}
+
+/// Produces a deterministic representation of a geometry.
+pub trait Canonicalize<T> {
+    /// Returns the canonical form of a Polygon
+    ///
+    /// The exterior ring is oriented counter-clockwise and the interior rings clockwise. Each
+    /// ring then starts at its lexicographically smallest vertex (smallest `x`, then smallest
+    /// `y`), and the interior rings are sorted by their vertices. Two Polygons which describe
+    /// the same shape using different starting vertices, orientations, or interior ring order
+    /// have equal canonical forms.
+    ///
+    /// ```
+    /// use geo::Polygon;
+    /// use geo::algorithm::canonicalize::Canonicalize;
+    ///
+    /// // the same square, clockwise from the top right and counter-clockwise from the origin
+    /// let a = Polygon::new(vec![(1., 1.), (1., 0.), (0., 0.), (0., 1.), (1., 1.)].into(), vec![]);
+    /// let b = Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into(), vec![]);
+    ///
+    /// assert_eq!(a.canonicalize(), b.canonicalize());
+    /// assert_eq!(a.canonicalize(), b);
+    /// ```
+    fn canonicalize(&self) -> Self where T: Float;
+}
+
+impl<T> Canonicalize<T> for Polygon<T>
+    where T: Float
+{
+    fn canonicalize(&self) -> Polygon<T> {
+        let oriented = self.orient(Direction::Default);
+        let mut interiors: Vec<LineString<T>> = oriented.interiors.iter().map(rotate_ring).collect();
+        interiors.sort_by(compare_rings);
+        Polygon::new(rotate_ring(&oriented.exterior), interiors)
+    }
+}
+
+// the lexicographically largest vertex of a Polygon's exterior
+fn largest_vertex<T>(poly: &Polygon<T>) -> Option<&Point<T>>
+    where T: Float
+{
+    poly.exterior.0.iter().max_by(|a, b| lexicographic(a, b))
+}
+
+/// Produces a normalized form of a collection of geometries.
+pub trait Normalized<T> {
+    /// Returns a copy of a MultiPolygon with its rings consistently oriented and its Polygons in
+    /// a fixed order
+    ///
+    /// The exterior of each Polygon is oriented counter-clockwise and its interiors clockwise.
+    /// The Polygons are then sorted by the lexicographically largest vertex of their exteriors
+    /// (largest `x`, then largest `y`), and equal ones by the vertices of their exteriors, so
+    /// MultiPolygons listing the same Polygons in different orders normalize to the same value.
+    /// Unlike `canonicalize`, the rings keep their starting vertices.
+    ///
+    /// ```
+    /// use geo::{Polygon, MultiPolygon};
+    /// use geo::algorithm::canonicalize::Normalized;
+    ///
+    /// let left = Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 0.)].into(), vec![]);
+    /// // wound clockwise
+    /// let right = Polygon::new(vec![(5., 0.), (5., 1.), (6., 0.), (5., 0.)].into(), vec![]);
+    /// let normalized = MultiPolygon(vec![right, left.clone()]).normalized();
+    ///
+    /// assert_eq!(normalized.0[0], left);
+    /// assert_eq!(normalized.0[1].exterior, vec![(5., 0.), (6., 0.), (5., 1.), (5., 0.)].into());
+    /// ```
+    fn normalized(&self) -> MultiPolygon<T> where T: Float;
+}
+
+impl<T> Normalized<T> for MultiPolygon<T>
+    where T: Float
+{
+    fn normalized(&self) -> MultiPolygon<T> {
+        let mut polygons: Vec<Polygon<T>> = self.0.iter().map(|poly| poly.orient(Direction::Default)).collect();
+        polygons.sort_by(|a, b| {
+            let ordering = match (largest_vertex(a), largest_vertex(b)) {
+                (Some(p), Some(q)) => lexicographic(p, q),
+                (a, b) => a.is_some().cmp(&b.is_some()),
+            };
+            ordering.then_with(|| compare_rings(&a.exterior, &b.exterior))
+        });
+        MultiPolygon(polygons)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon, MultiPolygon};
+    use super::*;
+
+    #[test]
+    fn canonicalize_square_test() {
+        // clockwise, starting from the top right corner
+        let a = Polygon::new(vec![(2., 2.), (2., 0.), (0., 0.), (0., 2.), (2., 2.)].into(), vec![]);
+        // counter-clockwise, starting from the bottom right corner
+        let b = Polygon::new(vec![(2., 0.), (2., 2.), (0., 2.), (0., 0.), (2., 0.)].into(), vec![]);
+        let correct: LineString<f64> = vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)].into();
+        assert_eq!(a.canonicalize(), b.canonicalize());
+        assert_eq!(a.canonicalize().exterior, correct);
+    }
+    #[test]
+    fn canonicalize_interiors_test() {
+        let exterior: LineString<f64> = vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)].into();
+        let hole1: LineString<f64> = vec![(1., 1.), (1., 2.), (2., 2.), (2., 1.), (1., 1.)].into();
+        let hole2: LineString<f64> = vec![(5., 5.), (5., 6.), (6., 6.), (6., 5.), (5., 5.)].into();
+        // hole2 listed first and wound counter-clockwise, starting from another corner
+        let hole2_ccw: LineString<f64> = vec![(6., 6.), (5., 6.), (5., 5.), (6., 5.), (6., 6.)].into();
+        let a = Polygon::new(exterior.clone(), vec![hole1.clone(), hole2.clone()]);
+        let b = Polygon::new(exterior, vec![hole2_ccw, hole1.clone()]);
+        let canonical = b.canonicalize();
+        assert_eq!(a.canonicalize(), canonical);
+        assert_eq!(canonical.interiors, vec![hole1, hole2]);
+    }
+    #[test]
+    fn canonicalize_empty_test() {
+        let poly = Polygon::<f64>::new(LineString(vec![]), vec![]);
+        assert_eq!(poly.canonicalize(), poly);
+        let single = Polygon::new(LineString(vec![Point::new(1., 1.)]), vec![]);
+        assert_eq!(single.canonicalize(), single);
+    }
+    #[test]
+    fn normalized_test() {
+        let square: Polygon<f64> = Polygon::new(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)].into(),
+                                                vec![vec![(0.5, 0.5), (0.5, 1.5), (1.5, 1.5), (0.5, 0.5)].into()]);
+        let triangle = Polygon::new(vec![(3., 0.), (5., 0.), (4., 3.), (3., 0.)].into(), vec![]);
+        // the same polygons reversed, and with their rings wound the other way
+        let a = MultiPolygon(vec![square.clone(), triangle.clone()]);
+        let b = MultiPolygon(vec![triangle.orient(Direction::Reversed), square.orient(Direction::Reversed)]);
+        assert!(a != b);
+        assert_eq!(a.normalized(), b.normalized());
+        assert_eq!(b.normalized(), a);
+        // equal largest vertices fall back to the rest of the exteriors
+        let lower = Polygon::new(vec![(0., 0.), (4., 3.), (0., 1.), (0., 0.)].into(), vec![]);
+        let upper = Polygon::new(vec![(0., 2.), (4., 3.), (0., 3.), (0., 2.)].into(), vec![]);
+        assert_eq!(MultiPolygon(vec![upper.clone(), lower.clone()]).normalized(),
+                   MultiPolygon(vec![lower, upper]));
+        assert!(MultiPolygon::<f64>(vec![]).normalized().0.is_empty());
+    }
+}
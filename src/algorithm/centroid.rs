@@ -1,6 +1,6 @@
 use num_traits::{Float, FromPrimitive};
 
-use types::{Point, Line, LineString, Polygon, MultiPolygon, Bbox};
+use types::{Point, MultiPoint, Line, LineString, MultiLineString, Polygon, MultiPolygon, Bbox};
 use algorithm::area::Area;
 use algorithm::length::Length;
 
@@ -97,6 +97,29 @@ impl<T> Centroid<T> for LineString<T>
     }
 }
 
+impl<T> Centroid<T> for MultiLineString<T>
+    where T: Float
+{
+    type Output = Option<Point<T>>;
+
+    // The Centroid of a MultiLineString is the mean of the centroids of its LineStrings, weighted
+    // by their lengths, or their plain mean if they all have zero length.
+    fn centroid(&self) -> Self::Output {
+        let centroids: Vec<(Point<T>, T)> = self.0.iter().filter_map(|ls| ls.centroid().map(|c| (c, ls.length()))).collect();
+        if centroids.is_empty() {
+            return None;
+        }
+        let total_length = centroids.iter().fold(T::zero(), |total, &(_, length)| total + length);
+        if total_length == T::zero() {
+            return MultiPoint(centroids.iter().map(|&(c, _)| c).collect()).centroid();
+        }
+        let (sum_x, sum_y) = centroids.iter().fold((T::zero(), T::zero()), |(sum_x, sum_y), &(c, length)| {
+            (sum_x + c.x() * length, sum_y + c.y() * length)
+        });
+        Some(Point::new(sum_x / total_length, sum_y / total_length))
+    }
+}
+
 impl<T> Centroid<T> for Polygon<T>
     where T: Float + FromPrimitive
 {
@@ -191,10 +214,66 @@ impl<T> Centroid<T> for Point<T>
     }
 }
 
+impl<T> Centroid<T> for MultiPoint<T>
+    where T: Float
+{
+    type Output = Option<Point<T>>;
+
+    // The Centroid of a MultiPoint is the mean of its Points.
+    fn centroid(&self) -> Self::Output {
+        if self.0.is_empty() {
+            return None;
+        }
+        let (sum_x, sum_y) = self.0.iter().fold((T::zero(), T::zero()), |(sum_x, sum_y), p| (sum_x + p.x(), sum_y + p.y()));
+        let n = T::from(self.0.len()).unwrap();
+        Some(Point::new(sum_x / n, sum_y / n))
+    }
+}
+
+/// Calculation of a weighted centroid.
+pub trait WeightedCentroid<T: Float> {
+    /// Returns the weighted average of the Points in a MultiPoint
+    ///
+    /// Each Point is weighted by the value at the same index in `weights`, such as the
+    /// population living there. Returns `None` if the number of weights doesn't match the
+    /// number of Points, or if the weights add up to zero.
+    ///
+    /// ```
+    /// use geo::{Point, MultiPoint};
+    /// use geo::algorithm::centroid::WeightedCentroid;
+    ///
+    /// let towns = MultiPoint(vec![Point::new(0., 0.), Point::new(10., 0.)]);
+    ///
+    /// assert_eq!(towns.weighted_centroid(&[3., 1.]), Some(Point::new(2.5, 0.)));
+    /// assert_eq!(towns.weighted_centroid(&[1.]), None);
+    /// ```
+    fn weighted_centroid(&self, weights: &[T]) -> Option<Point<T>>;
+}
+
+impl<T> WeightedCentroid<T> for MultiPoint<T>
+    where T: Float
+{
+    fn weighted_centroid(&self, weights: &[T]) -> Option<Point<T>> {
+        if self.0.len() != weights.len() {
+            return None;
+        }
+        let (total, sum_x, sum_y) = self.0
+            .iter()
+            .zip(weights.iter())
+            .fold((T::zero(), T::zero(), T::zero()), |(total, sum_x, sum_y), (p, &w)| {
+                (total + w, sum_x + p.x() * w, sum_y + p.y() * w)
+            });
+        if total == T::zero() {
+            return None;
+        }
+        Some(Point::new(sum_x / total, sum_y / total))
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use types::{COORD_PRECISION, Coordinate, Point, Line, LineString, Polygon, MultiPolygon, Bbox};
-    use algorithm::centroid::Centroid;
+    use types::{COORD_PRECISION, Coordinate, Point, MultiPoint, Line, LineString, MultiLineString, Polygon, MultiPolygon, Bbox};
+    use algorithm::centroid::{Centroid, WeightedCentroid};
     use algorithm::distance::Distance;
     // Tests: Centroid of LineString
     #[test]
@@ -322,4 +401,44 @@ mod test {
         let line1 = Line::new(p(0., 1.), p(1., 3.));
         assert_eq!(line1.centroid(), p(0.5, 2.));
     }
+    #[test]
+    fn point_test() {
+        assert_eq!(Point::new(3., -1.).centroid(), Point::new(3., -1.));
+    }
+    #[test]
+    fn multipoint_test() {
+        // the corners of a square
+        let mp = MultiPoint(vec![Point::new(1., 1.), Point::new(5., 1.), Point::new(5., 5.), Point::new(1., 5.)]);
+        assert_eq!(mp.centroid(), Some(Point::new(3., 3.)));
+        // a repeated Point counts each time
+        let mp = MultiPoint(vec![Point::new(0., 0.), Point::new(0., 0.), Point::new(3., 0.)]);
+        assert_eq!(mp.centroid(), Some(Point::new(1., 0.)));
+        assert_eq!(MultiPoint(vec![Point::new(2., 7.)]).centroid(), Some(Point::new(2., 7.)));
+        assert_eq!(MultiPoint::<f64>(vec![]).centroid(), None);
+    }
+    #[test]
+    fn multilinestring_test() {
+        // weighted by length, so the longer LineString pulls the centroid towards it
+        let mls = MultiLineString(vec![vec![(0., 0.), (3., 0.)].into(), vec![(0., 4.), (1., 4.)].into()]);
+        assert_eq!(mls.centroid(), Some(Point::new(1.25, 1.)));
+        let points = MultiLineString(vec![LineString(vec![Point::new(0., 0.)]), LineString(vec![Point::new(2., 0.)])]);
+        assert_eq!(points.centroid(), Some(Point::new(1., 0.)));
+        assert_eq!(MultiLineString::<f64>(vec![]).centroid(), None);
+    }
+    #[test]
+    fn weighted_centroid_test() {
+        let mp = MultiPoint(vec![Point::new(0., 0.), Point::new(4., 0.), Point::new(0., 4.), Point::new(4., 4.)]);
+        assert_eq!(mp.weighted_centroid(&[1., 1., 1., 1.]), Some(Point::new(2., 2.)));
+        // the heavily weighted corner pulls the centroid towards it
+        let pulled = mp.weighted_centroid(&[1., 1., 1., 97.]).unwrap();
+        assert_relative_eq!(pulled.x(), 3.92);
+        assert_relative_eq!(pulled.y(), 3.92);
+    }
+    #[test]
+    fn weighted_centroid_invalid_test() {
+        let mp = MultiPoint(vec![Point::new(0., 0.), Point::new(4., 0.)]);
+        assert!(mp.weighted_centroid(&[1., 2., 3.]).is_none());
+        assert!(mp.weighted_centroid(&[0., 0.]).is_none());
+        assert!(MultiPoint::<f64>(vec![]).weighted_centroid(&[]).is_none());
+    }
 }
@@ -0,0 +1,90 @@
+use num_traits::Float;
+use types::{Point, LineString, Polygon};
+
+// the most sides a circle is given, whose chord error is about 1.2e-9 of the radius
+const MAX_SIDES: usize = 1 << 16;
+
+/// Approximates a circle by a regular Polygon
+///
+/// The number of sides is the smallest for which the chord error, the greatest distance between
+/// the circle and the Polygon's edges, stays within `max_error`, with a minimum of three and a
+/// maximum of 65,536, so a `max_error` much smaller than the radius can't exhaust memory. The
+/// vertices lie on the circle, starting due east of the centre and running counter-clockwise, so
+/// the Polygon lies inside the circle. If `radius` or `max_error` isn't positive, the Polygon's
+/// exterior is empty.
+///
+/// ```
+/// use geo::Point;
+/// use geo::algorithm::circle::circle;
+///
+/// // a hexagon deviates from its circumcircle by 1 - cos(30°), about 0.134 of the radius
+/// let hexagon = circle(Point::new(0., 0.), 1., 0.14);
+/// assert_eq!(hexagon.exterior.0.len(), 7);
+/// assert_eq!(hexagon.exterior.0[0], Point::new(1., 0.));
+/// ```
+pub fn circle<T>(center: Point<T>, radius: T, max_error: T) -> Polygon<T>
+    where T: Float
+{
+    if radius.is_nan() || max_error.is_nan() || radius <= T::zero() || max_error <= T::zero() {
+        return Polygon::new(LineString(vec![]), vec![]);
+    }
+    let pi = T::from(::std::f64::consts::PI).unwrap();
+    let three = T::from(3.).unwrap();
+    // an edge subtending an angle of 2θ at the centre is r (1 - cos θ) from the circle at its
+    // middle, so the largest allowed half-angle is acos(1 - max_error / r)
+    let half_angle = (T::one() - (max_error / radius).min(T::one())).acos();
+    // a max_error too small to change 1 - max_error / r gives a half-angle of zero
+    let sides = (pi / half_angle).ceil().max(three).min(T::from(MAX_SIDES).unwrap());
+    let sides = sides.to_usize().unwrap_or(MAX_SIDES);
+    let step = (pi + pi) / T::from(sides).unwrap();
+    let mut points: Vec<Point<T>> = (0..sides)
+        .map(|idx| {
+            let (sin, cos) = (step * T::from(idx).unwrap()).sin_cos();
+            Point::new(center.x() + radius * cos, center.y() + radius * sin)
+        })
+        .collect();
+    points.push(points[0]);
+    Polygon::new(LineString(points), vec![])
+}
+
+#[cfg(test)]
+mod test {
+    use std::f64::consts::PI;
+    use types::Point;
+    use algorithm::area::Area;
+    use algorithm::distance::Distance;
+    use super::*;
+
+    #[test]
+    fn circle_area_test() {
+        let (center, radius) = (Point::new(3., -2.), 10.);
+        for &max_error in &[1., 0.1, 0.01] {
+            let poly = circle(center, radius, max_error);
+            // the Polygon lies between the circle and a concentric one max_error smaller
+            let area = poly.area();
+            assert!(area <= PI * radius * radius);
+            assert!(area >= PI * (radius - max_error) * (radius - max_error));
+            for line in poly.exterior.lines() {
+                let p = Point::new((line.start.x() + line.end.x()) / 2., (line.start.y() + line.end.y()) / 2.);
+                assert!(radius - p.distance(&center) <= max_error);
+            }
+        }
+    }
+    #[test]
+    fn circle_sides_test() {
+        let sides = |max_error| circle(Point::new(0., 0.), 1., max_error).exterior.0.len() - 1;
+        assert_eq!(sides(2.), 3);
+        assert!(sides(0.1) < sides(0.01));
+        assert!(sides(0.01) < sides(0.001));
+        // the chord error of a square is 1 - cos(45°), so just beyond that it's enough
+        assert_eq!(sides(1. - (PI / 4.).cos() + 1e-9), 4);
+        assert!(circle(Point::new(0., 0.), 1., 0.).exterior.0.is_empty());
+        assert!(circle(Point::new(0., 0.), -1., 0.1).exterior.0.is_empty());
+    }
+    #[test]
+    fn circle_tiny_error_test() {
+        // too small to be met, or even to register against the radius
+        assert_eq!(circle(Point::new(0., 0.), 1., 1e-15).exterior.0.len(), MAX_SIDES + 1);
+        assert_eq!(circle(Point::new(0., 0.), 1., 1e-17).exterior.0.len(), MAX_SIDES + 1);
+    }
+}
@@ -0,0 +1,205 @@
+use std::cmp::Ordering;
+use num_traits::Float;
+use types::{Bbox, Line, LineString, Polygon, MultiLineString};
+use algorithm::contains::Contains;
+use algorithm::kernels::{cross, point_along};
+
+// The positions along a line, from 0 at its start to 1 at its end, where it meets the edges of
+// a ring. Where an edge is collinear with the line, the positions of its endpoints are used.
+fn ring_meetings<T>(line: &Line<T>, ring: &LineString<T>, positions: &mut Vec<T>)
+    where T: Float
+{
+    let (dx, dy) = (line.end.x() - line.start.x(), line.end.y() - line.start.y());
+    let length_squared = dx * dx + dy * dy;
+    for edge in ring.lines() {
+        // the sides of the edge the line's endpoints lie on, and of the line the edge's lie on
+        let (d1, d2) = (cross(&edge.start, &edge.end, &line.start), cross(&edge.start, &edge.end, &line.end));
+        let (d3, d4) = (cross(&line.start, &line.end, &edge.start), cross(&line.start, &line.end, &edge.end));
+        if d1 != d2 {
+            let (t, u) = (d1 / (d1 - d2), d3 / (d3 - d4));
+            if t >= T::zero() && t <= T::one() && u >= T::zero() && u <= T::one() {
+                positions.push(t);
+            }
+        } else if d3 == T::zero() {
+            for p in &[edge.start, edge.end] {
+                let t = ((p.x() - line.start.x()) * dx + (p.y() - line.start.y()) * dy) / length_squared;
+                if t > T::zero() && t < T::one() {
+                    positions.push(t);
+                }
+            }
+        }
+    }
+}
+
+/// Clips a geometry to a rectangle.
+pub trait ClipToBbox<T: Float> {
+    /// Returns the part of a Line lying inside a Bbox, including its boundary, or `None` if the
+    /// Line lies entirely outside it
+    ///
+    /// This uses the Liang–Barsky algorithm. The clipped Line runs in the same direction as the
+    /// original, and a Line lying entirely inside the Bbox is returned unchanged. If the Line only
+    /// touches the Bbox at a single point, the result is a degenerate Line starting and ending
+    /// there.
+    ///
+    /// ```
+    /// use geo::{Bbox, Point, Line};
+    /// use geo::algorithm::clip::ClipToBbox;
+    ///
+    /// let bbox = Bbox { xmin: 0., xmax: 10., ymin: 0., ymax: 10. };
+    /// let line = Line::new(Point::new(-5., 5.), Point::new(5., 5.));
+    ///
+    /// assert_eq!(line.clip_to_bbox(&bbox), Some(Line::new(Point::new(0., 5.), Point::new(5., 5.))));
+    /// ```
+    fn clip_to_bbox(&self, bbox: &Bbox<T>) -> Option<Line<T>>;
+}
+
+impl<T> ClipToBbox<T> for Line<T>
+    where T: Float
+{
+    fn clip_to_bbox(&self, bbox: &Bbox<T>) -> Option<Line<T>> {
+        let (dx, dy) = (self.end.x() - self.start.x(), self.end.y() - self.start.y());
+        // the range of positions along the Line, from 0 at its start to 1 at its end, which lie
+        // inside each of the four half-planes bounding the Bbox
+        let (mut t0, mut t1) = (T::zero(), T::one());
+        for &(p, q) in &[(-dx, self.start.x() - bbox.xmin), (dx, bbox.xmax - self.start.x()),
+                         (-dy, self.start.y() - bbox.ymin), (dy, bbox.ymax - self.start.y())] {
+            if p == T::zero() {
+                // parallel to this edge of the Bbox, and outside it
+                if q < T::zero() {
+                    return None;
+                }
+            } else if p < T::zero() {
+                t0 = t0.max(q / p);
+            } else {
+                t1 = t1.min(q / p);
+            }
+        }
+        if t0 > t1 {
+            return None;
+        }
+        let at = |t: T| if t == T::zero() {
+            self.start
+        } else if t == T::one() {
+            self.end
+        } else {
+            point_along(self, t)
+        };
+        Some(Line::new(at(t0), at(t1)))
+    }
+}
+
+/// Clips a geometry to the inside of a Polygon.
+pub trait ClipToPolygon<T: Float> {
+    /// Returns the parts of a Line lying inside a Polygon, outside its interiors
+    ///
+    /// The Line is split wherever it meets the boundary of the Polygon, and the pieces whose
+    /// middles the Polygon contains are kept, in order along the Line and with its direction.
+    /// Adjacent pieces are joined, so a Line which enters the Polygon, crosses a hole and
+    /// reenters gives two pieces. As with `contains`, parts running along the boundary aren't
+    /// inside the Polygon.
+    ///
+    /// ```
+    /// use geo::{Point, Line, Polygon};
+    /// use geo::algorithm::clip::ClipToPolygon;
+    ///
+    /// let triangle = Polygon::new(vec![(0., 0.), (4., 0.), (0., 4.), (0., 0.)].into(), vec![]);
+    /// let line = Line::new(Point::new(-1., 1.), Point::new(5., 1.));
+    ///
+    /// assert_eq!(line.clip_to_polygon(&triangle).0, vec![vec![(0., 1.), (3., 1.)].into()]);
+    /// ```
+    fn clip_to_polygon(&self, poly: &Polygon<T>) -> MultiLineString<T>;
+}
+
+impl<T> ClipToPolygon<T> for Line<T>
+    where T: Float
+{
+    fn clip_to_polygon(&self, poly: &Polygon<T>) -> MultiLineString<T> {
+        if self.start == self.end {
+            return MultiLineString(vec![]);
+        }
+        let mut positions = vec![T::zero(), T::one()];
+        for ring in Some(&poly.exterior).into_iter().chain(poly.interiors.iter()) {
+            ring_meetings(self, ring, &mut positions);
+        }
+        positions.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        positions.dedup();
+        let at = |t: T| if t == T::one() { self.end } else { point_along(self, t) };
+        let two = T::one() + T::one();
+        let mut pieces: Vec<LineString<T>> = vec![];
+        // whether the last piece ends where the next one starts
+        let mut joined = false;
+        for window in positions.windows(2) {
+            if !poly.contains(&at((window[0] + window[1]) / two)) {
+                joined = false;
+                continue;
+            }
+            let end = at(window[1]);
+            match pieces.last_mut() {
+                Some(last) if joined => last.0[1] = end,
+                _ => pieces.push(LineString(vec![at(window[0]), end])),
+            }
+            joined = true;
+        }
+        MultiLineString(pieces)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Bbox, Point, Line, LineString, Polygon};
+    use super::*;
+
+    #[test]
+    fn clip_to_bbox_test() {
+        let bbox = Bbox { xmin: 0., xmax: 4., ymin: 0., ymax: 2. };
+        let inside = Line::new(Point::new(1., 1.), Point::new(3., 0.5));
+        assert_eq!(inside.clip_to_bbox(&bbox), Some(inside));
+        // crossing the Bbox diagonally, from the outside at both ends
+        let crossing = Line::new(Point::new(-1., -1.), Point::new(5., 2.));
+        assert_eq!(crossing.clip_to_bbox(&bbox), Some(Line::new(Point::new(1., 0.), Point::new(4., 1.5))));
+        // and in reverse, from the inside
+        let leaving = Line::new(Point::new(2., 1.), Point::new(2., 5.));
+        assert_eq!(leaving.clip_to_bbox(&bbox), Some(Line::new(Point::new(2., 1.), Point::new(2., 2.))));
+    }
+    #[test]
+    fn clip_to_bbox_outside_test() {
+        let bbox = Bbox { xmin: 0., xmax: 4., ymin: 0., ymax: 2. };
+        assert!(Line::new(Point::new(5., 0.), Point::new(6., 2.)).clip_to_bbox(&bbox).is_none());
+        // its line passes through the Bbox, but the segment stops short
+        assert!(Line::new(Point::new(-3., 1.), Point::new(-1., 1.)).clip_to_bbox(&bbox).is_none());
+        assert!(Line::new(Point::new(-1., 1.), Point::new(1., 4.)).clip_to_bbox(&bbox).is_none());
+        // touching a corner
+        let touching = Line::new(Point::new(3., 3.), Point::new(5., 1.));
+        assert_eq!(touching.clip_to_bbox(&bbox), Some(Line::new(Point::new(4., 2.), Point::new(4., 2.))));
+        // along an edge
+        let edge = Line::new(Point::new(-1., 2.), Point::new(1., 2.));
+        assert_eq!(edge.clip_to_bbox(&bbox), Some(Line::new(Point::new(0., 2.), Point::new(1., 2.))));
+    }
+    #[test]
+    fn clip_to_polygon_test() {
+        let exterior: LineString<f64> = vec![(0., 0.), (6., 0.), (6., 6.), (0., 6.), (0., 0.)].into();
+        let hole: LineString<f64> = vec![(2., 2.), (2., 4.), (4., 4.), (4., 2.), (2., 2.)].into();
+        let poly = Polygon::new(exterior, vec![hole]);
+        // across the whole square, through the hole
+        let line = Line::new(Point::new(-1., 3.), Point::new(7., 3.));
+        let correct: Vec<LineString<f64>> = vec![vec![(0., 3.), (2., 3.)].into(), vec![(4., 3.), (6., 3.)].into()];
+        assert_eq!(line.clip_to_polygon(&poly).0, correct);
+        // starting inside, and passing through a corner of the hole without entering it
+        let diagonal = Line::new(Point::new(1., 3.), Point::new(3., 5.));
+        assert_eq!(diagonal.clip_to_polygon(&poly).0, vec![vec![(1., 3.), (3., 5.)].into()]);
+        // along the edge of the hole, which isn't inside
+        let along = Line::new(Point::new(1., 2.), Point::new(5., 2.));
+        let correct: Vec<LineString<f64>> = vec![vec![(1., 2.), (2., 2.)].into(), vec![(4., 2.), (5., 2.)].into()];
+        assert_eq!(along.clip_to_polygon(&poly).0, correct);
+    }
+    #[test]
+    fn clip_to_polygon_outside_test() {
+        let poly = Polygon::new(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)].into(), vec![]);
+        assert!(Line::new(Point::new(3., 0.), Point::new(3., 2.)).clip_to_polygon(&poly).0.is_empty());
+        // entirely inside the hole of a frame
+        let frame = Polygon::new(vec![(-1., -1.), (3., -1.), (3., 3.), (-1., 3.), (-1., -1.)].into(),
+                                 vec![poly.exterior.clone()]);
+        assert!(Line::new(Point::new(0.5, 0.5), Point::new(1.5, 1.)).clip_to_polygon(&frame).0.is_empty());
+        assert!(Line::new(Point::new(1., 1.), Point::new(1., 1.)).clip_to_polygon(&poly).0.is_empty());
+    }
+}
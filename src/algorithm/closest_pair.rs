@@ -0,0 +1,133 @@
+use std::cmp::Ordering;
+use num_traits::Float;
+use types::{Point, MultiPoint};
+use algorithm::distance::Distance;
+
+// two points and the distance between them
+type Pair<T> = (Point<T>, Point<T>, T);
+
+// the closer of two candidate pairs, preferring the first
+fn closer<T>(a: Pair<T>, b: Pair<T>) -> Pair<T>
+    where T: Float
+{
+    if b.2 < a.2 { b } else { a }
+}
+
+// Finds the closest pair among at least two points sorted by x, returning it along with the
+// points sorted by y, which is merged from the halves in linear time.
+fn recurse<T>(by_x: &[Point<T>]) -> (Pair<T>, Vec<Point<T>>)
+    where T: Float
+{
+    if by_x.len() <= 3 {
+        let mut best = (by_x[0], by_x[1], by_x[0].distance(&by_x[1]));
+        for (idx, a) in by_x.iter().enumerate() {
+            for b in &by_x[idx + 1..] {
+                best = closer(best, (*a, *b, a.distance(b)));
+            }
+        }
+        let mut by_y = by_x.to_vec();
+        by_y.sort_by(|a, b| a.y().partial_cmp(&b.y()).unwrap_or(Ordering::Equal));
+        return (best, by_y);
+    }
+    let mid = by_x.len() / 2;
+    let split = by_x[mid].x();
+    let (left, left_y) = recurse(&by_x[..mid]);
+    let (right, right_y) = recurse(&by_x[mid..]);
+    let mut best = closer(left, right);
+    let mut by_y = Vec::with_capacity(by_x.len());
+    let (mut i, mut j) = (0, 0);
+    while i < left_y.len() || j < right_y.len() {
+        if j == right_y.len() || (i < left_y.len() && left_y[i].y() <= right_y[j].y()) {
+            by_y.push(left_y[i]);
+            i += 1;
+        } else {
+            by_y.push(right_y[j]);
+            j += 1;
+        }
+    }
+    // a closer pair straddling the split lies within the strip either side of it, and each point
+    // in the strip need only be compared with the few above it which are less than `best` higher
+    let strip: Vec<Point<T>> = by_y.iter().filter(|p| (p.x() - split).abs() < best.2).cloned().collect();
+    for (idx, a) in strip.iter().enumerate() {
+        for b in &strip[idx + 1..] {
+            if b.y() - a.y() >= best.2 {
+                break;
+            }
+            best = closer(best, (*a, *b, a.distance(b)));
+        }
+    }
+    (best, by_y)
+}
+
+/// Finds the two closest points of a geometry.
+pub trait ClosestPair<T: Float> {
+    /// Returns the two closest Points of a MultiPoint, and the distance between them
+    ///
+    /// This uses the divide and conquer algorithm, which takes O(n log n) time. If several pairs
+    /// are equally close, any one of them may be returned. Returns `None` if there are fewer
+    /// than two Points.
+    ///
+    /// ```
+    /// use geo::{Point, MultiPoint};
+    /// use geo::algorithm::closest_pair::ClosestPair;
+    ///
+    /// let points = MultiPoint(vec![Point::new(0., 0.), Point::new(5., 5.), Point::new(1., 1.), Point::new(9., 0.)]);
+    /// let (a, b, distance) = points.closest_pair().unwrap();
+    ///
+    /// assert_eq!((a, b), (Point::new(0., 0.), Point::new(1., 1.)));
+    /// assert_eq!(distance, 2f64.sqrt());
+    /// ```
+    fn closest_pair(&self) -> Option<(Point<T>, Point<T>, T)>;
+}
+
+impl<T> ClosestPair<T> for MultiPoint<T>
+    where T: Float
+{
+    fn closest_pair(&self) -> Option<(Point<T>, Point<T>, T)> {
+        if self.0.len() < 2 {
+            return None;
+        }
+        let mut by_x = self.0.clone();
+        by_x.sort_by(|a, b| a.x().partial_cmp(&b.x()).unwrap_or(Ordering::Equal));
+        Some(recurse(&by_x).0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, MultiPoint};
+    use super::*;
+
+    #[test]
+    fn closest_pair_test() {
+        let points: MultiPoint<f64> = vec![(2., 3.), (12., 30.), (40., 50.), (5., 1.), (12., 10.), (3., 4.5),
+                                           (20., 7.), (25., 25.), (33., 2.), (7., 42.), (30., 31.), (18., 18.)]
+            .into();
+        let (a, b, distance) = points.closest_pair().unwrap();
+        assert_eq!((a, b), (Point::new(2., 3.), Point::new(3., 4.5)));
+        assert_relative_eq!(distance, 3.25f64.sqrt());
+        // the same as comparing every pair
+        let brute = points.0
+            .iter()
+            .flat_map(|a| points.0.iter().filter(move |b| *b != a).map(move |b| a.distance(b)))
+            .fold(f64::INFINITY, f64::min);
+        assert_eq!(distance, brute);
+    }
+    #[test]
+    fn closest_pair_across_split_test() {
+        // the closest pair straddles the middle of the points, sorted by x
+        let points: MultiPoint<f64> = vec![(0., 0.), (1., 10.), (2., 20.), (3.9, 5.), (4.1, 5.2), (6., 0.),
+                                           (7., 10.), (8., 20.)]
+            .into();
+        let (a, b, distance) = points.closest_pair().unwrap();
+        assert_eq!((a, b), (Point::new(3.9, 5.), Point::new(4.1, 5.2)));
+        assert_relative_eq!(distance, 0.08f64.sqrt());
+    }
+    #[test]
+    fn closest_pair_degenerate_test() {
+        assert!(MultiPoint::<f64>(vec![]).closest_pair().is_none());
+        assert!(MultiPoint(vec![Point::new(1., 1.)]).closest_pair().is_none());
+        let duplicate = MultiPoint(vec![Point::new(1., 1.), Point::new(3., 0.), Point::new(1., 1.)]);
+        assert_eq!(duplicate.closest_pair().unwrap().2, 0.);
+    }
+}
@@ -0,0 +1,135 @@
+use num_traits::Float;
+use types::{Point, Line, LineString, Polygon};
+use algorithm::distance::Distance;
+use algorithm::kernels::project_onto_segment;
+
+/// The nearest point of a geometry to a query Point.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Closest<T>
+    where T: Float
+{
+    /// The query Point lies on the geometry.
+    Intersection(Point<T>),
+    /// The nearest point of the geometry, which doesn't include the query Point.
+    SinglePoint(Point<T>),
+    /// The geometry is empty, so has no nearest point.
+    Indeterminate,
+}
+
+// the nearest of the projections of p onto a set of segments, preferring the first
+fn nearest<T, I>(lines: I, p: &Point<T>) -> Closest<T>
+    where T: Float,
+          I: Iterator<Item = Line<T>>
+{
+    let mut best: Option<(Point<T>, T)> = None;
+    for line in lines {
+        let (candidate, _) = project_onto_segment(p, &line);
+        let distance = candidate.distance(p);
+        let nearer = match best {
+            Some((_, closest)) => distance < closest,
+            None => true,
+        };
+        if nearer {
+            best = Some((candidate, distance));
+        }
+    }
+    match best {
+        Some((_, distance)) if distance == T::zero() => Closest::Intersection(*p),
+        Some((candidate, _)) => Closest::SinglePoint(candidate),
+        None => Closest::Indeterminate,
+    }
+}
+
+/// Finds the nearest point of a geometry to a Point.
+pub trait ClosestPoint<T: Float> {
+    /// Returns the point of a geometry nearest to a Point, for snapping it onto the geometry
+    ///
+    /// The Point is projected onto each segment, clamped to its ends, and the nearest of the
+    /// projections is kept; if several are equally near, the first is. A Point lying on the
+    /// geometry is returned as an `Intersection`, and an empty geometry gives `Indeterminate`. A
+    /// Point is snapped onto the boundary of a Polygon, whether it lies inside or outside it.
+    ///
+    /// ```
+    /// use geo::{Point, Line};
+    /// use geo::algorithm::closest_point::{ClosestPoint, Closest};
+    ///
+    /// let line = Line::new(Point::new(0., 0.), Point::new(4., 0.));
+    ///
+    /// assert_eq!(line.closest_point(&Point::new(1., 3.)), Closest::SinglePoint(Point::new(1., 0.)));
+    /// assert_eq!(line.closest_point(&Point::new(6., 1.)), Closest::SinglePoint(Point::new(4., 0.)));
+    /// assert_eq!(line.closest_point(&Point::new(2., 0.)), Closest::Intersection(Point::new(2., 0.)));
+    /// ```
+    fn closest_point(&self, p: &Point<T>) -> Closest<T>;
+}
+
+impl<T> ClosestPoint<T> for Line<T>
+    where T: Float
+{
+    fn closest_point(&self, p: &Point<T>) -> Closest<T> {
+        nearest(::std::iter::once(*self), p)
+    }
+}
+
+impl<T> ClosestPoint<T> for LineString<T>
+    where T: Float
+{
+    fn closest_point(&self, p: &Point<T>) -> Closest<T> {
+        match self.0.len() {
+            0 => Closest::Indeterminate,
+            1 if self.0[0] == *p => Closest::Intersection(*p),
+            1 => Closest::SinglePoint(self.0[0]),
+            _ => nearest(self.lines(), p),
+        }
+    }
+}
+
+impl<T> ClosestPoint<T> for Polygon<T>
+    where T: Float
+{
+    fn closest_point(&self, p: &Point<T>) -> Closest<T> {
+        nearest(self.exterior.lines().chain(self.interiors.iter().flat_map(|ring| ring.lines())), p)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, Line, LineString, Polygon};
+    use super::*;
+
+    #[test]
+    fn closest_point_line_test() {
+        // onto a diagonal
+        let line = Line::new(Point::new(0., 0.), Point::new(4., 4.));
+        assert_eq!(line.closest_point(&Point::new(0., 4.)), Closest::SinglePoint(Point::new(2., 2.)));
+        // with the projection beyond either end clamped to it
+        assert_eq!(line.closest_point(&Point::new(-1., -3.)), Closest::SinglePoint(Point::new(0., 0.)));
+        assert_eq!(line.closest_point(&Point::new(7., 5.)), Closest::SinglePoint(Point::new(4., 4.)));
+        assert_eq!(line.closest_point(&Point::new(1., 1.)), Closest::Intersection(Point::new(1., 1.)));
+        let point = Line::new(Point::new(1., 1.), Point::new(1., 1.));
+        assert_eq!(point.closest_point(&Point::new(2., 2.)), Closest::SinglePoint(Point::new(1., 1.)));
+    }
+    #[test]
+    fn closest_point_linestring_test() {
+        let ls: LineString<f64> = vec![(0., 0.), (2., 0.), (2., 4.), (5., 4.)].into();
+        assert_eq!(ls.closest_point(&Point::new(2.5, 3.)), Closest::SinglePoint(Point::new(2., 3.)));
+        assert_eq!(ls.closest_point(&Point::new(7., 5.)), Closest::SinglePoint(Point::new(5., 4.)));
+        assert_eq!(ls.closest_point(&Point::new(2., 4.)), Closest::Intersection(Point::new(2., 4.)));
+        assert_eq!(LineString::<f64>(vec![]).closest_point(&Point::new(1., 1.)), Closest::Indeterminate);
+        let single = LineString(vec![Point::new(1., 1.)]);
+        assert_eq!(single.closest_point(&Point::new(0., 0.)), Closest::SinglePoint(Point::new(1., 1.)));
+    }
+    #[test]
+    fn closest_point_polygon_test() {
+        let exterior: LineString<f64> = vec![(0., 0.), (6., 0.), (6., 6.), (0., 6.), (0., 0.)].into();
+        let hole: LineString<f64> = vec![(2., 2.), (2., 4.), (4., 4.), (4., 2.), (2., 2.)].into();
+        let poly = Polygon::new(exterior, vec![hole]);
+        assert_eq!(poly.closest_point(&Point::new(8., 3.)), Closest::SinglePoint(Point::new(6., 3.)));
+        // inside, on the boundary, and in the hole
+        assert_eq!(poly.closest_point(&Point::new(1., 1.5)), Closest::SinglePoint(Point::new(0., 1.5)));
+        assert_eq!(poly.closest_point(&Point::new(3., 4.5)), Closest::SinglePoint(Point::new(3., 4.)));
+        assert_eq!(poly.closest_point(&Point::new(0., 3.)), Closest::Intersection(Point::new(0., 3.)));
+        assert_eq!(poly.closest_point(&Point::new(3., 2.5)), Closest::SinglePoint(Point::new(3., 2.)));
+        let empty = Polygon::<f64>::new(LineString(vec![]), vec![]);
+        assert_eq!(empty.closest_point(&Point::new(1., 1.)), Closest::Indeterminate);
+    }
+}
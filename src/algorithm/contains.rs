@@ -3,6 +3,7 @@ use num_traits::{Float, ToPrimitive};
 use types::{COORD_PRECISION, Point, Line, LineString, Polygon, MultiPolygon, Bbox};
 use algorithm::intersects::Intersects;
 use algorithm::distance::Distance;
+use algorithm::kernels::crossed_edges;
 
 ///  Checks if the geometry A is completely inside the B geometry.
 
@@ -146,29 +147,12 @@ fn get_position<T>(p: &Point<T>, linestring: &LineString<T>) -> PositionPoint
     if linestring.0.is_empty() {
         return PositionPoint::Outside;
     }
-    // Point is on linestring
-    if linestring.contains(p) {
+    // Point is on linestring, including along a segment which isn't axis-aligned
+    if linestring.contains(p) || linestring.lines().any(|line| line.intersects(p)) {
         return PositionPoint::OnBoundary;
     }
-
-    let mut xints = T::zero();
-    let mut crossings = 0;
-    for line in linestring.lines() {
-        if p.y() > line.start.y().min(line.end.y()) {
-            if p.y() <= line.start.y().max(line.end.y()) {
-                if p.x() <= line.start.x().max(line.end.x()) {
-                    if line.start.y() != line.end.y() {
-                        xints = (p.y() - line.start.y()) * (line.end.x() - line.start.x()) /
-                                (line.end.y() - line.start.y()) + line.start.x();
-                    }
-                    if (line.start.x() == line.end.x()) || (p.x() <= xints) {
-                        crossings += 1;
-                    }
-                }
-            }
-        }
-    }
-    if crossings % 2 == 1 {
+    // count the segments crossed by a ray cast from the point towards positive x
+    if crossed_edges(p, linestring).count() % 2 == 1 {
         PositionPoint::Inside
     } else {
         PositionPoint::Outside
@@ -250,11 +234,174 @@ impl<T> Contains<Bbox<T>> for Bbox<T>
     }
 }
 
+/// Checks if the geometry A is inside the B geometry, allowing for a small positional error.
+pub trait ContainsWithin<T: Float> {
+    /// Checks if every point of a LineString lies within `tol` of a Line
+    ///
+    /// Unlike `Line::contains`, which requires each point to lie exactly on the Line, this
+    /// accepts points up to a distance of `tol` away from it, so that nearly-collinear points
+    /// (from a GPS track, for instance) count as being on the Line. The distance is measured to
+    /// the segment between the Line's endpoints, so points beyond either end must also lie
+    /// within `tol` of that end.
+    ///
+    /// ```
+    /// use geo::{Point, Line, LineString};
+    /// use geo::algorithm::contains::ContainsWithin;
+    ///
+    /// let line = Line::new(Point::new(0., 0.), Point::new(10., 0.));
+    /// let track = LineString(vec![Point::new(1., 0.001), Point::new(6., -0.002)]);
+    ///
+    /// assert!(line.contains_linestring_within(&track, 0.01));
+    /// assert!(!line.contains_linestring_within(&track, 0.001));
+    /// ```
+    fn contains_linestring_within(&self, ls: &LineString<T>, tol: T) -> bool;
+
+    /// Checks if a Point lies within `tol` of a Line
+    ///
+    /// This lets the caller choose how close counts as being on the Line, rather than the exact
+    /// test made by `Line::contains`. As for `contains_linestring_within`, the distance is
+    /// measured to the segment between the Line's endpoints.
+    ///
+    /// ```
+    /// use geo::{Point, Line};
+    /// use geo::algorithm::contains::ContainsWithin;
+    ///
+    /// let line = Line::new(Point::new(0., 0.), Point::new(10., 0.));
+    ///
+    /// assert!(line.contains_point_within(&Point::new(4., 0.001), 0.01));
+    /// assert!(!line.contains_point_within(&Point::new(4., 0.001), 0.0001));
+    /// ```
+    fn contains_point_within(&self, p: &Point<T>, tol: T) -> bool;
+}
+
+impl<T> ContainsWithin<T> for Line<T>
+    where T: Float
+{
+    fn contains_linestring_within(&self, ls: &LineString<T>, tol: T) -> bool {
+        ls.0.iter().all(|p| self.contains_point_within(p, tol))
+    }
+
+    fn contains_point_within(&self, p: &Point<T>, tol: T) -> bool {
+        p.distance(self) <= tol
+    }
+}
+
+/// Checks if a geometry contains a Point, choosing whether its boundary counts.
+pub trait ContainsBoundary<T: Float> {
+    /// Checks if a Polygon contains a Point, counting Points on the boundary of its exterior or
+    /// interiors as contained if `include_boundary` is set
+    ///
+    /// With `include_boundary` unset, this is the same as `contains`, treating the Polygon as
+    /// an open set. With it set, the Polygon is treated as a closed set.
+    ///
+    /// ```
+    /// use geo::{Point, Polygon};
+    /// use geo::algorithm::contains::ContainsBoundary;
+    ///
+    /// let square = Polygon::new(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)].into(), vec![]);
+    ///
+    /// assert!(square.contains_point_boundary(&Point::new(2., 1.), true));
+    /// assert!(!square.contains_point_boundary(&Point::new(2., 1.), false));
+    /// ```
+    fn contains_point_boundary(&self, p: &Point<T>, include_boundary: bool) -> bool;
+}
+
+impl<T> ContainsBoundary<T> for Polygon<T>
+    where T: Float
+{
+    fn contains_point_boundary(&self, p: &Point<T>, include_boundary: bool) -> bool {
+        match get_position(p, &self.exterior) {
+            PositionPoint::OnBoundary => include_boundary,
+            PositionPoint::Outside => false,
+            PositionPoint::Inside => {
+                for ring in &self.interiors {
+                    match get_position(p, ring) {
+                        PositionPoint::OnBoundary => return include_boundary,
+                        PositionPoint::Inside => return false,
+                        PositionPoint::Outside => {}
+                    }
+                }
+                true
+            }
+        }
+    }
+}
+
+/// Locates a Point relative to a geometry's boundary.
+pub trait ContainsAndDistance<T: Float> {
+    /// Checks if a Polygon contains a Point, and finds the signed distance from the Point to
+    /// the Polygon's boundary, both at once
+    ///
+    /// The first value is the same as `contains` would return. The second is the distance to
+    /// the nearest edge of the exterior or an interior, negated if the Point is contained, so it
+    /// is negative inside the Polygon, positive outside it and zero on its boundary. This is
+    /// cheaper than calling `contains` and `distance` separately in a tight loop. For a Polygon
+    /// with an empty exterior, the distance is infinite.
+    ///
+    /// ```
+    /// use geo::{Point, Polygon};
+    /// use geo::algorithm::contains::ContainsAndDistance;
+    ///
+    /// let square = Polygon::new(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into(), vec![]);
+    ///
+    /// assert_eq!(square.contains_and_signed_distance(&Point::new(1., 2.)), (true, -1.));
+    /// assert_eq!(square.contains_and_signed_distance(&Point::new(7., 0.)), (false, 3.));
+    /// ```
+    fn contains_and_signed_distance(&self, p: &Point<T>) -> (bool, T);
+}
+
+impl<T> ContainsAndDistance<T> for Polygon<T>
+    where T: Float
+{
+    fn contains_and_signed_distance(&self, p: &Point<T>) -> (bool, T) {
+        let mut distance = T::infinity();
+        let mut on_boundary = false;
+        // whether each ring, exterior first, encloses the point
+        let mut enclosed = vec![];
+        for ring in Some(&self.exterior).into_iter().chain(self.interiors.iter()) {
+            if ring.0.len() == 1 {
+                distance = distance.min(p.distance(&ring.0[0]));
+                on_boundary |= ring.0[0] == *p;
+            }
+            for line in ring.lines() {
+                distance = distance.min(p.distance(&line));
+                on_boundary |= line.intersects(p);
+            }
+            enclosed.push(crossed_edges(p, ring).count() % 2 == 1);
+        }
+        if on_boundary {
+            return (false, T::zero());
+        }
+        let inside = enclosed[0] && !enclosed[1..].iter().any(|&odd| odd);
+        (inside, if inside { -distance } else { distance })
+    }
+}
+
+/// Checks if a geometry contains every Point of a slice
+///
+/// An empty slice is contained by any geometry.
+///
+/// ```
+/// use geo::{Point, Polygon};
+/// use geo::algorithm::contains::contains_all;
+///
+/// let square = Polygon::new(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)].into(), vec![]);
+///
+/// assert!(contains_all(&square, &[Point::new(1., 1.), Point::new(0.5, 1.5)]));
+/// assert!(!contains_all(&square, &[Point::new(1., 1.), Point::new(3., 1.)]));
+/// ```
+pub fn contains_all<T, G>(g: &G, pts: &[Point<T>]) -> bool
+    where T: Float,
+          G: Contains<Point<T>>
+{
+    pts.iter().all(|p| g.contains(p))
+}
 
 #[cfg(test)]
 mod test {
     use types::{Coordinate, Point, Line, LineString, Polygon, MultiPolygon, Bbox};
-    use algorithm::contains::Contains;
+    use algorithm::contains::{Contains, ContainsAndDistance, ContainsBoundary, ContainsWithin, contains_all};
+    use algorithm::distance::Distance;
     #[test]
     // V doesn't contain rect because two of its edges intersect with V's exterior boundary
     fn polygon_does_not_contain_polygon() {
@@ -497,4 +644,120 @@ mod test {
         assert!(linestring1.contains(&line0));
         assert!(!linestring2.contains(&line0));
     }
+    #[test]
+    fn linestring_in_line_within_test() {
+        let line = Line::new(Point::new(0., 0.), Point::new(10., 10.));
+        // points jittered slightly off the line
+        let jittered = LineString(vec![Point::new(1., 1. + 1e-6), Point::new(5., 5. - 1e-6), Point::new(9. + 1e-6, 9.)]);
+        assert!(!line.contains(&jittered));
+        assert!(line.contains_linestring_within(&jittered, 1e-5));
+        assert!(!line.contains_linestring_within(&jittered, 0.));
+        // a point on the infinite line, but past the end of the segment
+        let beyond = LineString(vec![Point::new(5., 5.), Point::new(11., 11.)]);
+        assert!(!line.contains_linestring_within(&beyond, 1e-5));
+        assert!(line.contains_linestring_within(&LineString(vec![]), 0.));
+    }
+    #[test]
+    fn point_in_line_within_test() {
+        let line = Line::new(Point::new(0., 0.), Point::new(10., 0.));
+        let off = Point::new(3., 1e-6);
+        assert!(!line.contains(&off));
+        assert!(line.contains_point_within(&off, 1e-5));
+        assert!(!line.contains_point_within(&off, 0.));
+        assert!(line.contains_point_within(&Point::new(3., 0.), 0.));
+        // past the end of the segment, the distance is to the endpoint
+        assert!(line.contains_point_within(&Point::new(10. + 1e-6, 0.), 1e-5));
+        assert!(!line.contains_point_within(&Point::new(11., 0.), 1e-5));
+    }
+    #[test]
+    fn point_in_polygon_aligned_with_vertices_test() {
+        // a sawtooth top edge, with vertices at y = 1 and y = 2
+        let poly = Polygon::new(vec![(0., 0.), (6., 0.), (6., 2.), (5., 1.), (4., 2.), (3., 1.),
+                                     (2., 2.), (1., 1.), (0., 2.), (0., 0.)].into(),
+                                vec![]);
+        // rays from these points pass through three or four vertices
+        assert!(poly.contains(&Point::new(0.5, 1.)));
+        assert!(poly.contains(&Point::new(2.5, 1.)));
+        assert!(!poly.contains(&Point::new(-1., 1.)));
+        assert!(!poly.contains(&Point::new(7., 1.)));
+        assert!(!poly.contains(&Point::new(1., 2.)));
+        assert!(!poly.contains(&Point::new(3., 2.)));
+        assert!(!poly.contains(&Point::new(-1., 2.)));
+        assert!(!poly.contains(&Point::new(-1., 0.)));
+        // on the boundary, and not contained
+        assert!(!poly.contains(&Point::new(1., 1.)));
+        assert!(!poly.contains(&Point::new(3., 0.)));
+        assert!(!poly.contains(&Point::new(0.5, 1.5)));
+        assert!(!poly.contains(&Point::new(1.5, 1.5)));
+        assert!(!poly.contains(&Point::new(2.5, 1.5)));
+        assert!(!poly.contains(&Point::new(5.5, 1.5)));
+    }
+    #[test]
+    fn point_on_slanted_edge_test() {
+        // a diamond, whose vertices share their y coordinates in pairs
+        let diamond = Polygon::new(vec![(1., 0.), (2., 1.), (1., 2.), (0., 1.), (1., 0.)].into(), vec![]);
+        assert!(diamond.contains(&Point::new(1., 1.)));
+        assert!(!diamond.contains(&Point::new(0.5, 0.5)));
+        assert!(!diamond.contains(&Point::new(1.5, 1.5)));
+        assert!(!diamond.contains(&Point::new(0.5, 1.5)));
+        assert!(!diamond.contains(&Point::new(3., 1.)));
+        assert!(!diamond.contains(&Point::new(-1., 1.)));
+    }
+    #[test]
+    fn contains_and_signed_distance_test() {
+        let exterior: LineString<f64> = vec![(0., 0.), (6., 0.), (6., 6.), (0., 6.), (0., 0.)].into();
+        let hole: LineString<f64> = vec![(2., 2.), (4., 1.), (4., 4.), (2., 4.), (2., 2.)].into();
+        let poly = Polygon::new(exterior, vec![hole]);
+        let points = vec![Point::new(1., 1.), Point::new(3., 3.), Point::new(5., 5.5), Point::new(-1., 3.),
+                          Point::new(3., 0.), Point::new(2., 3.), Point::new(8., 9.), Point::new(4.5, 1.)];
+        for p in &points {
+            let contained = poly.contains(p);
+            let distance = Some(&poly.exterior).into_iter()
+                .chain(poly.interiors.iter())
+                .map(|ring| p.distance(ring))
+                .fold(f64::INFINITY, f64::min);
+            let signed = if contained { -distance } else { distance };
+            let (paired_contained, paired_signed) = poly.contains_and_signed_distance(p);
+            assert_eq!(paired_contained, contained);
+            assert_relative_eq!(paired_signed, signed);
+        }
+        assert_eq!(poly.contains_and_signed_distance(&Point::new(1., 1.)), (true, -1.));
+        assert_eq!(poly.contains_and_signed_distance(&Point::new(3., 3.)), (false, 1.));
+        assert_eq!(poly.contains_and_signed_distance(&Point::new(3., 0.)), (false, 0.));
+        let empty = Polygon::<f64>::new(LineString(vec![]), vec![]);
+        assert_eq!(empty.contains_and_signed_distance(&Point::new(0., 0.)), (false, f64::INFINITY));
+    }
+    #[test]
+    fn contains_point_boundary_test() {
+        let exterior: LineString<f64> = vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into();
+        let hole: LineString<f64> = vec![(1., 1.), (1., 3.), (3., 3.), (3., 1.), (1., 1.)].into();
+        let poly = Polygon::new(exterior, vec![hole]);
+        // on the exterior, at a vertex, and on the hole
+        for p in &[Point::new(4., 2.), Point::new(0., 0.), Point::new(2., 3.)] {
+            assert!(poly.contains_point_boundary(p, true));
+            assert!(!poly.contains_point_boundary(p, false));
+            assert!(!poly.contains(p));
+        }
+        // strictly inside, inside the hole, and outside
+        for &(p, inside) in &[(Point::new(0.5, 2.), true), (Point::new(2., 2.), false), (Point::new(5., 2.), false)] {
+            assert_eq!(poly.contains_point_boundary(&p, true), inside);
+            assert_eq!(poly.contains_point_boundary(&p, false), inside);
+            assert_eq!(poly.contains(&p), inside);
+        }
+    }
+    #[test]
+    fn contains_all_test() {
+        let poly = Polygon::new(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into(),
+                                vec![vec![(1., 1.), (1., 2.), (2., 2.), (2., 1.), (1., 1.)].into()]);
+        let inside = [Point::new(3., 3.), Point::new(0.5, 3.5), Point::new(3., 1.)];
+        assert!(contains_all(&poly, &inside));
+        // one Point in the hole is enough to fail
+        let mixed = [Point::new(3., 3.), Point::new(1.5, 1.5), Point::new(3., 1.)];
+        assert!(!contains_all(&poly, &mixed));
+        assert!(contains_all(&poly, &[]));
+        // any geometry containing Points will do
+        let line = Line::new(Point::new(0., 0.), Point::new(2., 2.));
+        assert!(contains_all(&line, &[Point::new(1., 1.), Point::new(0.5, 0.5)]));
+        assert!(!contains_all(&line, &[Point::new(1., 1.), Point::new(1., 0.)]));
+    }
 }
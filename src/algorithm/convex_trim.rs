@@ -0,0 +1,111 @@
+use num_traits::Float;
+use types::{LineString, Polygon};
+use algorithm::area::Winding;
+use algorithm::kernels::{cross, in_triangle};
+
+/// Makes a Polygon more convex by filling in its small notches.
+pub trait ConvexTrim<T: Float> {
+    /// Returns a copy of a Polygon whose exterior has had its small reflex notches replaced by
+    /// convex shortcuts
+    ///
+    /// A reflex vertex of the exterior is removed, joining its neighbours directly, when the
+    /// triangle this adds to the Polygon has less than `max_area_fraction` of the area enclosed
+    /// by the original exterior, and no other vertex lies inside it. This is repeated, removing
+    /// the reflex vertex adding the least area first, until no more can be removed. A wide notch
+    /// may take several such shortcuts to fill, each of which must be small enough. The result
+    /// is a simplification lying between the Polygon and its convex hull, which is reached as
+    /// `max_area_fraction` grows. The interiors are left unchanged.
+    ///
+    /// ```
+    /// use geo::Polygon;
+    /// use geo::algorithm::convex_trim::ConvexTrim;
+    ///
+    /// // a square with a shallow dent in its top edge
+    /// let poly = Polygon::new(vec![(0., 0.), (4., 0.), (4., 4.), (2., 3.9), (0., 4.), (0., 0.)].into(), vec![]);
+    /// let trimmed = poly.convex_trim(0.05);
+    ///
+    /// assert_eq!(trimmed.exterior, vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into());
+    /// ```
+    fn convex_trim(&self, max_area_fraction: T) -> Polygon<T>;
+}
+
+impl<T> ConvexTrim<T> for Polygon<T>
+    where T: Float
+{
+    fn convex_trim(&self, max_area_fraction: T) -> Polygon<T> {
+        let mut points = self.exterior.0.clone();
+        if points.len() > 1 && points.first() == points.last() {
+            points.pop();
+        }
+        if points.len() < 4 {
+            return self.clone();
+        }
+        let area = LineString(points.iter().chain(points.first()).cloned().collect()).signed_area();
+        // the sign of a convex turn along the ring
+        let turn = if area < T::zero() { -T::one() } else { T::one() };
+        // cross gives twice the area of the triangle each removal adds
+        let max_added = (area + area).abs() * max_area_fraction;
+        while points.len() > 3 {
+            let n = points.len();
+            let mut smallest: Option<(usize, T)> = None;
+            for idx in 0..n {
+                let (a, v, b) = (points[(idx + n - 1) % n], points[idx], points[(idx + 1) % n]);
+                let added = -cross(&a, &v, &b) * turn;
+                let beaten = match smallest {
+                    Some((_, least)) => least <= added,
+                    None => false,
+                };
+                if added <= T::zero() || added >= max_added || beaten {
+                    continue;
+                }
+                if !points.iter().any(|p| *p != a && *p != v && *p != b && in_triangle(p, &a, &v, &b)) {
+                    smallest = Some((idx, added));
+                }
+            }
+            match smallest {
+                Some((idx, _)) => {
+                    points.remove(idx);
+                }
+                None => break,
+            }
+        }
+        let first = points[0];
+        points.push(first);
+        Polygon::new(LineString(points), self.interiors.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{LineString, Polygon};
+    use algorithm::area::Area;
+    use super::*;
+
+    #[test]
+    fn convex_trim_test() {
+        // a 10 by 10 square, with a small notch in its bottom edge and a large one in its top
+        let poly = Polygon::new(vec![(0., 0.), (4., 0.), (5., 0.5), (6., 0.), (10., 0.), (10., 10.), (7., 10.),
+                                     (7., 4.), (3., 4.), (3., 10.), (0., 10.), (0., 0.)]
+                                    .into(),
+                                vec![]);
+        let trimmed = poly.convex_trim(0.05);
+        assert_eq!(trimmed.exterior,
+                   vec![(0., 0.), (4., 0.), (6., 0.), (10., 0.), (10., 10.), (7., 10.), (7., 4.), (3., 4.), (3., 10.),
+                        (0., 10.), (0., 0.)]
+                       .into());
+        assert_relative_eq!(trimmed.area() - poly.area(), 0.5);
+        // a larger allowance fills the large notch too, by two shortcuts of 12 each, against 75.5 in all
+        let filled = poly.convex_trim(0.2);
+        assert_relative_eq!(filled.area(), 100.);
+        assert_eq!(poly.convex_trim(0.), poly);
+    }
+    #[test]
+    fn convex_trim_clockwise_test() {
+        let poly = Polygon::new(vec![(0., 0.), (0., 4.), (2., 3.9), (4., 4.), (4., 0.), (0., 0.)].into(), vec![]);
+        let trimmed = poly.convex_trim(0.05);
+        assert_eq!(trimmed.exterior, vec![(0., 0.), (0., 4.), (4., 4.), (4., 0.), (0., 0.)].into());
+        let triangle = Polygon::new(vec![(0., 0.), (1., 0.), (0., 1.), (0., 0.)].into(), vec![]);
+        assert_eq!(triangle.convex_trim(1.), triangle);
+        assert_eq!(Polygon::<f64>::new(LineString(vec![]), vec![]).convex_trim(1.).exterior, LineString(vec![]));
+    }
+}
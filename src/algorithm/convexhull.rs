@@ -1,6 +1,7 @@
 use num_traits::Float;
 use types::{Point, Polygon, MultiPolygon, LineString, MultiPoint, MultiLineString};
 use std::mem;
+use algorithm::kernels::lexicographic;
 
 fn swap_remove_to_first<'a, T>(slice: &mut &'a mut [T], idx: usize) -> &'a mut T {
     let tmp = mem::replace(slice, &mut []);
@@ -69,13 +70,46 @@ fn pseudo_distance<T>(p_a: &Point<T>, p_b: &Point<T>, p_c: &Point<T>) -> T
     if dist < T::zero() { -dist } else { dist }
 }
 
+// The hull of fewer than four points, or of collinear points, which QuickHull doesn't handle.
+// Points which are all equal give a ring of two copies of the point, collinear ones a ring
+// running from one end of the line to the other and back, and a triangle is oriented
+// counter-clockwise. Returns `None` for any other set of points.
+fn degenerate_hull<T>(points: &[Point<T>]) -> Option<Vec<Point<T>>>
+    where T: Float
+{
+    let first = match points.first() {
+        Some(first) => *first,
+        None => return Some(vec![]),
+    };
+    let second = match points.iter().find(|p| **p != first) {
+        Some(second) => *second,
+        None => return Some(vec![first, first]),
+    };
+    let third = points.iter().find(|p| cross_prod(&first, &second, p) != T::zero());
+    match third {
+        None => {
+            let min = *points.iter().min_by(|a, b| lexicographic(a, b)).unwrap();
+            let max = *points.iter().max_by(|a, b| lexicographic(a, b)).unwrap();
+            Some(vec![max, min, max])
+        }
+        Some(&third) if points.iter().all(|p| *p == first || *p == second || *p == third) => {
+            if point_location(&first, &second, &third) {
+                Some(vec![first, second, third, first])
+            } else {
+                Some(vec![first, third, second, first])
+            }
+        }
+        Some(_) => None,
+    }
+}
+
 // Adapted from http://www.ahristov.com/tutorial/geometry-games/convex-hull.html
 fn quick_hull<T>(mut points: &mut [Point<T>]) -> Vec<Point<T>>
     where T: Float
 {
-    // can't build a hull from fewer than four points
-    if points.len() < 4 {
-        return points.to_vec();
+    // can't build a hull from fewer than three distinct points, or collinear ones
+    if let Some(hull) = degenerate_hull(points) {
+        return hull;
     }
     let mut hull = vec![];
     let min = swap_remove_to_first(&mut points, 0);
@@ -137,6 +171,10 @@ fn hull_set<T>(p_a: &Point<T>, p_b: &Point<T>, mut set: &mut [Point<T>], hull: &
 pub trait ConvexHull<T> {
     /// Returns the convex hull of a Polygon. The hull is always oriented counter-clockwise.
     ///
+    /// Degenerate inputs still give a closed ring: no points give an empty ring, a single
+    /// distinct point a ring of two copies of it, and collinear points a ring from one end of
+    /// the line to the other and back.
+    ///
     /// This implementation uses the QuickHull algorithm,
     /// based on [Barber, C. Bradford; Dobkin, David P.; Huhdanpaa, Hannu (1 December 1996)](https://dx.doi.org/10.1145%2F235815.235821)
     /// Original paper here: http://www.cs.princeton.edu/~dpd/Papers/BarberDobkinHuhdanpaa.pdf
@@ -205,9 +243,95 @@ impl<T> ConvexHull<T> for MultiPoint<T>
     }
 }
 
+/// Maintains the convex hull of a stream of Points, as they are inserted one at a time
+///
+/// ```
+/// use geo::Point;
+/// use geo::algorithm::convexhull::ConvexHullBuilder;
+///
+/// let mut builder = ConvexHullBuilder::new();
+/// for &(x, y) in &[(0., 0.), (2., 0.), (1., 1.), (2., 2.), (0., 2.)] {
+///     builder.insert(Point::new(x, y));
+/// }
+///
+/// assert_eq!(builder.hull().exterior, vec![(0., 2.), (0., 0.), (2., 0.), (2., 2.), (0., 2.)].into());
+/// ```
+#[derive(PartialEq, Clone, Debug)]
+pub struct ConvexHullBuilder<T>
+    where T: Float
+{
+    // the vertices of the hull, without repeating the first; counter-clockwise once there are
+    // three of them, and otherwise the distinct vertices of a degenerate hull
+    vertices: Vec<Point<T>>,
+}
+
+impl<T> ConvexHullBuilder<T>
+    where T: Float
+{
+    /// Creates a builder with an empty hull.
+    pub fn new() -> ConvexHullBuilder<T> {
+        ConvexHullBuilder { vertices: vec![] }
+    }
+
+    /// Adds a Point, growing the hull to include it
+    ///
+    /// This takes time proportional to the number of vertices of the hull. Inserting a Point
+    /// inside the hull, or on its boundary, leaves it unchanged.
+    pub fn insert(&mut self, p: Point<T>) {
+        let n = self.vertices.len();
+        if n < 3 {
+            self.vertices.push(p);
+            let mut hull = degenerate_hull(&self.vertices).unwrap();
+            hull.pop();
+            hull.dedup();
+            self.vertices = hull;
+            return;
+        }
+        let turns: Vec<T> = (0..n).map(|idx| cross_prod(&self.vertices[idx], &self.vertices[(idx + 1) % n], &p)).collect();
+        if turns.iter().all(|turn| *turn >= T::zero()) {
+            return;
+        }
+        // The edges which p lies outside of, or in line with, form a single chain. Its
+        // interior vertices are replaced by p.
+        let visible = |idx: usize| turns[idx % n] <= T::zero();
+        let first = (0..n).find(|&idx| visible(idx) && !visible(idx + n - 1)).unwrap();
+        let last = (0..n).map(|offset| (first + offset) % n).take_while(|&idx| visible(idx)).last().unwrap();
+        let mut vertices = vec![p];
+        let mut idx = (last + 1) % n;
+        while idx != first {
+            vertices.push(self.vertices[idx]);
+            idx = (idx + 1) % n;
+        }
+        vertices.push(self.vertices[first]);
+        self.vertices = vertices;
+    }
+
+    /// Returns the hull of the Points inserted so far, oriented counter-clockwise
+    ///
+    /// Degenerate hulls are the same as those `convex_hull` returns: an empty ring if no Points
+    /// have been inserted, two copies of the Point if they're all equal, and a ring from one end
+    /// of a line to the other and back if they're collinear.
+    pub fn hull(&self) -> Polygon<T> {
+        let mut ring = self.vertices.clone();
+        if let Some(&first) = ring.first() {
+            ring.push(first);
+        }
+        Polygon::new(LineString(ring), vec![])
+    }
+}
+
+impl<T> Default for ConvexHullBuilder<T>
+    where T: Float
+{
+    fn default() -> ConvexHullBuilder<T> {
+        ConvexHullBuilder::new()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use types::Point;
+    use algorithm::canonicalize::Canonicalize;
     use super::*;
 
     #[test]
@@ -359,4 +483,54 @@ mod test {
         let res = mp.convex_hull();
         assert_eq!(res.exterior.0, correct);
     }
+    #[test]
+    fn quick_hull_interior_points_test() {
+        // a square, with points inside it and duplicated corners
+        let mp: MultiPoint<f64> = vec![(1., 1.), (0., 0.), (2., 0.), (0.5, 1.5), (2., 2.), (2., 0.), (0., 2.), (1., 1.)]
+            .into();
+        let correct: LineString<f64> = vec![(2., 0.), (2., 2.), (0., 2.), (0., 0.), (2., 0.)].into();
+        assert_eq!(mp.convex_hull().exterior, correct);
+    }
+    #[test]
+    fn quick_hull_degenerate_test() {
+        assert!(MultiPoint::<f64>(vec![]).convex_hull().exterior.0.is_empty());
+        let single = MultiPoint(vec![Point::new(1., 1.), Point::new(1., 1.), Point::new(1., 1.), Point::new(1., 1.)]);
+        assert_eq!(single.convex_hull().exterior.0, vec![Point::new(1., 1.), Point::new(1., 1.)]);
+        // a vertical line, given out of order and with a duplicate
+        let vertical: MultiPoint<f64> = vec![(0., 2.), (0., 0.), (0., 3.), (0., 1.), (0., 0.)].into();
+        assert_eq!(vertical.convex_hull().exterior, vec![(0., 3.), (0., 0.), (0., 3.)].into());
+        let two: MultiPoint<f64> = vec![(3., 1.), (1., 0.)].into();
+        assert_eq!(two.convex_hull().exterior, vec![(3., 1.), (1., 0.), (3., 1.)].into());
+        // a clockwise triangle, and one with a repeated vertex
+        let triangle: MultiPoint<f64> = vec![(0., 0.), (0., 1.), (1., 0.)].into();
+        assert_eq!(triangle.convex_hull().exterior, vec![(0., 0.), (1., 0.), (0., 1.), (0., 0.)].into());
+        let repeated: MultiPoint<f64> = vec![(0., 0.), (0., 1.), (0., 0.), (1., 0.)].into();
+        assert_eq!(repeated.convex_hull().exterior, vec![(0., 0.), (1., 0.), (0., 1.), (0., 0.)].into());
+    }
+    #[test]
+    fn convex_hull_builder_test() {
+        let coords = include!("test_fixtures/poly1.rs");
+        let points: Vec<Point<f64>> = coords.iter().map(|e| Point::new(e.0, e.1)).collect();
+        let mut builder = ConvexHullBuilder::new();
+        for p in &points {
+            builder.insert(*p);
+        }
+        let batch = MultiPoint(points).convex_hull();
+        assert_eq!(builder.hull().canonicalize(), batch.canonicalize());
+    }
+    #[test]
+    fn convex_hull_builder_degenerate_test() {
+        let mut builder = ConvexHullBuilder::new();
+        assert!(builder.hull().exterior.0.is_empty());
+        builder.insert(Point::new(1., 1.));
+        builder.insert(Point::new(1., 1.));
+        assert_eq!(builder.hull().exterior, vec![(1., 1.), (1., 1.)].into());
+        // collinear, then a point in line with the hull's first edge, then inside it
+        for &(x, y) in &[(3., 1.), (2., 1.), (3., 3.), (4., 1.), (3., 1.5)] {
+            builder.insert(Point::new(x, y));
+            let batch = MultiPoint(builder.vertices.clone()).convex_hull();
+            assert_eq!(builder.hull().canonicalize(), batch.canonicalize());
+        }
+        assert_eq!(builder.hull().canonicalize().exterior, vec![(1., 1.), (4., 1.), (3., 3.), (1., 1.)].into());
+    }
 }
@@ -0,0 +1,106 @@
+use num_traits::Float;
+use types::{Point, Line, LineString, Polygon};
+use algorithm::kernels::{cross, point_along};
+
+// The number of segments of a LineString crossing a line segment. Each vertex is classified by
+// whether it lies strictly to the left of the line, and a segment crosses if its ends are
+// classified differently, so a path passing through the line at a vertex crosses it once, and
+// one touching it and turning back doesn't cross it, or crosses it twice. The crossing must
+// also fall between the line's ends.
+fn crossings<T>(points: &[Point<T>], line: &Line<T>) -> usize
+    where T: Float
+{
+    let (dx, dy) = (line.end.x() - line.start.x(), line.end.y() - line.start.y());
+    let length_squared = dx * dx + dy * dy;
+    if length_squared == T::zero() {
+        return 0;
+    }
+    points.windows(2)
+        .filter(|w| {
+            let (sa, sb) = (cross(&line.start, &line.end, &w[0]), cross(&line.start, &line.end, &w[1]));
+            if (sa > T::zero()) == (sb > T::zero()) {
+                return false;
+            }
+            let t = sa / (sa - sb);
+            let p = point_along(&Line::new(w[0], w[1]), t);
+            let along = ((p.x() - line.start.x()) * dx + (p.y() - line.start.y()) * dy) / length_squared;
+            along >= T::zero() && along <= T::one()
+        })
+        .count()
+}
+
+/// Counts the segments of a geometry crossing a line.
+pub trait CountCrossings<T: Float> {
+    /// Returns the number of segments of a geometry which cross a Line
+    ///
+    /// Each time the geometry passes from one side of the Line to the other counts once, even
+    /// where it passes through the Line at a vertex. Vertices lying on the Line are treated as
+    /// lying to its right, so a geometry touching the Line, at a vertex or along a segment, and
+    /// turning back, is counted either twice or not at all. Crossings of the infinite line
+    /// through the Line beyond its ends don't count, so to count those, extend it past the
+    /// geometry's bounding box. A Polygon counts the segments of each of its rings.
+    ///
+    /// ```
+    /// use geo::{Point, Line, Polygon};
+    /// use geo::algorithm::count_crossings::CountCrossings;
+    ///
+    /// let square = Polygon::new(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into(), vec![]);
+    /// let cut = Line::new(Point::new(-1., 1.), Point::new(5., 3.));
+    ///
+    /// assert_eq!(square.count_crossings(&cut), 2);
+    /// ```
+    fn count_crossings(&self, line: &Line<T>) -> usize;
+}
+
+impl<T> CountCrossings<T> for LineString<T>
+    where T: Float
+{
+    fn count_crossings(&self, line: &Line<T>) -> usize {
+        crossings(&self.0, line)
+    }
+}
+
+impl<T> CountCrossings<T> for Polygon<T>
+    where T: Float
+{
+    fn count_crossings(&self, line: &Line<T>) -> usize {
+        self.interiors.iter().fold(crossings(&self.exterior.0, line), |total, ring| total + crossings(&ring.0, line))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, Line, LineString, Polygon};
+    use super::*;
+
+    #[test]
+    fn count_crossings_square_test() {
+        let square = Polygon::new(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into(), vec![]);
+        // the diagonal passes through two corners
+        assert_eq!(square.count_crossings(&Line::new(Point::new(0., 0.), Point::new(4., 4.))), 2);
+        assert_eq!(square.count_crossings(&Line::new(Point::new(-1., -1.), Point::new(5., 5.))), 2);
+        // stopping inside the square, or short of it
+        assert_eq!(square.count_crossings(&Line::new(Point::new(-1., 2.), Point::new(2., 2.))), 1);
+        assert_eq!(square.count_crossings(&Line::new(Point::new(-3., 2.), Point::new(-1., 2.))), 0);
+        // along an edge, with the square to the left or the right, and touching a corner from outside
+        assert_eq!(square.count_crossings(&Line::new(Point::new(-1., 0.), Point::new(5., 0.))), 2);
+        assert_eq!(square.count_crossings(&Line::new(Point::new(-1., 4.), Point::new(5., 4.))), 0);
+        assert_eq!(square.count_crossings(&Line::new(Point::new(3., 5.), Point::new(5., 3.))), 0);
+        // holes count too
+        let hole: LineString<f64> = vec![(1., 1.), (1., 3.), (3., 3.), (3., 1.), (1., 1.)].into();
+        let frame = Polygon::new(square.exterior.clone(), vec![hole]);
+        assert_eq!(frame.count_crossings(&Line::new(Point::new(-1., 2.), Point::new(5., 2.))), 4);
+    }
+    #[test]
+    fn count_crossings_linestring_test() {
+        // a zig-zag
+        let ls: LineString<f64> = vec![(0., 0.), (1., 2.), (2., 0.), (3., 2.), (4., 0.)].into();
+        assert_eq!(ls.count_crossings(&Line::new(Point::new(-1., 1.), Point::new(5., 1.))), 4);
+        // through its peaks, where it touches the line and turns back, on the line's right
+        let peaks = Line::new(Point::new(-1., 2.), Point::new(5., 2.));
+        assert_eq!(ls.count_crossings(&peaks), 0);
+        assert_eq!(ls.count_crossings(&Line::new(peaks.end, peaks.start)), 4);
+        assert_eq!(ls.count_crossings(&Line::new(Point::new(1., 1.), Point::new(1., 1.))), 0);
+        assert_eq!(LineString::<f64>(vec![]).count_crossings(&peaks), 0);
+    }
+}
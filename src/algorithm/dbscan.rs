@@ -0,0 +1,99 @@
+use num_traits::Float;
+use types::{Point, MultiPoint};
+use algorithm::distance::Distance;
+
+// the indices of the points within eps of the point at idx, including itself
+fn neighbours<T>(points: &[Point<T>], idx: usize, eps: T) -> Vec<usize>
+    where T: Float
+{
+    (0..points.len()).filter(|&other| points[idx].distance(&points[other]) <= eps).collect()
+}
+
+/// Groups the Points of a geometry into clusters by their density.
+pub trait Dbscan<T: Float> {
+    /// Returns the clusters of a MultiPoint found by DBSCAN, as lists of indices into its Points
+    ///
+    /// A Point is a core point if at least `min_points` Points, counting itself, lie within
+    /// Euclidean distance `eps` of it. Each cluster is made up of core points which are
+    /// connected through such neighbourhoods, along with the other Points in their
+    /// neighbourhoods. Points which belong to no cluster are noise, and are left out. Clusters
+    /// are listed in the order of their first Point, and the indices within each are sorted.
+    ///
+    /// A Point within `eps` of core points in two clusters is assigned only to the first of them.
+    /// This takes O(n²) time, as every pair of Points is compared.
+    ///
+    /// ```
+    /// use geo::MultiPoint;
+    /// use geo::algorithm::dbscan::Dbscan;
+    ///
+    /// let points: MultiPoint<f64> = vec![(0., 0.), (0., 1.), (10., 10.), (1., 0.), (10., 11.), (10., 12.)].into();
+    ///
+    /// assert_eq!(points.dbscan(1.5, 3), vec![vec![0, 1, 3], vec![2, 4, 5]]);
+    /// ```
+    fn dbscan(&self, eps: T, min_points: usize) -> Vec<Vec<usize>>;
+}
+
+impl<T> Dbscan<T> for MultiPoint<T>
+    where T: Float
+{
+    fn dbscan(&self, eps: T, min_points: usize) -> Vec<Vec<usize>> {
+        let points = &self.0;
+        let mut assigned = vec![false; points.len()];
+        let mut clusters = vec![];
+        for idx in 0..points.len() {
+            if assigned[idx] {
+                continue;
+            }
+            let seeds = neighbours(points, idx, eps);
+            if seeds.len() < min_points {
+                // noise, unless a later core point reaches it
+                continue;
+            }
+            let mut cluster = vec![];
+            let mut queue = seeds;
+            while let Some(next) = queue.pop() {
+                if assigned[next] {
+                    continue;
+                }
+                assigned[next] = true;
+                cluster.push(next);
+                let reachable = neighbours(points, next, eps);
+                if reachable.len() >= min_points {
+                    queue.extend(reachable.into_iter().filter(|&other| !assigned[other]));
+                }
+            }
+            cluster.sort();
+            clusters.push(cluster);
+        }
+        clusters
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, MultiPoint};
+    use super::*;
+
+    #[test]
+    fn dbscan_test() {
+        // two clusters of five, around (0, 0) and (20, 20), and a point far from either
+        let mut points = vec![];
+        for &(x, y) in &[(0., 0.), (20., 20.)] {
+            for &(dx, dy) in &[(0., 0.), (0.5, 0.), (0., 0.5), (-0.5, 0.), (0., -0.5)] {
+                points.push(Point::new(x + dx, y + dy));
+            }
+        }
+        points.insert(3, Point::new(10., -5.));
+        let clusters = MultiPoint(points).dbscan(0.6, 3);
+        assert_eq!(clusters, vec![vec![0, 1, 2, 4, 5], vec![6, 7, 8, 9, 10]]);
+        assert!(!clusters.iter().any(|cluster| cluster.contains(&3)));
+    }
+    #[test]
+    fn dbscan_border_test() {
+        // a chain whose ends only reach one core point each
+        let points: MultiPoint<f64> = vec![(0., 0.), (1., 0.), (2., 0.), (3., 0.), (4., 0.)].into();
+        assert_eq!(points.dbscan(1., 3), vec![vec![0, 1, 2, 3, 4]]);
+        assert!(points.dbscan(0.5, 2).is_empty());
+        assert!(MultiPoint::<f64>(vec![]).dbscan(1., 1).is_empty());
+    }
+}
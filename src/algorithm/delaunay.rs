@@ -0,0 +1,121 @@
+use num_traits::Float;
+use types::{Point, MultiPoint, Triangle};
+use algorithm::boundingbox::BoundingBox;
+use algorithm::kernels::{cross, lexicographic};
+
+// true if d lies strictly inside the circumcircle of the counter-clockwise triangle (a, b, c)
+fn in_circumcircle<T>(a: &Point<T>, b: &Point<T>, c: &Point<T>, d: &Point<T>) -> bool
+    where T: Float
+{
+    let (ax, ay) = (a.x() - d.x(), a.y() - d.y());
+    let (bx, by) = (b.x() - d.x(), b.y() - d.y());
+    let (cx, cy) = (c.x() - d.x(), c.y() - d.y());
+    let det = (ax * ax + ay * ay) * (bx * cy - by * cx) - (bx * bx + by * by) * (ax * cy - ay * cx) +
+              (cx * cx + cy * cy) * (ax * by - ay * bx);
+    det > T::zero()
+}
+
+/// Triangulates a set of points.
+pub trait DelaunayTriangulation<T: Float> {
+    /// Returns the Delaunay triangulation of the Points of a MultiPoint
+    ///
+    /// The triangles are built by the Bowyer–Watson algorithm: the Points are inserted one by one
+    /// into a triangle enclosing them all, each one replacing the triangles whose circumcircles
+    /// contain it. No Point lies strictly inside the circumcircle of any of the resulting
+    /// triangles, which are counter-clockwise. They cover the convex hull of the Points, except
+    /// that, as the enclosing triangle is only a finite distance away, thin triangles along the
+    /// hull may be missing where Points lie almost in line along it. Repeated Points are only
+    /// used once. If there are fewer than three distinct Points, or they're all
+    /// collinear, an empty `Vec` is returned.
+    ///
+    /// ```
+    /// use geo::{Point, MultiPoint};
+    /// use geo::algorithm::delaunay::DelaunayTriangulation;
+    ///
+    /// let points = MultiPoint(vec![Point::new(0., 0.), Point::new(2., 0.), Point::new(1., 1.), Point::new(1., 3.)]);
+    /// let triangles = points.delaunay_triangulation();
+    ///
+    /// assert_eq!(triangles.len(), 3);
+    /// ```
+    fn delaunay_triangulation(&self) -> Vec<Triangle<T>>;
+}
+
+impl<T> DelaunayTriangulation<T> for MultiPoint<T>
+    where T: Float
+{
+    fn delaunay_triangulation(&self) -> Vec<Triangle<T>> {
+        let mut points = self.0.clone();
+        points.sort_by(lexicographic);
+        points.dedup();
+        if points.len() < 3 || points[2..].iter().all(|p| cross(&points[0], &points[1], p) == T::zero()) {
+            return vec![];
+        }
+        let n = points.len();
+        // a triangle enclosing all the points, far enough away to leave the hull intact unless
+        // points lie almost in line along it
+        let bbox = self.bbox().unwrap();
+        let two = T::one() + T::one();
+        let (cx, cy) = ((bbox.xmin + bbox.xmax) / two, (bbox.ymin + bbox.ymax) / two);
+        let size = (bbox.xmax - bbox.xmin).max(bbox.ymax - bbox.ymin) * T::from(1000.).unwrap();
+        points.push(Point::new(cx - two * size, cy - size));
+        points.push(Point::new(cx + two * size, cy - size));
+        points.push(Point::new(cx, cy + two * size));
+        let mut triangles: Vec<[usize; 3]> = vec![[n, n + 1, n + 2]];
+        for idx in 0..n {
+            let p = points[idx];
+            let (bad, good): (Vec<[usize; 3]>, Vec<[usize; 3]>) =
+                triangles.into_iter().partition(|t| in_circumcircle(&points[t[0]], &points[t[1]], &points[t[2]], &p));
+            triangles = good;
+            // the edges of the cavity are those belonging to only one of the removed triangles
+            let edges: Vec<(usize, usize)> = bad.iter().flat_map(|t| vec![(t[0], t[1]), (t[1], t[2]), (t[2], t[0])]).collect();
+            for &(a, b) in &edges {
+                if !edges.contains(&(b, a)) {
+                    triangles.push([a, b, idx]);
+                }
+            }
+        }
+        triangles.iter()
+            .filter(|t| t.iter().all(|&idx| idx < n))
+            .map(|t| Triangle(points[t[0]], points[t[1]], points[t[2]]))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, MultiPoint, Triangle};
+    use algorithm::area::Area;
+    use super::*;
+
+    #[test]
+    fn delaunay_square_test() {
+        let square = MultiPoint(vec![Point::new(0., 0.), Point::new(1., 0.), Point::new(1., 1.), Point::new(0., 1.)]);
+        let triangles = square.delaunay_triangulation();
+        assert_eq!(triangles.len(), 2);
+        assert_relative_eq!(triangles.iter().fold(0., |total, t| total + t.area()), 1.);
+    }
+    #[test]
+    fn delaunay_empty_circumcircle_test() {
+        let points = MultiPoint(vec![Point::new(0., 0.), Point::new(4., 0.5), Point::new(3., 3.), Point::new(0.5, 4.),
+                                     Point::new(2., 1.5)]);
+        let triangles = points.delaunay_triangulation();
+        // n points, h of them on the hull, give 2n - h - 2 triangles
+        assert_eq!(triangles.len(), 2 * 5 - 4 - 2);
+        for &Triangle(a, b, c) in &triangles {
+            assert!(cross(&a, &b, &c) > 0.);
+            assert!(points.0.iter().all(|p| !in_circumcircle(&a, &b, &c, p)));
+        }
+        // covering the hull
+        assert_relative_eq!(triangles.iter().fold(0., |total, t| total + t.area()), 10.5);
+    }
+    #[test]
+    fn delaunay_degenerate_test() {
+        let collinear = MultiPoint(vec![Point::new(0., 0.), Point::new(1., 1.), Point::new(3., 3.), Point::new(2., 2.)]);
+        assert!(collinear.delaunay_triangulation().is_empty());
+        let repeated = MultiPoint(vec![Point::new(0., 0.), Point::new(0., 0.), Point::new(1., 0.)]);
+        assert!(repeated.delaunay_triangulation().is_empty());
+        assert!(MultiPoint::<f64>(vec![]).delaunay_triangulation().is_empty());
+        let triangle = MultiPoint(vec![Point::new(0., 0.), Point::new(0., 1.), Point::new(1., 0.), Point::new(0., 1.)]);
+        assert_eq!(triangle.delaunay_triangulation().len(), 1);
+    }
+}
@@ -0,0 +1,106 @@
+use num_traits::Float;
+use types::{LineString, Polygon};
+use algorithm::kernels::point_along;
+
+// the most pieces a segment is split into, however small max_distance is
+const MAX_PIECES: usize = 1 << 16;
+
+// the vertices of a LineString, with evenly spaced points added along each segment longer than
+// max_distance, splitting it into the fewest equal pieces no longer than that
+fn densify_line<T>(ls: &LineString<T>, max_distance: T) -> LineString<T>
+    where T: Float
+{
+    if ls.0.len() < 2 || max_distance.is_nan() || max_distance <= T::zero() {
+        return ls.clone();
+    }
+    let mut points = vec![ls.0[0]];
+    for line in ls.lines() {
+        let (dx, dy) = (line.end.x() - line.start.x(), line.end.y() - line.start.y());
+        let pieces = (dx.hypot(dy) / max_distance).ceil().min(T::from(MAX_PIECES).unwrap());
+        let pieces = pieces.to_usize().unwrap_or(1);
+        for idx in 1..pieces {
+            points.push(point_along(&line, T::from(idx).unwrap() / T::from(pieces).unwrap()));
+        }
+        points.push(line.end);
+    }
+    LineString(points)
+}
+
+/// Adds vertices to a geometry, so that none of its segments is too long.
+pub trait Densify<T> {
+    /// Returns a copy of a geometry with evenly spaced vertices added along each segment longer
+    /// than `max_distance`, so that no segment is any longer
+    ///
+    /// Each long segment is split into the fewest equal pieces no longer than `max_distance`,
+    /// and the original vertices are kept, so the rings of a Polygon stay closed. If
+    /// `max_distance` isn't positive, the geometry is returned unchanged, and no segment is split
+    /// into more than 65536 pieces, however small it is.
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::densify::Densify;
+    ///
+    /// let ls: LineString<f64> = vec![(0., 0.), (3., 0.), (3., 1.)].into();
+    ///
+    /// assert_eq!(ls.densify(1.), vec![(0., 0.), (1., 0.), (2., 0.), (3., 0.), (3., 1.)].into());
+    /// ```
+    fn densify(&self, max_distance: T) -> Self where T: Float;
+}
+
+impl<T> Densify<T> for LineString<T>
+    where T: Float
+{
+    fn densify(&self, max_distance: T) -> LineString<T> {
+        densify_line(self, max_distance)
+    }
+}
+
+impl<T> Densify<T> for Polygon<T>
+    where T: Float
+{
+    fn densify(&self, max_distance: T) -> Polygon<T> {
+        Polygon::new(densify_line(&self.exterior, max_distance),
+                     self.interiors.iter().map(|ring| densify_line(ring, max_distance)).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{LineString, Polygon};
+    use algorithm::length::Length;
+    use super::*;
+
+    #[test]
+    fn densify_linestring_test() {
+        let ls: LineString<f64> = vec![(0., 0.), (10., 0.)].into();
+        let dense = ls.densify(2.5);
+        assert_eq!(dense, vec![(0., 0.), (2.5, 0.), (5., 0.), (7.5, 0.), (10., 0.)].into());
+        assert!(dense.lines().all(|line| line.length() == 2.5));
+        // a segment slightly too long is split in two
+        let split = ls.densify(9.9);
+        assert_eq!(split.0.len(), 3);
+        // no segment is too long, so nothing changes
+        assert_eq!(ls.densify(10.), ls);
+        assert_eq!(ls.densify(0.), ls);
+        assert_eq!(LineString::<f64>(vec![]).densify(1.), LineString(vec![]));
+    }
+    #[test]
+    fn densify_tiny_distance_test() {
+        let ls: LineString<f64> = vec![(0., 0.), (1., 0.)].into();
+        assert_eq!(ls.densify(1e-300).0.len(), MAX_PIECES + 1);
+    }
+    #[test]
+    fn densify_polygon_test() {
+        let exterior: LineString<f64> = vec![(0., 0.), (4., 0.), (4., 3.), (0., 0.)].into();
+        let hole: LineString<f64> = vec![(1., 0.5), (1.5, 0.5), (1.5, 1.), (1., 0.5)].into();
+        let poly = Polygon::new(exterior, vec![hole.clone()]);
+        let dense = poly.densify(1.);
+        assert!(dense.exterior.lines().all(|line| line.length() <= 1. + 1e-10));
+        // 4 + 3 + 5 pieces, and the closing vertex
+        assert_eq!(dense.exterior.0.len(), 13);
+        assert_eq!(dense.exterior.0.first(), dense.exterior.0.last());
+        // the hole has no long segments
+        assert_eq!(dense.interiors, vec![hole]);
+        assert_relative_eq!(dense.exterior.length(), poly.exterior.length());
+    }
+}
@@ -1,6 +1,7 @@
 use num_traits::{Float, ToPrimitive};
-use types::{Point, Line, MultiPoint, LineString, MultiLineString, Polygon, MultiPolygon};
+use types::{Bbox, Point, Line, MultiPoint, LineString, MultiLineString, Polygon, MultiPolygon};
 use algorithm::contains::Contains;
+use algorithm::intersects::Intersects;
 
 /// Returns the distance between two geometries.
 
@@ -66,7 +67,7 @@ pub trait Distance<T, Rhs = Self> {
 // Return minimum distance between a Point and a Line segment
 // This is a helper for Point-to-LineString and Point-to-Polygon distance
 // adapted from https://github.com/OSGeo/geos/blob/master/src/algorithm/CGAlgorithms.cpp#L191
-fn line_segment_distance<T>(point: &Point<T>, start: &Point<T>, end: &Point<T>) -> T
+pub(crate) fn line_segment_distance<T>(point: &Point<T>, start: &Point<T>, end: &Point<T>) -> T
 where
     T: Float + ToPrimitive,
 {
@@ -110,7 +111,7 @@ where
                 let (dx, dy) = (self.x() - p.x(), self.y() - p.y());
                 dx.hypot(dy)
             })
-            .fold(T::max_value(), |accum, val| accum.min(val))
+            .fold(T::infinity(), |accum, val| accum.min(val))
     }
 }
 
@@ -130,23 +131,24 @@ where
 {
     /// Minimum distance from a Point to a Polygon
     fn distance(&self, polygon: &Polygon<T>) -> T {
-        // No need to continue if the polygon contains the point, or is zero-length
-        if polygon.contains(self) || polygon.exterior.0.is_empty() {
+        // No need to continue if the polygon contains the point
+        if polygon.contains(self) {
             return T::zero();
         }
         // fold the minimum interior ring distance if any, followed by the exterior
-        // shell distance, returning the minimum of the two distances
+        // shell distance, returning the minimum of the two distances; a point in a hole
+        // is nearest to the boundary of the hole, and an empty polygon is infinitely far
         polygon
             .interiors
             .iter()
             .map(|ring| self.distance(ring))
-            .fold(T::max_value(), |accum, val| accum.min(val))
+            .fold(T::infinity(), |accum, val| accum.min(val))
             .min(
                 polygon
                     .exterior
                     .lines()
                     .map(|line| line_segment_distance(self, &line.start, &line.end))
-                    .fold(T::max_value(), |accum, val| accum.min(val)),
+                    .fold(T::infinity(), |accum, val| accum.min(val)),
             )
     }
 }
@@ -168,7 +170,7 @@ where
     /// Minimum distance from a Point to a MultiPolygon
     fn distance(&self, mpolygon: &MultiPolygon<T>) -> T {
         mpolygon.0.iter().map(|p| self.distance(p)).fold(
-            T::max_value(),
+            T::infinity(),
             |accum, val| {
                 accum.min(val)
             },
@@ -193,7 +195,7 @@ where
     /// Minimum distance from a Point to a MultiLineString
     fn distance(&self, mls: &MultiLineString<T>) -> T {
         mls.0.iter().map(|ls| self.distance(ls)).fold(
-            T::max_value(),
+            T::infinity(),
             |accum, val| {
                 accum.min(val)
             },
@@ -217,14 +219,18 @@ where
 {
     /// Minimum distance from a Point to a LineString
     fn distance(&self, linestring: &LineString<T>) -> T {
-        // No need to continue if the point is on the LineString, or it's empty
-        if linestring.contains(self) || linestring.0.is_empty() {
+        // No need to continue if the point is on the LineString
+        if linestring.contains(self) {
             return T::zero();
         }
+        // a single point has no segments, and an empty LineString is infinitely far
+        if linestring.0.len() == 1 {
+            return self.distance(&linestring.0[0]);
+        }
         linestring
             .lines()
             .map(|line| line_segment_distance(self, &line.start, &line.end))
-            .fold(T::max_value(), |accum, val| accum.min(val))
+            .fold(T::infinity(), |accum, val| accum.min(val))
     }
 }
 
@@ -257,10 +263,154 @@ where
     }
 }
 
+impl<T> Distance<T, Bbox<T>> for Point<T>
+where
+    T: Float,
+{
+    /// Minimum distance from a Point to a Bbox: zero if the Point lies inside or on it
+    fn distance(&self, bbox: &Bbox<T>) -> T {
+        let dx = (bbox.xmin - self.x()).max(self.x() - bbox.xmax).max(T::zero());
+        let dy = (bbox.ymin - self.y()).max(self.y() - bbox.ymax).max(T::zero());
+        dx.hypot(dy)
+    }
+}
+impl<T> Distance<T, Point<T>> for Bbox<T>
+where
+    T: Float,
+{
+    /// Minimum distance from a Bbox to a Point: zero if the Point lies inside or on it
+    fn distance(&self, point: &Point<T>) -> T {
+        point.distance(self)
+    }
+}
+
+impl<T> Distance<T, LineString<T>> for LineString<T>
+where
+    T: Float,
+{
+    /// Minimum distance between two LineStrings: zero if they intersect
+    fn distance(&self, other: &LineString<T>) -> T {
+        if self.0.is_empty() || other.0.is_empty() {
+            return T::infinity();
+        }
+        if self.intersects(other) {
+            return T::zero();
+        }
+        // two segments which don't cross are nearest at an endpoint of one of them
+        self.0
+            .iter()
+            .map(|p| p.distance(other))
+            .chain(other.0.iter().map(|p| p.distance(self)))
+            .fold(T::infinity(), |accum, val| accum.min(val))
+    }
+}
+
+impl<T> Distance<T, Polygon<T>> for Polygon<T>
+where
+    T: Float,
+{
+    /// Minimum distance between two Polygons: zero if they intersect, or one contains the other
+    fn distance(&self, other: &Polygon<T>) -> T {
+        if self.exterior.0.is_empty() || other.exterior.0.is_empty() {
+            return T::infinity();
+        }
+        if self.intersects(other) {
+            return T::zero();
+        }
+        // otherwise their boundaries are nearest, even if one lies in a hole in the other
+        ::std::iter::once(&self.exterior).chain(&self.interiors).fold(T::infinity(), |accum, ring| {
+            ::std::iter::once(&other.exterior)
+                .chain(&other.interiors)
+                .fold(accum, |accum, other| accum.min(ring.distance(other)))
+        })
+    }
+}
+
+/// The metric used to measure the distance between two Points
+///
+/// - `Euclidean`: the straight-line distance, `sqrt(dx² + dy²)`
+/// - `Manhattan`: the taxicab distance, `|dx| + |dy|`
+/// - `Chebyshev`: the chessboard distance, `max(|dx|, |dy|)`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Metric {
+    Euclidean,
+    Manhattan,
+    Chebyshev,
+}
+
+/// Returns the distance between two geometries, using a selectable metric.
+pub trait DistanceMetric<T, Rhs = Self> {
+    /// Returns the distance between two Points, measured using the given `Metric`
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::distance::{DistanceMetric, Metric};
+    ///
+    /// let p1 = Point::new(1., 2.);
+    /// let p2 = Point::new(4., -2.);
+    ///
+    /// assert_eq!(p1.distance_metric(&p2, Metric::Euclidean), 5.);
+    /// assert_eq!(p1.distance_metric(&p2, Metric::Manhattan), 7.);
+    /// assert_eq!(p1.distance_metric(&p2, Metric::Chebyshev), 4.);
+    /// ```
+    fn distance_metric(&self, rhs: &Rhs, metric: Metric) -> T;
+}
+
+impl<T> DistanceMetric<T, Point<T>> for Point<T>
+where
+    T: Float,
+{
+    fn distance_metric(&self, p: &Point<T>, metric: Metric) -> T {
+        let (dx, dy) = ((self.x() - p.x()).abs(), (self.y() - p.y()).abs());
+        match metric {
+            Metric::Euclidean => dx.hypot(dy),
+            Metric::Manhattan => dx + dy,
+            Metric::Chebyshev => dx.max(dy),
+        }
+    }
+}
+
+/// Checks that a geometry stays close to another.
+pub trait VerticesWithin<T, Rhs = Self> {
+    /// Returns `true` if every vertex of a LineString lies within `tolerance` of another
+    ///
+    /// This is a building block for checking that simplification hasn't moved a path too far
+    /// from its original. It's one-sided: the other LineString may stray far from this one.
+    /// Nothing is within any distance of an empty LineString, so the result is then `false`
+    /// unless this LineString is empty too.
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::distance::VerticesWithin;
+    ///
+    /// let path: LineString<f64> = vec![(0., 0.), (5., 0.5), (10., 0.)].into();
+    /// let straight: LineString<f64> = vec![(0., 0.), (10., 0.)].into();
+    ///
+    /// assert!(path.vertices_within(&straight, 0.5));
+    /// assert!(!path.vertices_within(&straight, 0.4));
+    /// // every vertex of the straight line lies on the path
+    /// assert!(straight.vertices_within(&path, 0.));
+    /// ```
+    fn vertices_within(&self, other: &Rhs, tolerance: T) -> bool;
+}
+
+impl<T> VerticesWithin<T, LineString<T>> for LineString<T>
+where
+    T: Float,
+{
+    fn vertices_within(&self, other: &LineString<T>, tolerance: T) -> bool {
+        if other.0.is_empty() {
+            return self.0.is_empty();
+        }
+        self.0.iter().all(|p| p.distance(other) <= tolerance)
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use types::{Point, Line, MultiPoint, LineString, MultiLineString, Polygon, MultiPolygon};
-    use algorithm::distance::{Distance, line_segment_distance};
+    use types::{Bbox, Point, Line, MultiPoint, LineString, MultiLineString, Polygon, MultiPolygon};
+    use algorithm::distance::{Distance, DistanceMetric, Metric, VerticesWithin, line_segment_distance};
+    use algorithm::simplify::Simplify;
 
     #[test]
     fn line_segment_distance_test() {
@@ -373,10 +523,10 @@ mod test {
         let points = vec![];
         let ls = LineString(points);
         let poly = Polygon::new(ls, vec![]);
-        // A point on the octagon
+        // an empty Polygon is infinitely far from every point
         let p = Point::new(2.5, 0.5);
         let dist = p.distance(&poly);
-        assert_relative_eq!(dist, 0.0);
+        assert_eq!(dist, f64::INFINITY);
     }
     #[test]
     // Point to Polygon with an interior ring
@@ -480,7 +630,14 @@ mod test {
         let ls = LineString(points);
         let p = Point::new(5.0, 4.0);
         let dist = p.distance(&ls);
-        assert_relative_eq!(dist, 0.0);
+        assert_eq!(dist, f64::INFINITY);
+        // a single point has no segments
+        let single = LineString(vec![Point::new(2.0, 0.0)]);
+        assert_relative_eq!(p.distance(&single), 5.0);
+        // and empty collections are infinitely far too
+        assert_eq!(p.distance(&MultiPoint(vec![])), f64::INFINITY);
+        assert_eq!(p.distance(&MultiLineString(vec![])), f64::INFINITY);
+        assert_eq!(p.distance(&MultiPolygon(vec![])), f64::INFINITY);
     }
     #[test]
     fn distance_multilinestring_test() {
@@ -538,4 +695,83 @@ mod test {
         assert_eq!(line0.distance(&p2), 1.);
         assert_eq!(p2.distance(&line0), 1.);
     }
+    #[test]
+    fn distance_bbox_test() {
+        let bbox = Bbox { xmin: 0., xmax: 4., ymin: 0., ymax: 2. };
+        // inside, and on the boundary
+        assert_eq!(Point::new(1., 1.).distance(&bbox), 0.);
+        assert_eq!(Point::new(4., 1.).distance(&bbox), 0.);
+        // beside an edge
+        assert_eq!(Point::new(2., 5.).distance(&bbox), 3.);
+        assert_eq!(Point::new(-1.5, 1.).distance(&bbox), 1.5);
+        // past a corner
+        assert_eq!(Point::new(7., 6.).distance(&bbox), 5.);
+        assert_eq!(bbox.distance(&Point::new(-3., -4.)), 5.);
+    }
+    #[test]
+    fn distance_linestring_test() {
+        // parallel, offset segments
+        let a: LineString<f64> = vec![(0., 0.), (4., 0.)].into();
+        let b: LineString<f64> = vec![(1., 2.), (3., 2.)].into();
+        assert_eq!(a.distance(&b), 2.);
+        assert_eq!(b.distance(&a), 2.);
+        // nearest between the interior of one segment and the end of another
+        let c: LineString<f64> = vec![(5., 3.), (6., 1.), (8., 0.)].into();
+        assert_relative_eq!(a.distance(&c), 5f64.sqrt());
+        // crossing, and sharing a vertex
+        let d: LineString<f64> = vec![(2., -1.), (2., 1.)].into();
+        assert_eq!(a.distance(&d), 0.);
+        let e: LineString<f64> = vec![(4., 0.), (5., 5.)].into();
+        assert_eq!(a.distance(&e), 0.);
+        assert_eq!(a.distance(&LineString(vec![])), f64::INFINITY);
+    }
+    #[test]
+    fn distance_polygon_test() {
+        let square = |x: f64, y: f64, size: f64| {
+            Polygon::new(vec![(x, y), (x + size, y), (x + size, y + size), (x, y + size), (x, y)].into(), vec![])
+        };
+        // disjoint squares, with a gap of 3
+        assert_eq!(square(0., 0., 2.).distance(&square(5., 0., 2.)), 3.);
+        // diagonally apart, nearest at their corners
+        assert_eq!(square(0., 0., 1.).distance(&square(4., 5., 1.)), 5.);
+        // overlapping, touching, and one containing the other
+        assert_eq!(square(0., 0., 2.).distance(&square(1., 1., 2.)), 0.);
+        assert_eq!(square(0., 0., 2.).distance(&square(2., 0., 2.)), 0.);
+        assert_eq!(square(0., 0., 10.).distance(&square(4., 4., 1.)), 0.);
+        assert_eq!(square(4., 4., 1.).distance(&square(0., 0., 10.)), 0.);
+        // inside a hole, the distance is to the hole's boundary
+        let frame = Polygon::new(square(0., 0., 10.).exterior,
+                                 vec![vec![(2., 2.), (2., 8.), (8., 8.), (8., 2.), (2., 2.)].into()]);
+        assert_eq!(frame.distance(&square(4., 4., 1.)), 2.);
+        assert_eq!(square(4., 4., 1.).distance(&frame), 2.);
+        let empty = Polygon::<f64>::new(LineString(vec![]), vec![]);
+        assert_eq!(empty.distance(&frame), f64::INFINITY);
+    }
+    #[test]
+    fn distance_metric_test() {
+        let p1 = Point::new(-1.5, 2.0);
+        let p2 = Point::new(4.5, 10.0);
+        assert_relative_eq!(p1.distance_metric(&p2, Metric::Euclidean), 10.0);
+        assert_relative_eq!(p1.distance_metric(&p2, Metric::Manhattan), 14.0);
+        assert_relative_eq!(p1.distance_metric(&p2, Metric::Chebyshev), 8.0);
+        // the metrics are symmetric
+        assert_relative_eq!(p2.distance_metric(&p1, Metric::Manhattan), 14.0);
+        // the Euclidean metric agrees with Distance
+        assert_relative_eq!(p1.distance_metric(&p2, Metric::Euclidean), p1.distance(&p2));
+    }
+    #[test]
+    fn vertices_within_test() {
+        let original: LineString<f64> = vec![(0.0, 0.0), (5.0, 4.0), (11.0, 5.5), (17.3, 3.2), (27.8, 0.1)].into();
+        let simplified = original.simplify(&1.0);
+        assert!(simplified.0.len() < original.0.len());
+        // simplification only drops vertices, each less than epsilon from the result (0.26 here)
+        assert!(original.vertices_within(&simplified, 1.0));
+        assert!(simplified.vertices_within(&original, 0.0));
+        assert!(!original.vertices_within(&simplified, 0.25));
+        assert!(original.vertices_within(&simplified, 0.27));
+        let empty = LineString::<f64>(vec![]);
+        assert!(!original.vertices_within(&empty, 100.0));
+        assert!(empty.vertices_within(&empty, 0.0));
+        assert!(empty.vertices_within(&original, 0.0));
+    }
 }
@@ -220,7 +220,7 @@ impl<T, G> ExtremePoints<T> for G
 #[cfg(test)]
 mod test {
 
-    use types::{Point, LineString};
+    use types::{Point, LineString, MultiPolygon};
     use super::*;
     #[test]
     fn test_polygon_extreme_x() {
@@ -316,4 +316,24 @@ mod test {
         let correct = Point::new(0.0, 1.0);
         assert_eq!(extremes.xmin, correct);
     }
+    #[test]
+    fn test_extreme_points_diamond() {
+        // a diamond shape, given clockwise, and translated away from the origin
+        let diamond = Polygon::new(vec![(11., 10.), (10., 11.), (11., 12.), (12., 11.), (11., 10.)].into(), vec![]);
+        let correct = ExtremePoint {
+            ymin: Point::new(11., 10.),
+            xmax: Point::new(12., 11.),
+            ymax: Point::new(11., 12.),
+            xmin: Point::new(10., 11.),
+        };
+        assert_eq!(diamond.extreme_points(), correct);
+        // a smaller diamond inside changes nothing, but one further out does
+        let inner = Polygon::new(vec![(11., 10.5), (11.5, 11.), (11., 11.5), (10.5, 11.), (11., 10.5)].into(), vec![]);
+        let mp = MultiPolygon(vec![diamond.clone(), inner]);
+        assert_eq!(mp.extreme_points(), correct);
+        let east = Polygon::new(vec![(14., 10.5), (15., 11.), (14., 11.5), (14., 10.5)].into(), vec![]);
+        let mp = MultiPolygon(vec![diamond, east]);
+        assert_eq!(mp.extreme_points().xmax, Point::new(15., 11.));
+        assert_eq!(mp.extreme_points().xmin, correct.xmin);
+    }
 }
@@ -0,0 +1,130 @@
+use num_traits::Float;
+use types::{Point, LineString, Polygon};
+use algorithm::kernels::crossed_edges;
+
+// the number of edges of a ring crossed by a ray cast from p towards positive x
+fn ring_crossings<T>(p: &Point<T>, ring: &LineString<T>) -> usize
+    where T: Float
+{
+    crossed_edges(p, ring).count()
+}
+
+// the winding number of a ring around p: the number of its edges crossed by a ray cast from p
+// towards positive x, counting those running upwards as +1 and downwards as -1, so positive if
+// the ring runs counter-clockwise around p
+fn ring_winding<T>(p: &Point<T>, ring: &LineString<T>) -> isize
+    where T: Float
+{
+    crossed_edges(p, ring)
+        .map(|line| if line.end.y() > line.start.y() { 1 } else { -1 })
+        .sum()
+}
+
+/// Tests Points against a Polygon under different fill rules.
+pub trait FillRule<T: Float> {
+    /// Returns the number of edges of a Polygon, counting those of every ring, crossed by a ray
+    /// cast from a Point towards positive x
+    ///
+    /// This is the raw count which `contains` reduces to odd or even, so other fill rules can be
+    /// built on it. Each edge is half-open, including its lower endpoint but not its upper one,
+    /// so a ray passing exactly through a vertex counts a single crossing only where the ring
+    /// continues to the other side. Horizontal edges are never counted. Points on the boundary
+    /// aren't treated specially.
+    ///
+    /// ```
+    /// use geo::{Point, Polygon};
+    /// use geo::algorithm::fill_rule::FillRule;
+    ///
+    /// let square = Polygon::new(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into(),
+    ///                           vec![vec![(1., 1.), (1., 3.), (3., 3.), (3., 1.), (1., 1.)].into()]);
+    ///
+    /// assert_eq!(square.crossing_number(&Point::new(0.5, 2.)), 3);
+    /// assert_eq!(square.crossing_number(&Point::new(2., 2.)), 2);
+    /// assert_eq!(square.crossing_number(&Point::new(5., 2.)), 0);
+    /// ```
+    fn crossing_number(&self, p: &Point<T>) -> usize;
+
+    /// Checks whether a Polygon contains a Point under the nonzero winding rule
+    ///
+    /// The winding numbers of all the rings around the Point, as they are oriented, are summed,
+    /// and the Point is contained if the total isn't zero. Where `contains` fills a region by the
+    /// parity of the number of rings around it, this fills every region the rings wind around,
+    /// so the overlapping loops of a self-intersecting ring are filled. A hole only cuts out its
+    /// region if it winds the opposite way to the exterior; one wound the same way fills it
+    /// instead. The crossings are counted as in `crossing_number`, and the two rules agree
+    /// wherever the rings wind around a Point an odd number of times.
+    ///
+    /// ```
+    /// use geo::{Point, Polygon};
+    /// use geo::algorithm::contains::Contains;
+    /// use geo::algorithm::fill_rule::FillRule;
+    ///
+    /// // a pentagram, whose ring winds twice around its centre
+    /// let star = Polygon::new(vec![(0., 3.), (1.8, -2.5), (-2.9, 0.9), (2.9, 0.9), (-1.8, -2.5), (0., 3.)].into(),
+    ///                         vec![]);
+    ///
+    /// assert!(star.contains_point_nonzero(&Point::new(0., 0.)));
+    /// assert!(!star.contains(&Point::new(0., 0.)));
+    /// ```
+    fn contains_point_nonzero(&self, p: &Point<T>) -> bool;
+}
+
+impl<T> FillRule<T> for Polygon<T>
+    where T: Float
+{
+    fn crossing_number(&self, p: &Point<T>) -> usize {
+        ring_crossings(p, &self.exterior) + self.interiors.iter().map(|ring| ring_crossings(p, ring)).sum::<usize>()
+    }
+
+    fn contains_point_nonzero(&self, p: &Point<T>) -> bool {
+        ring_winding(p, &self.exterior) + self.interiors.iter().map(|ring| ring_winding(p, ring)).sum::<isize>() != 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, Polygon};
+    use algorithm::contains::Contains;
+    use super::*;
+
+    #[test]
+    fn crossing_number_test() {
+        // a pentagram, whose ring winds twice around its centre
+        let star = Polygon::new(vec![(0., 3.), (1.8, -2.5), (-2.9, 0.9), (2.9, 0.9), (-1.8, -2.5), (0., 3.)].into(),
+                                vec![]);
+        // the ray from the centre crosses two edges, so even-odd leaves it unfilled, though
+        // nonzero would fill it
+        let centre = Point::new(0., 0.);
+        assert_eq!(star.crossing_number(&centre), 2);
+        assert!(!star.contains(&centre));
+        // a point in one of the arms is filled either way
+        let arm = Point::new(0., 2.);
+        assert_eq!(star.crossing_number(&arm), 1);
+        assert!(star.contains(&arm));
+        assert_eq!(star.crossing_number(&Point::new(5., 0.)), 0);
+    }
+    #[test]
+    fn contains_point_nonzero_holes_test() {
+        // two overlapping holes in a square: the left one wound clockwise, against the exterior,
+        // and the right one counter-clockwise, along with it
+        let square = Polygon::new(vec![(0., 0.), (6., 0.), (6., 4.), (0., 4.), (0., 0.)].into(),
+                                  vec![vec![(1., 1.), (1., 3.), (4., 3.), (4., 1.), (1., 1.)].into(),
+                                       vec![(2., 1.), (5., 1.), (5., 3.), (2., 3.), (2., 1.)].into()]);
+        // in the right hole alone, the winding number is 2: filled by nonzero but not by even-odd
+        let right = Point::new(4.5, 2.);
+        assert_eq!(square.crossing_number(&right), 2);
+        assert!(square.contains_point_nonzero(&right));
+        assert!(!square.contains(&right));
+        // in the left hole alone, it's 0, so neither rule fills it
+        let left = Point::new(1.5, 2.);
+        assert_eq!(square.crossing_number(&left), 4);
+        assert!(!square.contains_point_nonzero(&left));
+        // in the overlap, it's 1, and both rules fill it, though contains treats it as a hole
+        let overlap = Point::new(3., 2.);
+        assert_eq!(square.crossing_number(&overlap), 3);
+        assert!(square.contains_point_nonzero(&overlap));
+        assert!(!square.contains(&overlap));
+        assert!(square.contains_point_nonzero(&Point::new(0.5, 2.)));
+        assert!(!square.contains_point_nonzero(&Point::new(7., 2.)));
+    }
+}
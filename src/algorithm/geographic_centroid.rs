@@ -0,0 +1,112 @@
+use num_traits::Float;
+use types::{Point, LineString, Polygon};
+use algorithm::orient::{Orient, Direction};
+
+// the unit vector of a lon/lat point, given in degrees
+fn to_vector<T>(p: &Point<T>) -> [T; 3]
+    where T: Float
+{
+    let (lng, lat) = (p.x().to_radians(), p.y().to_radians());
+    [lat.cos() * lng.cos(), lat.cos() * lng.sin(), lat.sin()]
+}
+
+// The first moment of the region on the unit sphere enclosed by a counter-clockwise ring of
+// great circle arcs, the integral of the position vector over its surface. By Stokes' theorem,
+// this is half the sum over the arcs of their lengths times their unit normals, a × b / |a × b|;
+// the factor of a half is dropped, as only its direction is needed. A clockwise ring gives the
+// negated moment, so holes are subtracted.
+fn ring_moment<T>(ring: &LineString<T>) -> [T; 3]
+    where T: Float
+{
+    ring.lines().fold([T::zero(); 3], |total, line| {
+        let (a, b) = (to_vector(&line.start), to_vector(&line.end));
+        let normal = [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]];
+        let sin = (normal[0] * normal[0] + normal[1] * normal[1] + normal[2] * normal[2]).sqrt();
+        if sin == T::zero() {
+            return total;
+        }
+        let scale = sin.atan2(a[0] * b[0] + a[1] * b[1] + a[2] * b[2]) / sin;
+        [total[0] + normal[0] * scale, total[1] + normal[1] * scale, total[2] + normal[2] * scale]
+    })
+}
+
+/// Calculation of the centroid of a geometry on the sphere.
+pub trait GeographicCentroid<T: Float> {
+    /// Returns the centroid of a Polygon of lon/lat vertices, in degrees, whose edges are great
+    /// circle arcs
+    ///
+    /// This is the centroid of its surface on the sphere, projected back onto the sphere, so it
+    /// doesn't suffer the bias of the planar centroid, which treats degrees of longitude as
+    /// equally long at every latitude. The Polygon mustn't enclose a pole. Returns `None` for a
+    /// Polygon without area.
+    ///
+    /// ```
+    /// # extern crate geo;
+    /// # #[macro_use] extern crate approx;
+    /// # fn main() {
+    /// use geo::Polygon;
+    /// use geo::algorithm::geographic_centroid::GeographicCentroid;
+    ///
+    /// let poly = Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into(), vec![]);
+    /// let centroid = poly.geographic_centroid().unwrap();
+    ///
+    /// // small and near the equator, so much like the planar centroid
+    /// assert_relative_eq!(centroid.x(), 0.5, epsilon = 1e-6);
+    /// assert_relative_eq!(centroid.y(), 0.5, epsilon = 1e-4);
+    /// # }
+    /// ```
+    fn geographic_centroid(&self) -> Option<Point<T>>;
+}
+
+impl<T> GeographicCentroid<T> for Polygon<T>
+    where T: Float
+{
+    fn geographic_centroid(&self) -> Option<Point<T>> {
+        let oriented = self.orient(Direction::Default);
+        let moment = oriented.interiors.iter().fold(ring_moment(&oriented.exterior), |total, ring| {
+            let hole = ring_moment(ring);
+            [total[0] + hole[0], total[1] + hole[1], total[2] + hole[2]]
+        });
+        if moment == [T::zero(); 3] {
+            return None;
+        }
+        let (x, y, z) = (moment[0], moment[1], moment[2]);
+        Some(Point::new(y.atan2(x).to_degrees(), z.atan2(x.hypot(y)).to_degrees()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{LineString, Polygon};
+    use algorithm::centroid::Centroid;
+    use super::*;
+
+    #[test]
+    fn geographic_centroid_high_latitude_test() {
+        let poly = Polygon::new(vec![(0., 60.), (90., 60.), (90., 80.), (0., 80.), (0., 60.)].into(), vec![]);
+        let planar = poly.centroid().unwrap();
+        assert_relative_eq!(planar.x(), 45.);
+        assert_relative_eq!(planar.y(), 70.);
+        // the edges along the parallels are great circle arcs, bowing towards the pole, and the
+        // meridians converge, so the centroid lies well north of the planar one
+        let centroid = poly.geographic_centroid().unwrap();
+        assert_relative_eq!(centroid.x(), 45., epsilon = 1e-10);
+        assert_relative_eq!(centroid.y(), 74.1655, epsilon = 1e-4);
+        // the orientation of the ring doesn't matter
+        let clockwise = Polygon::new(LineString(poly.exterior.0.iter().rev().cloned().collect()), vec![]);
+        let reversed = clockwise.geographic_centroid().unwrap();
+        assert_relative_eq!(reversed.x(), centroid.x(), epsilon = 1e-10);
+        assert_relative_eq!(reversed.y(), centroid.y(), epsilon = 1e-10);
+    }
+    #[test]
+    fn geographic_centroid_hole_test() {
+        let exterior: LineString<f64> = vec![(0., 0.), (20., 0.), (20., 10.), (0., 10.), (0., 0.)].into();
+        let hole: LineString<f64> = vec![(1., 1.), (9., 1.), (9., 9.), (1., 9.), (1., 1.)].into();
+        let solid = Polygon::new(exterior.clone(), vec![]).geographic_centroid().unwrap();
+        // cutting a hole out of the western half moves the centroid east
+        let holed = Polygon::new(exterior, vec![hole]).geographic_centroid().unwrap();
+        assert_relative_eq!(solid.x(), 10., epsilon = 1e-10);
+        assert!(holed.x() > 12.);
+        assert!(Polygon::<f64>::new(LineString(vec![]), vec![]).geographic_centroid().is_none());
+    }
+}
@@ -0,0 +1,74 @@
+use num_traits::{Float, FromPrimitive};
+use types::{Point, LineString, Polygon};
+use algorithm::haversine_destination::HaversineDestination;
+
+/// Returns a geodesic circle around a Point, as a Polygon
+pub trait HaversineBuffer<T: Float> {
+    /// Returns a Polygon approximating the circle of points `radius_meters` from a lon/lat
+    /// Point, along the surface of the earth
+    ///
+    /// The Polygon has `steps` vertices, each found with `haversine_destination` on evenly
+    /// spaced bearings, running counter-clockwise from due north. Unlike a planar buffer, this
+    /// accounts for the earth's curvature, so the Polygon is stretched east to west away from the
+    /// equator. Returns a Polygon with an empty exterior if `steps` is less than 3.
+    ///
+    /// ```
+    /// # extern crate geo;
+    /// # #[macro_use] extern crate approx;
+    /// # fn main() {
+    /// use geo::Point;
+    /// use geo::algorithm::haversine_buffer::HaversineBuffer;
+    /// use geo::algorithm::haversine_distance::HaversineDistance;
+    ///
+    /// let center = Point::new(13.4050, 52.5200);
+    /// let circle = center.haversine_buffer(1000., 32);
+    ///
+    /// assert_eq!(circle.exterior.0.len(), 33);
+    /// assert_relative_eq!(center.haversine_distance(&circle.exterior.0[8]), 1000., epsilon = 1.0e-6);
+    /// # }
+    /// ```
+    fn haversine_buffer(&self, radius_meters: T, steps: usize) -> Polygon<T>;
+}
+
+impl<T> HaversineBuffer<T> for Point<T>
+    where T: Float + FromPrimitive
+{
+    fn haversine_buffer(&self, radius_meters: T, steps: usize) -> Polygon<T> {
+        if steps < 3 {
+            return Polygon::new(LineString(vec![]), vec![]);
+        }
+        let step = T::from(360.).unwrap() / T::from(steps).unwrap();
+        // bearings run clockwise, so decrease them to wind the ring counter-clockwise
+        let mut points: Vec<Point<T>> = (0..steps)
+            .map(|idx| self.haversine_destination(-step * T::from(idx).unwrap(), radius_meters))
+            .collect();
+        let first = points[0];
+        points.push(first);
+        Polygon::new(LineString(points), vec![])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::Point;
+    use algorithm::area::Area;
+    use algorithm::haversine_distance::HaversineDistance;
+    use super::*;
+
+    #[test]
+    fn haversine_buffer_test() {
+        let center = Point::<f64>::new(-0.1278, 51.5074);
+        let circle = center.haversine_buffer(5000., 64);
+        assert_eq!(circle.exterior.0.len(), 65);
+        assert_eq!(circle.exterior.0.first(), circle.exterior.0.last());
+        for p in &circle.exterior.0 {
+            assert_relative_eq!(center.haversine_distance(p), 5000., epsilon = 1.0e-6);
+        }
+        assert!(circle.area() > 0.);
+        // a degree of longitude is shorter than one of latitude, away from the equator
+        let east = circle.exterior.0[48];
+        let north = circle.exterior.0[0];
+        assert!(east.x() - center.x() > north.y() - center.y());
+        assert!(center.haversine_buffer(5000., 2).exterior.0.is_empty());
+    }
+}
@@ -0,0 +1,122 @@
+use num_traits::{Float, FromPrimitive};
+use types::{Point, LineString, MultiPoint};
+use algorithm::haversine_distance::HaversineDistance;
+
+// the point at `fraction` of the way along the great circle path from `a` to `b`,
+// which lie an angular distance of `delta` (in radians) apart
+fn intermediate<T>(a: &Point<T>, b: &Point<T>, delta: T, fraction: T) -> Point<T>
+    where T: Float
+{
+    let (lng1, lat1) = (a.x().to_radians(), a.y().to_radians());
+    let (lng2, lat2) = (b.x().to_radians(), b.y().to_radians());
+    let k1 = ((T::one() - fraction) * delta).sin() / delta.sin();
+    let k2 = (fraction * delta).sin() / delta.sin();
+    let x = k1 * lat1.cos() * lng1.cos() + k2 * lat2.cos() * lng2.cos();
+    let y = k1 * lat1.cos() * lng1.sin() + k2 * lat2.cos() * lng2.sin();
+    let z = k1 * lat1.sin() + k2 * lat2.sin();
+    let lat = z.atan2(x.hypot(y));
+    let lng = y.atan2(x);
+    Point::new(lng.to_degrees(), lat.to_degrees())
+}
+
+/// Samples a geometry at equal ground distances along the surface of the earth.
+pub trait HaversinePointsEvery<T> {
+    /// Returns points spaced `spacing_meters` apart along the great circle path of a LineString
+    ///
+    /// The first point is the start of the LineString and the last point is its end, so the final
+    /// interval may be shorter than `spacing_meters`. Spacing is measured along the whole path,
+    /// carrying over from one segment to the next, and the original vertices are not included
+    /// unless they happen to fall on a sample. If `spacing_meters` is not positive, the vertices of
+    /// the LineString are returned unchanged.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::haversine_densify::HaversinePointsEvery;
+    ///
+    /// let ls = LineString(vec![Point::new(0., 0.), Point::new(0.01, 0.)]);
+    /// // the segment is about 1112 m long
+    /// let points = ls.haversine_points_every(500.);
+    ///
+    /// assert_eq!(points.0.len(), 4);
+    /// assert_eq!(points.0[0], Point::new(0., 0.));
+    /// assert_eq!(points.0[3], Point::new(0.01, 0.));
+    /// ```
+    fn haversine_points_every(&self, spacing_meters: T) -> MultiPoint<T>
+        where T: Float + FromPrimitive;
+}
+
+impl<T> HaversinePointsEvery<T> for LineString<T>
+    where T: Float + FromPrimitive
+{
+    fn haversine_points_every(&self, spacing_meters: T) -> MultiPoint<T> {
+        if self.0.len() < 2 || spacing_meters.is_nan() || spacing_meters <= T::zero() {
+            return MultiPoint(self.0.clone());
+        }
+        // WGS84 equatorial radius is 6378137.0
+        let radius = T::from(6371000.0).unwrap();
+        // samples closer than this to the end are dropped, as the end itself is always included
+        let tolerance = T::from(1e-6).unwrap();
+        let last = self.0.len() - 2;
+        let mut points = vec![];
+        // distance along the current segment at which the next sample falls
+        let mut offset = T::zero();
+        for (idx, line) in self.lines().enumerate() {
+            let length = line.start.haversine_distance(&line.end);
+            let limit = if idx == last { length - tolerance } else { length };
+            while offset < limit {
+                points.push(intermediate(&line.start, &line.end, length / radius, offset / length));
+                offset = offset + spacing_meters;
+            }
+            offset = offset - length;
+        }
+        points.push(*self.0.last().unwrap());
+        MultiPoint(points)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString};
+    use algorithm::haversine_destination::HaversineDestination;
+    use super::*;
+
+    #[test]
+    fn points_every_test() {
+        let start = Point::new(9.177789688110352, 48.776781529534965);
+        let end = start.haversine_destination(45., 1000.);
+        let ls = LineString(vec![start, end]);
+        let points = ls.haversine_points_every(250.);
+        assert_eq!(points.0.len(), 5);
+        assert_eq!(points.0[0], start);
+        assert_eq!(points.0[4], end);
+        for pair in points.0.windows(2) {
+            assert_relative_eq!(pair[0].haversine_distance(&pair[1]), 250., epsilon = 1.0e-6);
+        }
+    }
+    #[test]
+    fn points_every_across_vertices_test() {
+        // 300 m east, then 250 m north, sampled every 200 m
+        let a = Point::new(0., 0.);
+        let b = a.haversine_destination(90., 300.);
+        let c = b.haversine_destination(0., 250.);
+        let ls = LineString(vec![a, b, c]);
+        let points = ls.haversine_points_every(200.);
+        assert_eq!(points.0.len(), 4);
+        assert_eq!(points.0[0], a);
+        assert_relative_eq!(points.0[1].haversine_distance(&a), 200., epsilon = 1.0e-6);
+        // the second sample lies 100 m past the vertex
+        assert_relative_eq!(points.0[2].haversine_distance(&b), 100., epsilon = 1.0e-6);
+        // leaving a shorter final interval
+        assert_relative_eq!(points.0[2].haversine_distance(&c), 150., epsilon = 1.0e-6);
+        assert_eq!(points.0[3], c);
+    }
+    #[test]
+    fn points_every_degenerate_test() {
+        let empty = LineString::<f64>(vec![]);
+        assert!(empty.haversine_points_every(100.).0.is_empty());
+        let single = LineString(vec![Point::new(1., 1.)]);
+        assert_eq!(single.haversine_points_every(100.).0, vec![Point::new(1., 1.)]);
+        let ls = LineString(vec![Point::new(0., 0.), Point::new(1., 0.)]);
+        assert_eq!(ls.haversine_points_every(0.).0, ls.0);
+    }
+}
@@ -33,7 +33,9 @@ impl<T> HaversineDistance<T, Point<T>> for Point<T>
         let delta_lambda = (rhs.x() - self.x()).to_radians();
         let a = (delta_theta / two).sin().powi(2) +
                 theta1.cos() * theta2.cos() * (delta_lambda / two).sin().powi(2);
-        let c = two * a.sqrt().asin();
+        // unlike asin, atan2 stays accurate, and defined, when rounding pushes a up to or past 1
+        // for nearly antipodal points
+        let c = two * a.sqrt().atan2((T::one() - a).max(T::zero()).sqrt());
         // WGS84 equatorial radius is 6378137.0
         T::from(6371000.0).unwrap() * c
     }
@@ -81,4 +83,24 @@ mod test {
                             2526.8318_f32,
                             epsilon = 1.0e-6);
     }
+
+    #[test]
+    fn city_pairs_test() {
+        let london = Point::new(-0.1278, 51.5074);
+        let paris = Point::new(2.3522, 48.8566);
+        let new_york = Point::new(-74.0060, 40.7128);
+        let sydney = Point::new(151.2093, -33.8688);
+        assert_relative_eq!(london.haversine_distance(&paris), 343_500., epsilon = 1000.);
+        assert_relative_eq!(london.haversine_distance(&new_york), 5_570_000., epsilon = 1000.);
+        assert_relative_eq!(new_york.haversine_distance(&sydney), 15_989_000., epsilon = 1000.);
+        assert_relative_eq!(paris.haversine_distance(&london), london.haversine_distance(&paris));
+    }
+
+    #[test]
+    fn antipodal_test() {
+        let a = Point::<f64>::new(-0.1278, 51.5074);
+        let b = Point::new(179.8722, -51.5074);
+        let half_circumference = 6371000. * ::std::f64::consts::PI;
+        assert_relative_eq!(a.haversine_distance(&b), half_circumference, epsilon = 1e-3);
+    }
 }
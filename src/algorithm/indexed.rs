@@ -0,0 +1,124 @@
+use num_traits::Float;
+use types::{Bbox, Point, Polygon, MultiPolygon};
+use algorithm::boundingbox::BoundingBox;
+use algorithm::contains::Contains;
+
+/// A MultiPolygon along with the bounding box of each of its Polygons, for repeated queries
+///
+/// The bounding boxes are computed once, when the index is built, so the MultiPolygon can't be
+/// modified through the index.
+#[derive(PartialEq, Clone, Debug)]
+pub struct MultiPolygonIndexed<T>
+    where T: Float
+{
+    polygons: MultiPolygon<T>,
+    bboxes: Vec<Option<Bbox<T>>>,
+}
+
+impl<T> MultiPolygonIndexed<T>
+    where T: Float
+{
+    /// Builds an index of the Polygons in a MultiPolygon.
+    pub fn new(polygons: MultiPolygon<T>) -> MultiPolygonIndexed<T> {
+        let bboxes = polygons.0.iter().map(|poly| poly.bbox()).collect();
+        MultiPolygonIndexed { polygons, bboxes }
+    }
+
+    /// The MultiPolygon which has been indexed.
+    pub fn polygons(&self) -> &MultiPolygon<T> {
+        &self.polygons
+    }
+
+    /// Returns the Polygons whose bounding boxes contain a Point, which are the only ones which
+    /// can contain it.
+    pub fn candidates<'a>(&'a self, p: &'a Point<T>) -> impl Iterator<Item = &'a Polygon<T>> + 'a {
+        self.polygons.0
+            .iter()
+            .zip(&self.bboxes)
+            .filter(move |&(_, bbox)| match *bbox {
+                Some(bbox) => bbox.contains(p),
+                None => false,
+            })
+            .map(|(poly, _)| poly)
+    }
+}
+
+/// Checks if a geometry contains a Point, skipping the parts which can't contain it.
+pub trait ContainsFast<T: Float> {
+    /// Checks if a MultiPolygon contains a Point, as `contains` does
+    ///
+    /// The Point is first checked against the bounding box of each Polygon, and the full ray
+    /// cast is only run for Polygons whose bounding box contains it. A MultiPolygon computes its
+    /// bounding boxes on each call, while a `MultiPolygonIndexed` reuses those it has cached,
+    /// which is faster when checking many Points.
+    ///
+    /// ```
+    /// use geo::{Point, Polygon, MultiPolygon};
+    /// use geo::algorithm::indexed::{ContainsFast, MultiPolygonIndexed};
+    ///
+    /// let a = Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into(), vec![]);
+    /// let b = Polygon::new(vec![(5., 5.), (6., 5.), (6., 6.), (5., 6.), (5., 5.)].into(), vec![]);
+    /// let index = MultiPolygonIndexed::new(MultiPolygon(vec![a, b]));
+    ///
+    /// assert!(index.contains_fast(&Point::new(5.5, 5.5)));
+    /// assert!(!index.contains_fast(&Point::new(3., 3.)));
+    /// ```
+    fn contains_fast(&self, p: &Point<T>) -> bool;
+}
+
+impl<T> ContainsFast<T> for MultiPolygon<T>
+    where T: Float
+{
+    fn contains_fast(&self, p: &Point<T>) -> bool {
+        self.0.iter().any(|poly| match poly.bbox() {
+            Some(bbox) => bbox.contains(p) && poly.contains(p),
+            None => false,
+        })
+    }
+}
+
+impl<T> ContainsFast<T> for MultiPolygonIndexed<T>
+    where T: Float
+{
+    fn contains_fast(&self, p: &Point<T>) -> bool {
+        self.candidates(p).any(|poly| poly.contains(p))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon, MultiPolygon};
+    use super::*;
+
+    #[test]
+    fn contains_fast_test() {
+        // a 20 by 20 grid of squares, with holes in every other one
+        let mut polygons = vec![];
+        for i in 0..20 {
+            for j in 0..20 {
+                let (x, y) = (i as f64 * 3., j as f64 * 3.);
+                let exterior: LineString<f64> = vec![(x, y), (x + 2., y), (x + 2., y + 2.), (x, y + 2.), (x, y)].into();
+                let hole: LineString<f64> = vec![(x + 0.5, y + 0.5), (x + 0.5, y + 1.5), (x + 1.5, y + 1.5),
+                                                 (x + 1.5, y + 0.5), (x + 0.5, y + 0.5)]
+                    .into();
+                let interiors = if (i + j) % 2 == 0 { vec![hole] } else { vec![] };
+                polygons.push(Polygon::new(exterior, interiors));
+            }
+        }
+        let mp = MultiPolygon(polygons);
+        let index = MultiPolygonIndexed::new(mp.clone());
+        assert_eq!(index.polygons(), &mp);
+        let (mut evaluations, mut contained) = (0, 0);
+        for k in 0..200 {
+            let p = Point::new((k * 7 % 61) as f64 + 0.25 * (k % 4) as f64, (k * 13 % 59) as f64 + 0.3);
+            assert_eq!(index.contains_fast(&p), mp.contains(&p));
+            assert_eq!(mp.contains_fast(&p), mp.contains(&p));
+            evaluations += index.candidates(&p).count();
+            contained += mp.contains(&p) as usize;
+        }
+        assert!(contained > 0 && contained < 200);
+        // each point lies in the bounding box of at most one square
+        assert!(evaluations <= 200);
+        assert!(!MultiPolygonIndexed::new(MultiPolygon::<f64>(vec![])).contains_fast(&Point::new(0., 0.)));
+    }
+}
@@ -0,0 +1,142 @@
+use num_traits::Float;
+use types::{Bbox, Point, Line, Polygon};
+use algorithm::boundingbox::BoundingBox;
+use algorithm::clip::ClipToBbox;
+use algorithm::contains::Contains;
+
+// A rectangle lies within the polygon, touching its boundary being allowed, if the polygon's
+// boundary doesn't pass through the rectangle's interior, and its centre lies inside the polygon.
+// As the rectangle is convex, an edge passes through its interior exactly when the middle of the
+// part of the edge within the rectangle lies strictly inside it.
+fn within<T>(rect: &Bbox<T>, polygon: &Polygon<T>) -> bool
+    where T: Float
+{
+    let two = T::one() + T::one();
+    let enters = |line: &Line<T>| match line.clip_to_bbox(rect) {
+        Some(clipped) => {
+            let (x, y) = ((clipped.start.x() + clipped.end.x()) / two, (clipped.start.y() + clipped.end.y()) / two);
+            x > rect.xmin && x < rect.xmax && y > rect.ymin && y < rect.ymax
+        }
+        None => false,
+    };
+    !polygon.all_lines().any(|line| enters(&line)) &&
+        polygon.contains(&Point::new((rect.xmin + rect.xmax) / two, (rect.ymin + rect.ymax) / two))
+}
+
+/// Finds large axis-aligned rectangles inside a geometry.
+pub trait InscribedRect<T: Float> {
+    /// Returns the largest axis-aligned rectangle lying within a Polygon, among those whose
+    /// corners lie on a grid
+    ///
+    /// The grid starts at the bottom left corner of the Polygon's bounding box, and its lines are
+    /// `step` apart, with the far edges of the bounding box added as a final line in each
+    /// direction. Every rectangle with its corners at grid points is considered, and the largest
+    /// one lying within the Polygon, touching its boundary being allowed, is returned. The true
+    /// largest rectangle may be larger, by an amount which shrinks along with `step`. If several
+    /// are equally large, any one of them may be returned.
+    ///
+    /// This is useful for placing a rectangular label or panel inside a region. The number of
+    /// candidate rectangles grows with the fourth power of the number of grid lines, so `step`
+    /// shouldn't be too small relative to the size of the Polygon. Returns `None` if `step`
+    /// isn't positive, or if no rectangle fits.
+    ///
+    /// ```
+    /// use geo::{Bbox, Polygon};
+    /// use geo::algorithm::inscribed_rect::InscribedRect;
+    ///
+    /// let diamond = Polygon::new(vec![(2., 0.), (4., 2.), (2., 4.), (0., 2.), (2., 0.)].into(), vec![]);
+    /// let rect = diamond.largest_inscribed_rect(0.5).unwrap();
+    ///
+    /// assert_eq!(rect, Bbox { xmin: 1., xmax: 3., ymin: 1., ymax: 3. });
+    /// ```
+    fn largest_inscribed_rect(&self, step: T) -> Option<Bbox<T>>;
+}
+
+impl<T> InscribedRect<T> for Polygon<T>
+    where T: Float
+{
+    fn largest_inscribed_rect(&self, step: T) -> Option<Bbox<T>> {
+        let bbox = match self.bbox() {
+            Some(bbox) if step > T::zero() => bbox,
+            _ => return None,
+        };
+        let lines = |min: T, max: T| {
+            let count = ((max - min) / step).ceil().to_usize().unwrap_or(0);
+            let mut lines: Vec<T> = (0..count).map(|idx| min + T::from(idx).unwrap() * step).collect();
+            lines.push(max);
+            lines
+        };
+        let (xs, ys) = (lines(bbox.xmin, bbox.xmax), lines(bbox.ymin, bbox.ymax));
+        let mut best: Option<(Bbox<T>, T)> = None;
+        for (i, &ymin) in ys.iter().enumerate() {
+            for (j, &xmin) in xs.iter().enumerate() {
+                for &xmax in &xs[j + 1..] {
+                    for &ymax in &ys[i + 1..] {
+                        let area = (xmax - xmin) * (ymax - ymin);
+                        let rect = Bbox { xmin, xmax, ymin, ymax };
+                        // any taller rectangle contains this one, so won't fit either
+                        if !within(&rect, self) {
+                            break;
+                        }
+                        match best {
+                            Some((_, largest)) if largest >= area => {}
+                            _ => best = Some((rect, area)),
+                        }
+                    }
+                }
+            }
+        }
+        best.map(|(rect, _)| rect)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Bbox, Point, LineString, Polygon};
+    use algorithm::distance::Distance;
+    use super::*;
+
+    #[test]
+    fn largest_inscribed_rect_triangle_test() {
+        // the largest rectangle in a right triangle spans half of each leg
+        let triangle = Polygon::new(vec![(0., 0.), (4., 0.), (0., 4.), (0., 0.)].into(), vec![]);
+        let rect = triangle.largest_inscribed_rect(0.5).unwrap();
+        assert_relative_eq!((rect.xmax - rect.xmin) * (rect.ymax - rect.ymin), 4.);
+        // a convex polygon contains a rectangle whose corners it contains
+        for corner in &[(rect.xmin, rect.ymin), (rect.xmax, rect.ymin), (rect.xmax, rect.ymax), (rect.xmin, rect.ymax)] {
+            let p = Point::new(corner.0, corner.1);
+            assert!(triangle.contains(&p) || p.distance(&triangle.exterior) < 1e-10);
+        }
+    }
+    #[test]
+    fn largest_inscribed_rect_convex_test() {
+        // an irregular hexagon, whose largest inscribed rectangle spans its top and bottom edges:
+        // widening it across the slanted sides costs twice as much height
+        let hexagon = Polygon::new(vec![(1., 0.), (5., 0.), (6., 2.), (5., 4.), (1., 4.), (0., 2.), (1., 0.)].into(),
+                                   vec![]);
+        let coarse = hexagon.largest_inscribed_rect(1.).unwrap();
+        assert_eq!(coarse, Bbox { xmin: 1., xmax: 5., ymin: 0., ymax: 4. });
+        let fine = hexagon.largest_inscribed_rect(0.25).unwrap();
+        assert_eq!(fine, coarse);
+        assert!(within(&fine, &hexagon));
+        let wider = Bbox { xmin: 0.75, xmax: 5.25, ymin: 0.5, ymax: 3.5 };
+        assert!(within(&wider, &hexagon));
+        assert!((wider.xmax - wider.xmin) * (wider.ymax - wider.ymin) < 16.);
+    }
+    #[test]
+    fn largest_inscribed_rect_hole_test() {
+        let exterior: LineString<f64> = vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into();
+        let hole: LineString<f64> = vec![(1., 1.), (3., 1.), (3., 3.), (1., 3.), (1., 1.)].into();
+        let frame = Polygon::new(exterior, vec![hole]);
+        // a strip down one side of the frame
+        let rect = frame.largest_inscribed_rect(1.).unwrap();
+        assert_eq!((rect.xmax - rect.xmin) * (rect.ymax - rect.ymin), 4.);
+        assert!(within(&rect, &frame));
+        // but not across the hole
+        assert!(!within(&Bbox { xmin: 0., xmax: 2., ymin: 0., ymax: 2. }, &frame));
+        // even when the hole only touches its boundary
+        assert!(!within(&Bbox { xmin: 0., xmax: 4., ymin: 1., ymax: 3. }, &frame));
+        assert!(frame.largest_inscribed_rect(0.).is_none());
+        assert!(Polygon::<f64>::new(LineString(vec![]), vec![]).largest_inscribed_rect(1.).is_none());
+    }
+}
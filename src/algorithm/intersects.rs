@@ -1,6 +1,7 @@
 use num_traits::Float;
-use types::{Line, LineString, Polygon, Bbox, Point};
+use types::{Line, LineString, Polygon, Bbox, Point, Geometry};
 use algorithm::contains::Contains;
+use algorithm::boundingbox::BoundingBox;
 
 /// Checks if the geometry A intersects the geometry B.
 
@@ -65,6 +66,11 @@ impl<T> Intersects<Line<T>> for Line<T>
     where T: Float
 {
     fn intersects(&self, line: &Line<T>) -> bool {
+        // segments whose bounding boxes don't overlap can't intersect
+        let (a, b) = (self.bbox(), line.bbox());
+        if a.xmax < b.xmin || b.xmax < a.xmin || a.ymax < b.ymin || b.ymax < a.ymin {
+            return false;
+        }
         // Using Cramer's Rule:
         // https://en.wikipedia.org/wiki/Intersection_%28Euclidean_geometry%29#Two_line_segments
         let (x1, y1, x2, y2) = (self.start.x(), self.start.y(),
@@ -220,10 +226,124 @@ impl<T> Intersects<Polygon<T>> for Polygon<T>
     }
 }
 
+// the simple geometries which the members of a Geometry are made of
+enum Primitive<'a, T>
+    where T: 'a + Float
+{
+    Point(&'a Point<T>),
+    LineString(&'a LineString<T>),
+    Polygon(&'a Polygon<T>),
+}
+
+// flatten a Geometry, including any nested collections, into its simple members
+fn primitives<'a, T>(geometry: &'a Geometry<T>, out: &mut Vec<Primitive<'a, T>>)
+    where T: Float
+{
+    match *geometry {
+        Geometry::Point(ref p) => out.push(Primitive::Point(p)),
+        Geometry::LineString(ref ls) => out.push(Primitive::LineString(ls)),
+        Geometry::Polygon(ref poly) => out.push(Primitive::Polygon(poly)),
+        Geometry::MultiPoint(ref mp) => out.extend(mp.0.iter().map(Primitive::Point)),
+        Geometry::MultiLineString(ref mls) => out.extend(mls.0.iter().map(Primitive::LineString)),
+        Geometry::MultiPolygon(ref mp) => out.extend(mp.0.iter().map(Primitive::Polygon)),
+        Geometry::GeometryCollection(ref gc) => {
+            for g in &gc.0 {
+                primitives(g, out);
+            }
+        }
+    }
+}
+
+// a Point intersects a LineString if it lies on one of its segments, or is its only vertex
+fn point_intersects_linestring<T>(p: &Point<T>, linestring: &LineString<T>) -> bool
+    where T: Float
+{
+    if linestring.0.len() == 1 {
+        return linestring.0[0] == *p;
+    }
+    linestring.lines().any(|line| line.intersects(p))
+}
+
+// Polygon::contains excludes the boundary, so check the rings separately
+fn point_intersects_polygon<T>(p: &Point<T>, polygon: &Polygon<T>) -> bool
+    where T: Float
+{
+    polygon.contains(p) || point_intersects_linestring(p, &polygon.exterior) ||
+        polygon.interiors.iter().any(|inner| point_intersects_linestring(p, inner))
+}
+
+fn primitives_intersect<T>(a: &Primitive<T>, b: &Primitive<T>) -> bool
+    where T: Float
+{
+    match (a, b) {
+        (&Primitive::Point(p), &Primitive::Point(q)) => p == q,
+        (&Primitive::Point(p), &Primitive::LineString(ls)) |
+        (&Primitive::LineString(ls), &Primitive::Point(p)) => point_intersects_linestring(p, ls),
+        (&Primitive::Point(p), &Primitive::Polygon(poly)) |
+        (&Primitive::Polygon(poly), &Primitive::Point(p)) => point_intersects_polygon(p, poly),
+        (&Primitive::LineString(ls1), &Primitive::LineString(ls2)) => ls1.intersects(ls2),
+        (&Primitive::LineString(ls), &Primitive::Polygon(poly)) |
+        (&Primitive::Polygon(poly), &Primitive::LineString(ls)) => poly.intersects(ls),
+        (&Primitive::Polygon(poly1), &Primitive::Polygon(poly2)) => poly1.intersects(poly2),
+    }
+}
+
+impl<T> Intersects<Geometry<T>> for Geometry<T>
+    where T: Float
+{
+    /// Checks if two Geometries of any variant intersect
+    ///
+    /// Multi-geometries and GeometryCollections are broken down into their Points,
+    /// LineStrings and Polygons, and intersect if any pair of members from either side does.
+    /// Every pair of members is tested exactly, using the implementations for the concrete
+    /// types (or a direct test for pairs involving a Point), so no bounding box approximation
+    /// is ever needed.
+    ///
+    /// ```
+    /// use geo::{Geometry, Point, Polygon};
+    /// use geo::algorithm::intersects::Intersects;
+    ///
+    /// let square = Polygon::new(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into(), vec![]);
+    /// let polygon = Geometry::Polygon(square);
+    ///
+    /// assert!(polygon.intersects(&Geometry::Point(Point::new(4., 2.))));
+    /// assert!(!polygon.intersects(&Geometry::Point(Point::new(5., 2.))));
+    /// ```
+    fn intersects(&self, geometry: &Geometry<T>) -> bool {
+        let (mut lhs, mut rhs) = (vec![], vec![]);
+        primitives(self, &mut lhs);
+        primitives(geometry, &mut rhs);
+        lhs.iter().any(|a| rhs.iter().any(|b| primitives_intersect(a, b)))
+    }
+}
+
+/// Checks if a geometry intersects any Line of a slice
+///
+/// No geometry intersects an empty slice.
+///
+/// ```
+/// use geo::{Point, Line, Polygon};
+/// use geo::algorithm::intersects::intersects_any;
+///
+/// let square = Polygon::new(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)].into(), vec![]);
+/// let far = Line::new(Point::new(5., 0.), Point::new(5., 2.));
+/// let crossing = Line::new(Point::new(1., -1.), Point::new(1., 3.));
+///
+/// assert!(intersects_any(&square, &[far, crossing]));
+/// assert!(!intersects_any(&square, &[far]));
+/// ```
+pub fn intersects_any<T, G>(g: &G, lines: &[Line<T>]) -> bool
+    where T: Float,
+          G: Intersects<Line<T>>
+{
+    lines.iter().any(|line| g.intersects(line))
+}
+
 #[cfg(test)]
 mod test {
-    use types::{Coordinate, Point, Line, LineString, Polygon, Bbox};
-    use algorithm::intersects::Intersects;
+    use types::{Coordinate, Point, Line, LineString, Polygon, Bbox, Geometry, MultiPoint,
+                GeometryCollection};
+    use algorithm::intersects::{Intersects, intersects_any};
     /// Tests: intersection LineString and LineString
     #[test]
     fn empty_linestring1_test() {
@@ -488,6 +608,23 @@ mod test {
         assert!(!line1.intersects(&line3));
     }
     #[test]
+    fn line_intersects_line_bbox_test() {
+        let line = Line::new(Point::new(0., 0.), Point::new(2., 2.));
+        // far apart, and in line but beyond the end, both rejected by their bounding boxes
+        assert!(!line.intersects(&Line::new(Point::new(100., -50.), Point::new(120., -40.))));
+        assert!(!line.intersects(&Line::new(Point::new(3., 3.), Point::new(4., 4.))));
+        // overlapping bounding boxes, but disjoint segments
+        assert!(!line.intersects(&Line::new(Point::new(0., 1.), Point::new(0.5, 2.))));
+        // bounding boxes which only share a corner, where the segments meet
+        assert!(line.intersects(&Line::new(Point::new(2., 2.), Point::new(3., 5.))));
+        assert!(line.intersects(&Line::new(Point::new(0., 2.), Point::new(2., 0.))));
+        // vertical and horizontal segments have flat bounding boxes
+        let vertical = Line::new(Point::new(1., -1.), Point::new(1., 3.));
+        let horizontal = Line::new(Point::new(-1., 1.), Point::new(3., 1.));
+        assert!(vertical.intersects(&horizontal));
+        assert!(!vertical.intersects(&Line::new(Point::new(2., -1.), Point::new(2., 3.))));
+    }
+    #[test]
     fn line_intersects_linestring_test() {
         let line0 = Line::new(Point::new(0., 0.), Point::new(3., 4.));
         let linestring0 = LineString(
@@ -530,4 +667,55 @@ mod test {
         assert!(!line0.intersects(&poly2));
         assert!(!poly2.intersects(&line0));
     }
+    #[test]
+    fn geometry_linestring_polygon_test() {
+        let poly = Geometry::Polygon(Polygon::new(vec![(0., 0.), (5., 0.), (5., 6.), (0., 6.), (0., 0.)].into(),
+                                                  vec![]));
+        let crossing = Geometry::LineString(vec![(2., 2.), (6., 6.)].into());
+        let outside = Geometry::LineString(vec![(7., 2.), (9., 4.)].into());
+        assert!(crossing.intersects(&poly));
+        assert!(poly.intersects(&crossing));
+        assert!(!outside.intersects(&poly));
+        assert!(!poly.intersects(&outside));
+    }
+    #[test]
+    fn geometry_point_test() {
+        let p = Geometry::Point(Point::new(1., 1.));
+        assert!(p.intersects(&Geometry::Point(Point::new(1., 1.))));
+        assert!(!p.intersects(&Geometry::Point(Point::new(1., 2.))));
+        assert!(p.intersects(&Geometry::LineString(vec![(0., 0.), (2., 2.)].into())));
+        assert!(!p.intersects(&Geometry::LineString(vec![(0., 0.), (2., 0.)].into())));
+        // a hole excludes its interior but not its boundary
+        let e: LineString<f64> = vec![(-5., -5.), (5., -5.), (5., 5.), (-5., 5.), (-5., -5.)].into();
+        let hole: LineString<f64> = vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into();
+        let poly = Geometry::Polygon(Polygon::new(e, vec![hole]));
+        assert!(p.intersects(&poly));
+        assert!(!Geometry::Point(Point::new(0.5, 0.5)).intersects(&poly));
+        assert!(Geometry::Point(Point::new(-2., 3.)).intersects(&poly));
+    }
+    #[test]
+    fn geometry_collections_test() {
+        let mp = Geometry::MultiPoint(MultiPoint(vec![Point::new(10., 10.), Point::new(3., 0.)]));
+        let line = Geometry::LineString(vec![(0., 0.), (5., 0.)].into());
+        assert!(mp.intersects(&line));
+        let nested = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::GeometryCollection(GeometryCollection(vec![line.clone()]))]));
+        assert!(nested.intersects(&mp));
+        assert!(!nested.intersects(&Geometry::GeometryCollection(GeometryCollection(vec![]))));
+        assert!(!nested.intersects(&Geometry::Point(Point::new(10., 10.))));
+    }
+    #[test]
+    fn intersects_any_test() {
+        let ls: LineString<f64> = vec![(0., 0.), (2., 0.), (2., 2.)].into();
+        let apart = Line::new(Point::new(0., 1.), Point::new(1., 2.));
+        let touching = Line::new(Point::new(2., 2.), Point::new(3., 3.));
+        let crossing = Line::new(Point::new(1., -1.), Point::new(1., 1.));
+        assert!(!intersects_any(&ls, &[apart]));
+        assert!(intersects_any(&ls, &[apart, touching]));
+        assert!(intersects_any(&ls, &[crossing, apart]));
+        assert!(!intersects_any(&ls, &[]));
+        // any geometry intersecting Lines will do
+        assert!(intersects_any(&Point::new(1., 0.), &[apart, crossing]));
+        assert!(!intersects_any(&Point::new(5., 5.), &[apart, crossing]));
+    }
 }
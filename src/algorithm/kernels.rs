@@ -0,0 +1,91 @@
+use std::cmp::Ordering;
+use num_traits::Float;
+use types::{Point, Line, LineString};
+
+// twice the signed area of the triangle (a, b, c): positive if it turns counter-clockwise
+pub fn cross<T>(a: &Point<T>, b: &Point<T>, c: &Point<T>) -> T
+    where T: Float
+{
+    (b.x() - a.x()) * (c.y() - a.y()) - (b.y() - a.y()) * (c.x() - a.x())
+}
+
+// true if p lies inside or on the boundary of the triangle (a, b, c), of either orientation
+pub fn in_triangle<T>(p: &Point<T>, a: &Point<T>, b: &Point<T>, c: &Point<T>) -> bool
+    where T: Float
+{
+    let (d1, d2, d3) = (cross(a, b, p), cross(b, c, p), cross(c, a, p));
+    (d1 >= T::zero() && d2 >= T::zero() && d3 >= T::zero()) ||
+        (d1 <= T::zero() && d2 <= T::zero() && d3 <= T::zero())
+}
+
+// opposite strict signs
+pub fn straddle<T>(a: T, b: T) -> bool
+    where T: Float
+{
+    (a > T::zero() && b < T::zero()) || (a < T::zero() && b > T::zero())
+}
+
+// true if the segments cross at a single point which is interior to both of them
+pub fn crosses<T>(a: &Line<T>, b: &Line<T>) -> bool
+    where T: Float
+{
+    straddle(cross(&a.start, &a.end, &b.start), cross(&a.start, &a.end, &b.end)) &&
+        straddle(cross(&b.start, &b.end, &a.start), cross(&b.start, &b.end, &a.end))
+}
+
+// lexicographic comparison of two points: by x, then by y
+pub fn lexicographic<T>(a: &Point<T>, b: &Point<T>) -> Ordering
+    where T: Float
+{
+    match a.x().partial_cmp(&b.x()) {
+        Some(Ordering::Equal) | None => a.y().partial_cmp(&b.y()).unwrap_or(Ordering::Equal),
+        Some(ordering) => ordering,
+    }
+}
+
+// the point at position t along the segment, from its start at 0 to its end at 1
+pub fn point_along<T>(line: &Line<T>, t: T) -> Point<T>
+    where T: Float
+{
+    Point::new(line.start.x() + t * (line.end.x() - line.start.x()),
+               line.start.y() + t * (line.end.y() - line.start.y()))
+}
+
+// the nearest point to p on the segment, and its position along it from 0 at its start to 1 at
+// its end, found by projecting p onto the line through it and clamping to the segment's ends. A
+// degenerate segment is projected onto its start.
+pub fn project_onto_segment<T>(p: &Point<T>, line: &Line<T>) -> (Point<T>, T)
+    where T: Float
+{
+    let (dx, dy) = (line.end.x() - line.start.x(), line.end.y() - line.start.y());
+    let length_squared = dx * dx + dy * dy;
+    if length_squared == T::zero() {
+        return (line.start, T::zero());
+    }
+    let t = ((p.x() - line.start.x()) * dx + (p.y() - line.start.y()) * dy) / length_squared;
+    let t = t.max(T::zero()).min(T::one());
+    (point_along(line, t), t)
+}
+
+// The edges of a ring crossed by a ray cast from p towards positive x. Each edge is half-open,
+// including its lower endpoint but not its upper one, so where the ray passes exactly through a
+// vertex it crosses a single edge if the ring continues to the other side, and none (or two) if
+// it doesn't. Horizontal edges are never crossed.
+pub fn crossed_edges<'a, T>(p: &Point<T>, ring: &'a LineString<T>) -> impl Iterator<Item = Line<T>> + 'a
+    where T: Float
+{
+    let p = *p;
+    ring.lines()
+        .filter(move |line| (line.start.y() <= p.y()) != (line.end.y() <= p.y()))
+        .filter(move |line| {
+            let xints = (p.y() - line.start.y()) * (line.end.x() - line.start.x()) /
+                        (line.end.y() - line.start.y()) + line.start.x();
+            p.x() < xints
+        })
+}
+
+// an axis-aligned square, for tests, with its bottom left corner at (x, y)
+#[cfg(test)]
+pub fn square(x: f64, y: f64, size: f64) -> ::types::Polygon<f64> {
+    ::types::Polygon::new(vec![(x, y), (x + size, y), (x + size, y + size), (x, y + size), (x, y)].into(), vec![])
+}
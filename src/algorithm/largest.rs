@@ -0,0 +1,129 @@
+use std::cmp::Ordering;
+use num_traits::Float;
+use types::{Polygon, MultiPolygon};
+use algorithm::area::Area;
+
+// compare two Polygons by their area
+fn compare_area<T>(a: &Polygon<T>, b: &Polygon<T>) -> Ordering
+    where T: Float
+{
+    a.area().partial_cmp(&b.area()).unwrap_or(Ordering::Equal)
+}
+
+/// Select the members of a geometry collection by their area.
+pub trait Largest<T> {
+    /// Returns the member Polygon with the largest area, or `None` if there are no members.
+    ///
+    /// If several members share the largest area, the first one is returned.
+    ///
+    /// ```
+    /// use geo::{Polygon, MultiPolygon};
+    /// use geo::algorithm::largest::Largest;
+    ///
+    /// let small = Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into(), vec![]);
+    /// let big = Polygon::new(vec![(5., 5.), (8., 5.), (8., 8.), (5., 8.), (5., 5.)].into(), vec![]);
+    /// let mp = MultiPolygon(vec![small, big.clone()]);
+    ///
+    /// assert_eq!(mp.largest(), Some(&big));
+    /// ```
+    fn largest(&self) -> Option<&Polygon<T>> where T: Float;
+
+    /// Returns references to the member Polygons, sorted by area from largest to smallest.
+    ///
+    /// Members with equal area keep their original relative order.
+    ///
+    /// ```
+    /// use geo::{Polygon, MultiPolygon};
+    /// use geo::algorithm::largest::Largest;
+    ///
+    /// let small = Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into(), vec![]);
+    /// let big = Polygon::new(vec![(5., 5.), (8., 5.), (8., 8.), (5., 8.), (5., 5.)].into(), vec![]);
+    /// let mp = MultiPolygon(vec![small.clone(), big.clone()]);
+    ///
+    /// assert_eq!(mp.sorted_by_area(), vec![&big, &small]);
+    /// ```
+    fn sorted_by_area(&self) -> Vec<&Polygon<T>> where T: Float;
+
+    /// Returns the member Polygons whose area is at least `min_area`, in their original order.
+    ///
+    /// Areas are compared regardless of the orientation of the exteriors.
+    ///
+    /// ```
+    /// use geo::{Polygon, MultiPolygon};
+    /// use geo::algorithm::largest::Largest;
+    ///
+    /// let small = Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into(), vec![]);
+    /// let big = Polygon::new(vec![(5., 5.), (8., 5.), (8., 8.), (5., 8.), (5., 5.)].into(), vec![]);
+    /// let mp = MultiPolygon(vec![small, big.clone()]);
+    ///
+    /// assert_eq!(mp.filter_by_area(2.), MultiPolygon(vec![big]));
+    /// ```
+    fn filter_by_area(&self, min_area: T) -> MultiPolygon<T> where T: Float;
+}
+
+impl<T> Largest<T> for MultiPolygon<T>
+    where T: Float
+{
+    fn largest(&self) -> Option<&Polygon<T>> {
+        self.0.iter().fold(None, |largest, poly| match largest {
+            Some(current) if compare_area(poly, current) != Ordering::Greater => Some(current),
+            _ => Some(poly),
+        })
+    }
+
+    fn sorted_by_area(&self) -> Vec<&Polygon<T>> {
+        let mut sorted: Vec<&Polygon<T>> = self.0.iter().collect();
+        // sort_by is stable, so equal areas keep their order
+        sorted.sort_by(|a, b| compare_area(b, a));
+        sorted
+    }
+
+    fn filter_by_area(&self, min_area: T) -> MultiPolygon<T> {
+        MultiPolygon(self.0.iter().filter(|poly| poly.area() >= min_area).cloned().collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Polygon, MultiPolygon};
+    use algorithm::kernels::square;
+    use super::*;
+
+    #[test]
+    fn largest_test() {
+        let small = square(0., 0., 1.);
+        let large = square(10., 10., 5.);
+        let medium = square(20., 20., 3.);
+        let mp = MultiPolygon(vec![small.clone(), large.clone(), medium.clone()]);
+        assert_eq!(mp.largest(), Some(&large));
+        assert_eq!(mp.sorted_by_area(), vec![&large, &medium, &small]);
+    }
+    #[test]
+    fn largest_clockwise_test() {
+        // a clockwise exterior has a negative signed area, but must still be the largest
+        let small = square(0., 0., 1.);
+        let large = Polygon::new(vec![(0., 0.), (0., 4.), (4., 4.), (4., 0.), (0., 0.)].into(), vec![]);
+        let mp = MultiPolygon(vec![small.clone(), large.clone()]);
+        assert_eq!(mp.largest(), Some(&large));
+        assert_eq!(mp.sorted_by_area(), vec![&large, &small]);
+    }
+    #[test]
+    fn filter_by_area_test() {
+        let sliver = Polygon::new(vec![(0., 0.), (1., 0.), (1., 0.01), (0., 0.01), (0., 0.)].into(), vec![]);
+        let large = Polygon::new(vec![(0., 0.), (2., 0.), (2., 2.5), (0., 2.5), (0., 0.)].into(), vec![]);
+        assert_relative_eq!(sliver.area(), 0.01);
+        assert_relative_eq!(large.area(), 5.);
+        let mp = MultiPolygon(vec![sliver.clone(), large.clone(), square(5., 5., 3.)]);
+        assert_eq!(mp.filter_by_area(1.), MultiPolygon(vec![large.clone(), square(5., 5., 3.)]));
+        // the threshold is inclusive
+        assert_eq!(mp.filter_by_area(5.).0.len(), 2);
+        assert_eq!(mp.filter_by_area(0.).0.len(), 3);
+    }
+    #[test]
+    fn largest_empty_test() {
+        let mp = MultiPolygon::<f64>(vec![]);
+        assert!(mp.largest().is_none());
+        assert!(mp.sorted_by_area().is_empty());
+        assert!(mp.filter_by_area(0.).0.is_empty());
+    }
+}
@@ -1,6 +1,6 @@
 use num_traits::Float;
 
-use types::{Line, LineString, MultiLineString};
+use types::{Line, LineString, MultiLineString, Polygon, MultiPolygon};
 use algorithm::distance::Distance;
 
 /// Calculation of the length
@@ -49,10 +49,45 @@ impl<T> Length<T> for MultiLineString<T>
     }
 }
 
+/// Calculation of the perimeter.
+pub trait Perimeter<T> {
+    /// Calculation of the perimeter of a Polygon, the total length of its exterior and interior
+    /// rings
+    ///
+    /// A MultiPolygon sums the perimeters of its Polygons, and an empty Polygon has a perimeter
+    /// of zero.
+    ///
+    /// ```
+    /// use geo::Polygon;
+    /// use geo::algorithm::length::Perimeter;
+    ///
+    /// let rect = Polygon::new(vec![(0., 0.), (2., 0.), (2., 3.), (0., 3.), (0., 0.)].into(), vec![]);
+    ///
+    /// assert_eq!(rect.perimeter(), 10.);
+    /// ```
+    fn perimeter(&self) -> T;
+}
+
+impl<T> Perimeter<T> for Polygon<T>
+    where T: Float
+{
+    fn perimeter(&self) -> T {
+        self.interiors.iter().fold(self.exterior.length(), |total, ring| total + ring.length())
+    }
+}
+
+impl<T> Perimeter<T> for MultiPolygon<T>
+    where T: Float
+{
+    fn perimeter(&self) -> T {
+        self.0.iter().fold(T::zero(), |total, poly| total + poly.perimeter())
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use types::{Coordinate, Point, Line, LineString, MultiLineString};
-    use algorithm::length::Length;
+    use types::{Coordinate, Point, Line, LineString, MultiLineString, Polygon, MultiPolygon};
+    use algorithm::length::{Length, Perimeter};
 
     #[test]
     fn empty_linestring_test() {
@@ -84,4 +119,16 @@ mod test {
         assert_eq!(line0.length(), 1.);
         assert_eq!(line1.length(), 5.);
     }
+    #[test]
+    fn perimeter_test() {
+        let rect = Polygon::new(vec![(0., 0.), (2., 0.), (2., 3.), (0., 3.), (0., 0.)].into(), vec![]);
+        assert_eq!(rect.perimeter(), 10.);
+        // the hole's perimeter is included
+        let frame = Polygon::new(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into(),
+                                 vec![vec![(1., 1.), (1., 2.), (2., 2.), (2., 1.), (1., 1.)].into()]);
+        assert_eq!(frame.perimeter(), 16. + 4.);
+        assert_eq!(MultiPolygon(vec![rect, frame]).perimeter(), 30.);
+        assert_eq!(Polygon::<f64>::new(LineString(vec![]), vec![]).perimeter(), 0.);
+        assert_eq!(MultiPolygon::<f64>(vec![]).perimeter(), 0.);
+    }
 }
@@ -0,0 +1,85 @@
+use num_traits::Float;
+use types::{Point, LineString, Polygon};
+
+// interpolates between two rings of the same length, vertex by vertex
+fn lerp_ring<T>(a: &LineString<T>, b: &LineString<T>, t: T) -> Option<LineString<T>>
+    where T: Float
+{
+    if a.0.len() != b.0.len() {
+        return None;
+    }
+    Some(a.0
+        .iter()
+        .zip(&b.0)
+        .map(|(p, q)| Point::new(p.x() + (q.x() - p.x()) * t, p.y() + (q.y() - p.y()) * t))
+        .collect())
+}
+
+/// Interpolates between two geometries, for animating from one shape to another.
+pub trait Lerp<T: Float> {
+    /// Returns the Polygon lying a fraction `t` of the way from this Polygon to another, by
+    /// linearly interpolating the positions of their corresponding vertices
+    ///
+    /// `t` is 0 at this Polygon and 1 at `other`, and may lie outside that range to extrapolate.
+    /// The two Polygons must have the same number of interiors, and each of their rings the
+    /// same number of vertices as its counterpart, otherwise `None` is returned. Vertices
+    /// correspond by their position in the rings, so rings should start at matching points and
+    /// run in the same direction for the morph to look natural.
+    ///
+    /// ```
+    /// use geo::Polygon;
+    /// use geo::algorithm::lerp::Lerp;
+    ///
+    /// let small = Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 0.)].into(), vec![]);
+    /// let large = Polygon::new(vec![(0., 0.), (3., 0.), (3., 3.), (0., 0.)].into(), vec![]);
+    /// let morphed = small.lerp(&large, 0.5).unwrap();
+    ///
+    /// assert_eq!(morphed.exterior, vec![(0., 0.), (2., 0.), (2., 2.), (0., 0.)].into());
+    /// ```
+    fn lerp(&self, other: &Polygon<T>, t: T) -> Option<Polygon<T>>;
+}
+
+impl<T> Lerp<T> for Polygon<T>
+    where T: Float
+{
+    fn lerp(&self, other: &Polygon<T>, t: T) -> Option<Polygon<T>> {
+        if self.interiors.len() != other.interiors.len() {
+            return None;
+        }
+        let exterior = lerp_ring(&self.exterior, &other.exterior, t)?;
+        let interiors = self.interiors
+            .iter()
+            .zip(&other.interiors)
+            .map(|(a, b)| lerp_ring(a, b, t))
+            .collect::<Option<Vec<_>>>()?;
+        Some(Polygon::new(exterior, interiors))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{LineString, Polygon};
+    use algorithm::translate::Translate;
+    use super::*;
+
+    #[test]
+    fn lerp_test() {
+        let exterior: LineString<f64> = vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into();
+        let hole: LineString<f64> = vec![(1., 1.), (1., 3.), (3., 3.), (3., 1.), (1., 1.)].into();
+        let square = Polygon::new(exterior, vec![hole]);
+        let moved = square.translate(10., -6.);
+        assert_eq!(square.lerp(&moved, 0.5), Some(square.translate(5., -3.)));
+        assert_eq!(square.lerp(&moved, 0.), Some(square.clone()));
+        assert_eq!(square.lerp(&moved, 1.), Some(moved));
+    }
+    #[test]
+    fn lerp_mismatch_test() {
+        let square = Polygon::new(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into(), vec![]);
+        let triangle = Polygon::new(vec![(0., 0.), (4., 0.), (4., 4.), (0., 0.)].into(), vec![]);
+        assert!(square.lerp(&triangle, 0.5).is_none());
+        let holed = Polygon::new(square.exterior.clone(),
+                                 vec![vec![(1., 1.), (1., 3.), (3., 3.), (1., 1.)].into()]);
+        assert!(square.lerp(&holed, 0.5).is_none());
+        assert!(holed.lerp(&square, 0.5).is_none());
+    }
+}
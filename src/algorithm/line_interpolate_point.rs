@@ -0,0 +1,75 @@
+use num_traits::Float;
+use types::{Point, LineString};
+use algorithm::length::Length;
+use algorithm::kernels::point_along;
+
+/// Finds the point a given fraction of the way along a geometry.
+pub trait LineInterpolatePoint<T: Float> {
+    /// Returns the point `fraction` of the way along a LineString, measured by length
+    ///
+    /// A fraction of 0 gives the start of the LineString and 1 its end; fractions outside that
+    /// range are clamped to it. A LineString of zero length gives its first point, and an empty
+    /// one `None`.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::line_interpolate_point::LineInterpolatePoint;
+    ///
+    /// let ls: LineString<f64> = vec![(0., 0.), (3., 0.), (3., 1.)].into();
+    ///
+    /// assert_eq!(ls.line_interpolate_point(0.25), Some(Point::new(1., 0.)));
+    /// assert_eq!(ls.line_interpolate_point(2.), Some(Point::new(3., 1.)));
+    /// ```
+    fn line_interpolate_point(&self, fraction: T) -> Option<Point<T>>;
+}
+
+impl<T> LineInterpolatePoint<T> for LineString<T>
+    where T: Float
+{
+    fn line_interpolate_point(&self, fraction: T) -> Option<Point<T>> {
+        let first = *self.0.first()?;
+        let total = self.length();
+        let fraction = fraction.max(T::zero()).min(T::one());
+        if total == T::zero() {
+            return Some(first);
+        }
+        let mut remaining = fraction * total;
+        for line in self.lines() {
+            let length = line.length();
+            if remaining <= length && length > T::zero() {
+                return Some(point_along(&line, remaining / length));
+            }
+            remaining = remaining - length;
+        }
+        // rounding left the target just past the end
+        self.0.last().cloned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString};
+    use super::*;
+
+    #[test]
+    fn line_interpolate_point_test() {
+        // an L-shaped path, 4 along and 4 up
+        let ls: LineString<f64> = vec![(0., 0.), (4., 0.), (4., 4.)].into();
+        assert_eq!(ls.line_interpolate_point(0.), Some(Point::new(0., 0.)));
+        assert_eq!(ls.line_interpolate_point(0.5), Some(Point::new(4., 0.)));
+        assert_eq!(ls.line_interpolate_point(0.75), Some(Point::new(4., 2.)));
+        assert_eq!(ls.line_interpolate_point(1.), Some(Point::new(4., 4.)));
+        // clamped to either end
+        assert_eq!(ls.line_interpolate_point(-0.5), Some(Point::new(0., 0.)));
+        assert_eq!(ls.line_interpolate_point(1.5), Some(Point::new(4., 4.)));
+    }
+    #[test]
+    fn line_interpolate_point_degenerate_test() {
+        assert_eq!(LineString::<f64>(vec![]).line_interpolate_point(0.5), None);
+        let point = LineString(vec![Point::new(1., 2.)]);
+        assert_eq!(point.line_interpolate_point(0.5), Some(Point::new(1., 2.)));
+        // a repeated vertex is skipped over
+        let repeated: LineString<f64> = vec![(0., 0.), (0., 0.), (2., 0.)].into();
+        assert_eq!(repeated.line_interpolate_point(0.5), Some(Point::new(1., 0.)));
+    }
+}
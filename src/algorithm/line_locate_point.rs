@@ -0,0 +1,77 @@
+use num_traits::Float;
+use types::{Point, LineString};
+use algorithm::length::Length;
+use algorithm::project::ProjectOnto;
+
+/// Finds how far along a geometry a Point lies.
+pub trait LineLocatePoint<T: Float> {
+    /// Returns the fraction of the length of a LineString lying before the point on it nearest
+    /// to `p`
+    ///
+    /// This is the inverse of `line_interpolate_point`: a Point on the LineString is located
+    /// exactly, and a Point off it is first snapped to the nearest point on it. The result lies
+    /// between 0 and 1. A LineString of zero length gives 0, and an empty one `None`.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::line_locate_point::LineLocatePoint;
+    ///
+    /// let ls: LineString<f64> = vec![(0., 0.), (3., 0.), (3., 1.)].into();
+    ///
+    /// assert_eq!(ls.line_locate_point(&Point::new(1., 0.)), Some(0.25));
+    /// assert_eq!(ls.line_locate_point(&Point::new(5., 0.5)), Some(0.875));
+    /// ```
+    fn line_locate_point(&self, p: &Point<T>) -> Option<T>;
+}
+
+impl<T> LineLocatePoint<T> for LineString<T>
+    where T: Float
+{
+    fn line_locate_point(&self, p: &Point<T>) -> Option<T> {
+        if self.0.is_empty() {
+            return None;
+        }
+        let total = self.length();
+        if total == T::zero() {
+            return Some(T::zero());
+        }
+        let (_, segment, fraction) = p.project_onto(self)?;
+        let mut lines = self.lines();
+        let before = lines.by_ref().take(segment).fold(T::zero(), |sum, line| sum + line.length());
+        let length = lines.next().map_or(T::zero(), |line| line.length());
+        Some((before + fraction * length) / total)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString};
+    use algorithm::line_interpolate_point::LineInterpolatePoint;
+    use super::*;
+
+    #[test]
+    fn line_locate_point_test() {
+        let ls: LineString<f64> = vec![(0., 0.), (10., 0.)].into();
+        assert_eq!(ls.line_locate_point(&Point::new(0., 0.)), Some(0.));
+        assert_eq!(ls.line_locate_point(&Point::new(2.5, 0.)), Some(0.25));
+        // points near the line snap onto it
+        assert_eq!(ls.line_locate_point(&Point::new(4., 0.1)), Some(0.4));
+        assert_eq!(ls.line_locate_point(&Point::new(7., -3.)), Some(0.7));
+        // and points beyond the ends onto them
+        assert_eq!(ls.line_locate_point(&Point::new(-5., 1.)), Some(0.));
+        assert_eq!(ls.line_locate_point(&Point::new(12., 0.)), Some(1.));
+    }
+    #[test]
+    fn line_locate_interpolate_test() {
+        // an L-shaped path, whose corner is nearest to points inside the bend
+        let ls: LineString<f64> = vec![(0., 0.), (4., 0.), (4., 4.)].into();
+        assert_eq!(ls.line_locate_point(&Point::new(4., 2.)), Some(0.75));
+        assert_eq!(ls.line_locate_point(&Point::new(5., -1.)), Some(0.5));
+        for &fraction in &[0., 0.2, 0.5, 0.9, 1.] {
+            let p = ls.line_interpolate_point(fraction).unwrap();
+            assert_relative_eq!(ls.line_locate_point(&p).unwrap(), fraction);
+        }
+        assert_eq!(LineString::<f64>(vec![]).line_locate_point(&Point::new(0., 0.)), None);
+        assert_eq!(LineString(vec![Point::new(1., 1.)]).line_locate_point(&Point::new(0., 0.)), Some(0.));
+    }
+}
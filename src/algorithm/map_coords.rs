@@ -124,10 +124,8 @@ impl<T: Float, NT: Float> MapCoords<T, NT> for GeometryCollection<T> {
     }
 }
 
-
+#[cfg(test)]
 mod test {
-    #[allow(unused_imports)]
-    
     use super::*;
 
     #[test]
@@ -247,5 +245,25 @@ mod test {
         assert_eq!(p2.y(), 2f32);
     }
 
+    #[test]
+    fn swap_axes() {
+        // reprojecting from (lat, lon) to (lon, lat) order
+        let p: Polygon<f64> = Polygon::new(vec![(0., 0.), (4., 0.), (4., 1.), (0., 0.)].into(),
+                                           vec![vec![(1., 0.1), (2., 0.2), (3., 0.2), (1., 0.1)].into()]);
+        let swapped = p.map_coords(&|&(x, y)| (y, x));
+        assert_eq!(swapped,
+                   Polygon::new(vec![(0., 0.), (0., 4.), (1., 4.), (0., 0.)].into(),
+                                vec![vec![(0.1, 1.), (0.2, 2.), (0.2, 3.), (0.1, 1.)].into()]));
+        assert_eq!(swapped.map_coords(&|&(x, y)| (y, x)), p);
+    }
+
+    #[test]
+    fn convert_polygon_type() {
+        let p: Polygon<f64> = Polygon::new(vec![(0., 0.), (1.5, 0.), (1.5, 2.25), (0., 0.)].into(), vec![]);
+        let converted: Polygon<f32> = p.map_coords(&|&(x, y)| (x as f32, y as f32));
+        assert_eq!(converted.exterior.0[2], Point::new(1.5f32, 2.25f32));
+        assert_eq!(converted.exterior.0.len(), 4);
+        assert!(converted.interiors.is_empty());
+    }
 }
 
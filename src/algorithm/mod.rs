@@ -4,9 +4,9 @@ pub mod centroid;
 pub mod contains;
 /// Checks if the geometry A intersects the geometry B.
 pub mod intersects;
-/// Returns the area of the surface of a geometry.
+/// Returns the area of the surface of a geometry, or the signed area of a ring.
 pub mod area;
-/// Returns the length of a line.
+/// Returns the length of a line, or the perimeter of a `Polygon`.
 pub mod length;
 /// Returns the Euclidean distance between two geometries.
 pub mod distance;
@@ -14,6 +14,8 @@ pub mod distance;
 pub mod haversine_destination;
 /// Returns the Haversine distance between two geometries.
 pub mod haversine_distance;
+/// Samples a geometry at equal Haversine distances.
+pub mod haversine_densify;
 /// Returns the Bbox of a geometry.
 pub mod boundingbox;
 /// Simplifies geometries using the Ramer-Douglas-Peucker algorithm.
@@ -28,7 +30,93 @@ pub mod orient;
 pub mod extremes;
 /// Rotates a geometry around either its centroid or a point by an angle, given in degrees.
 pub mod rotate;
-/// Translates a geometry along the given offsets.
+/// Translates a geometry along the given offsets, or onto the origin.
 pub mod translate;
 /// Apply a function to all coordinates
 pub mod map_coords;
+/// Converts the coordinates of a geometry from `f64` to `f32`.
+pub mod to_f32;
+/// Selects the members of a `MultiPolygon` by their area.
+pub mod largest;
+/// Produces a deterministic representation of a geometry, for comparison.
+pub mod canonicalize;
+/// Removes thin or tiny sliver Polygons from a `MultiPolygon`, and tiny holes from a `Polygon`.
+pub mod remove_slivers;
+/// Two-dimensional affine transforms, applying them to geometries, and fitting them to control points.
+pub mod affine;
+/// Checks that a set of triangles partitions a `Polygon`.
+pub mod triangles_cover;
+/// Samples a `Polygon` at the centres of a regular grid of cells.
+pub mod sample_grid;
+/// Checks whether a `Polygon` or `MultiPolygon` is well-formed.
+pub mod validity;
+/// Boolean operations on Polygons, such as their union.
+pub mod boolean;
+/// Finds the closest pair of Points in a `MultiPoint`.
+pub mod closest_pair;
+/// Snaps the coordinates of a geometry to a regular grid.
+pub mod snap_to_grid;
+/// Nodes a network of `LineString`s, splitting them where they cross.
+pub mod node;
+/// Projects a `Point` onto the nearest segment of a `LineString`.
+pub mod project;
+/// Approximates a circle by a regular `Polygon`.
+pub mod circle;
+/// Finds the largest axis-aligned rectangle inside a `Polygon`.
+pub mod inscribed_rect;
+/// Triangulates a `Polygon` by ear clipping.
+pub mod triangulate;
+/// Clips a geometry to a rectangle or a `Polygon`.
+pub mod clip;
+/// Snaps the vertices of a geometry onto nearby target `Point`s.
+pub mod snap_vertices;
+/// Fills in the small notches of a `Polygon`, making it more convex.
+pub mod convex_trim;
+/// Interpolates between two `Polygon`s with matching vertices.
+pub mod lerp;
+/// Returns a geodesic circle around a `Point`.
+pub mod haversine_buffer;
+/// Splits a `LineString` into pieces which are monotone in x.
+pub mod monotone;
+/// Removes redundant collinear vertices from a geometry.
+pub mod remove_collinear;
+/// Caches the bounding boxes of a `MultiPolygon`, to speed up containment queries.
+pub mod indexed;
+/// Counts the ray crossings of a `Polygon`, and tests containment under the nonzero fill rule.
+pub mod fill_rule;
+/// Clusters the `Point`s of a `MultiPoint` by density, using DBSCAN.
+pub mod dbscan;
+/// Rounds the corners of a `Polygon` with circular arcs.
+pub mod round_corners;
+/// Encodes geometries in a compact binary layout, and decodes them again.
+pub mod bytes;
+/// Calculates the spherical excess of a `Polygon`, its area on a unit sphere.
+pub mod spherical_excess;
+/// Divides the plane into the Voronoi cells of a `MultiPoint`.
+pub mod voronoi;
+/// Computes the Delaunay triangulation of a `MultiPoint`.
+pub mod delaunay;
+/// Finds a `Point` guaranteed to lie inside a `Polygon` or `MultiPolygon`.
+pub mod representative_point;
+/// Caches the results of a coordinate transform, for geometries sharing vertices.
+pub mod cached_transform;
+/// Finds the nearest point of a geometry to a `Point`.
+pub mod closest_point;
+/// Returns the initial bearing from one `Point` to another.
+pub mod bearing;
+/// Counts the segments of a geometry crossing a `Line`.
+pub mod count_crossings;
+/// Calculates the centroid of a `Polygon` of lon/lat vertices on the sphere.
+pub mod geographic_centroid;
+/// Scales a geometry about the origin or a `Point`.
+pub mod scale;
+/// Adds vertices along the segments of a geometry, so none is longer than a given distance.
+pub mod densify;
+/// Returns the boundary of a `Polygon` or `MultiPolygon` as a `MultiLineString` of its rings.
+pub mod boundary;
+/// Finds the `Point` a given fraction of the way along a `LineString`.
+pub mod line_interpolate_point;
+/// Finds how far along a `LineString` the point nearest to a `Point` lies.
+pub mod line_locate_point;
+// Geometric predicates shared by the algorithms above.
+pub(crate) mod kernels;
@@ -0,0 +1,79 @@
+use num_traits::Float;
+use types::{LineString, MultiLineString};
+
+/// Splits a geometry into pieces which are monotone in x.
+pub trait SplitIntoMonotone<T> {
+    /// Splits a LineString into x-monotone pieces, along which x never decreases or never
+    /// increases
+    ///
+    /// The LineString is split at each vertex where it reverses direction in x, which is shared
+    /// by the pieces either side of it. Vertical segments continue whichever piece they belong
+    /// to. An empty LineString gives no pieces, and one which is already monotone is returned
+    /// whole.
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::monotone::SplitIntoMonotone;
+    ///
+    /// let ls: LineString<f64> = vec![(0., 0.), (2., 1.), (1., 2.)].into();
+    /// let pieces = ls.split_into_monotone();
+    ///
+    /// assert_eq!(pieces.0, vec![vec![(0., 0.), (2., 1.)].into(), vec![(2., 1.), (1., 2.)].into()]);
+    /// ```
+    fn split_into_monotone(&self) -> MultiLineString<T> where T: Float;
+}
+
+impl<T> SplitIntoMonotone<T> for LineString<T>
+    where T: Float
+{
+    fn split_into_monotone(&self) -> MultiLineString<T> {
+        let mut pieces = vec![];
+        let mut points = self.0.iter();
+        let mut current = match points.next() {
+            Some(&first) => vec![first],
+            None => return MultiLineString(pieces),
+        };
+        // the sign of the change in x along the current piece, once it has one
+        let mut direction = T::zero();
+        for &p in points {
+            let last = current[current.len() - 1];
+            let dx = (p.x() - last.x()).signum();
+            if p.x() != last.x() {
+                if direction != T::zero() && dx != direction {
+                    pieces.push(LineString(current));
+                    current = vec![last];
+                }
+                direction = dx;
+            }
+            current.push(p);
+        }
+        pieces.push(LineString(current));
+        MultiLineString(pieces)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString};
+    use super::*;
+
+    #[test]
+    fn split_into_monotone_test() {
+        // a zig-zag, reversing its direction in x twice, including along a vertical segment
+        let ls: LineString<f64> = vec![(0., 0.), (1., 1.), (3., 0.), (3., 1.), (1., 2.), (0., 3.), (2., 4.), (4., 4.)]
+            .into();
+        let pieces = ls.split_into_monotone();
+        let correct: Vec<LineString<f64>> = vec![vec![(0., 0.), (1., 1.), (3., 0.), (3., 1.)].into(),
+                                                 vec![(3., 1.), (1., 2.), (0., 3.)].into(),
+                                                 vec![(0., 3.), (2., 4.), (4., 4.)].into()];
+        assert_eq!(pieces.0, correct);
+    }
+    #[test]
+    fn split_into_monotone_degenerate_test() {
+        let monotone: LineString<f64> = vec![(0., 0.), (0., 1.), (1., 5.), (1., 2.)].into();
+        assert_eq!(monotone.split_into_monotone().0, vec![monotone.clone()]);
+        let single = LineString(vec![Point::new(1., 1.)]);
+        assert_eq!(single.split_into_monotone().0, vec![single.clone()]);
+        assert!(LineString::<f64>(vec![]).split_into_monotone().0.is_empty());
+    }
+}
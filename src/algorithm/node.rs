@@ -0,0 +1,167 @@
+use std::cmp::Ordering;
+use num_traits::Float;
+use types::{Point, Line, LineString, MultiLineString};
+use algorithm::distance::Distance;
+use algorithm::kernels::{cross, point_along, straddle};
+
+// the position of the projection of p along a line, from 0 at its start to 1 at its end
+fn param<T>(line: &Line<T>, p: &Point<T>) -> T
+    where T: Float
+{
+    let (dx, dy) = (line.end.x() - line.start.x(), line.end.y() - line.start.y());
+    ((p.x() - line.start.x()) * dx + (p.y() - line.start.y()) * dy) / (dx * dx + dy * dy)
+}
+
+// Splits a LineString at its nodes, each given as a segment index and a position along that
+// segment. A node at position 0 lies on the vertex at the start of the segment.
+fn split<T>(ls: &[Point<T>], mut nodes: Vec<(usize, T, Point<T>)>) -> Vec<LineString<T>>
+    where T: Float
+{
+    nodes.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal)));
+    let mut nodes = nodes.into_iter().peekable();
+    let mut pieces = vec![];
+    let mut current = vec![ls[0]];
+    for (idx, &vertex) in ls.iter().enumerate().skip(1) {
+        while let Some(&(node, _, p)) = nodes.peek() {
+            if node >= idx {
+                break;
+            }
+            nodes.next();
+            if *current.last().unwrap() != p {
+                current.push(p);
+            }
+            if current.len() > 1 {
+                pieces.push(LineString(current));
+                current = vec![p];
+            }
+        }
+        if *current.last().unwrap() != vertex {
+            current.push(vertex);
+        }
+    }
+    if current.len() > 1 {
+        pieces.push(LineString(current));
+    }
+    pieces
+}
+
+/// Builds a network from a set of LineStrings.
+pub trait Node<T> {
+    /// Returns a properly noded copy of a MultiLineString, suitable for building a graph
+    ///
+    /// First, the endpoints of the LineStrings which lie within `tolerance` of each other are
+    /// joined, by moving them onto the first of them. Then the LineStrings are split wherever
+    /// they cross each other or themselves, and wherever another LineString ends on one of their
+    /// segments or interior vertices, again to within `tolerance`. In the result, LineStrings
+    /// only meet at their endpoints.
+    ///
+    /// ```
+    /// use geo::{LineString, MultiLineString};
+    /// use geo::algorithm::node::Node;
+    ///
+    /// // a T junction, whose stem stops just short of the bar
+    /// let bar: LineString<f64> = vec![(0., 0.), (2., 0.)].into();
+    /// let stem: LineString<f64> = vec![(1., 0.001), (1., 1.)].into();
+    /// let noded = MultiLineString(vec![bar, stem]).node(0.01);
+    ///
+    /// assert_eq!(noded.0.len(), 3);
+    /// assert_eq!(noded.0[0], vec![(0., 0.), (1., 0.001)].into());
+    /// ```
+    fn node(&self, tolerance: T) -> Self where T: Float;
+}
+
+impl<T> Node<T> for MultiLineString<T>
+    where T: Float
+{
+    fn node(&self, tolerance: T) -> MultiLineString<T> {
+        let mut lines: Vec<Vec<Point<T>>> = self.0.iter().filter(|ls| !ls.0.is_empty()).map(|ls| ls.0.clone()).collect();
+        // join nearby endpoints
+        let mut joined: Vec<Point<T>> = vec![];
+        for ls in &mut lines {
+            let last = ls.len() - 1;
+            for &idx in &[0, last] {
+                match joined.iter().find(|p| p.distance(&ls[idx]) <= tolerance) {
+                    Some(&p) => ls[idx] = p,
+                    None => joined.push(ls[idx]),
+                }
+            }
+        }
+        let segments: Vec<Vec<Line<T>>> = lines.iter().map(|ls| LineString(ls.clone()).lines().collect()).collect();
+        let mut nodes: Vec<Vec<(usize, T, Point<T>)>> = vec![vec![]; lines.len()];
+        // crossings, which lie strictly inside both segments
+        for (i, first) in segments.iter().enumerate() {
+            for (a, s) in first.iter().enumerate() {
+                for (j, second) in segments.iter().enumerate().skip(i) {
+                    let start = if i == j { a + 1 } else { 0 };
+                    for (b, t) in second.iter().enumerate().skip(start) {
+                        let (o1, o2) = (cross(&s.start, &s.end, &t.start), cross(&s.start, &s.end, &t.end));
+                        let (o3, o4) = (cross(&t.start, &t.end, &s.start), cross(&t.start, &t.end, &s.end));
+                        if straddle(o1, o2) && straddle(o3, o4) {
+                            let fraction = o3 / (o3 - o4);
+                            let p = point_along(s, fraction);
+                            nodes[i].push((a, fraction, p));
+                            nodes[j].push((b, param(t, &p), p));
+                        }
+                    }
+                }
+            }
+        }
+        // endpoints ending on the segments or interior vertices of a LineString
+        for p in lines.iter().flat_map(|ls| vec![ls[0], ls[ls.len() - 1]]) {
+            for (j, line_segments) in segments.iter().enumerate() {
+                for (b, t) in line_segments.iter().enumerate() {
+                    if b > 0 && t.start == p {
+                        nodes[j].push((b, T::zero(), p));
+                    } else if t.start != p && t.end != p && t.distance(&p) <= tolerance {
+                        let fraction = param(t, &p);
+                        if fraction > T::zero() && fraction < T::one() {
+                            nodes[j].push((b, fraction, p));
+                        }
+                    }
+                }
+            }
+        }
+        MultiLineString(lines.iter().zip(nodes).flat_map(|(ls, nodes)| split(ls, nodes)).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{LineString, MultiLineString};
+    use super::*;
+
+    #[test]
+    fn node_crossing_test() {
+        let a: LineString<f64> = vec![(0., 0.), (2., 2.)].into();
+        let b: LineString<f64> = vec![(0., 2.), (2., 0.)].into();
+        let noded = MultiLineString(vec![a, b]).node(0.);
+        let correct: Vec<LineString<f64>> = vec![vec![(0., 0.), (1., 1.)].into(),
+                                                 vec![(1., 1.), (2., 2.)].into(),
+                                                 vec![(0., 2.), (1., 1.)].into(),
+                                                 vec![(1., 1.), (2., 0.)].into()];
+        assert_eq!(noded.0, correct);
+    }
+    #[test]
+    fn node_snap_endpoints_test() {
+        // two paths which nearly meet, and a third ending on an interior vertex of the first
+        let a: LineString<f64> = vec![(0., 0.), (1., 0.), (2., 0.)].into();
+        let b: LineString<f64> = vec![(2.005, 0.), (3., 1.)].into();
+        let c: LineString<f64> = vec![(1., -1.), (1., 0.)].into();
+        let noded = MultiLineString(vec![a, b, c]).node(0.01);
+        let correct: Vec<LineString<f64>> = vec![vec![(0., 0.), (1., 0.)].into(),
+                                                 vec![(1., 0.), (2., 0.)].into(),
+                                                 vec![(2., 0.), (3., 1.)].into(),
+                                                 vec![(1., -1.), (1., 0.)].into()];
+        assert_eq!(noded.0, correct);
+        assert_eq!(noded.node(0.01), noded);
+    }
+    #[test]
+    fn node_self_crossing_test() {
+        // a loop crossing itself once
+        let ls: LineString<f64> = vec![(0., 0.), (2., 2.), (2., 0.), (0., 2.)].into();
+        let noded = MultiLineString(vec![ls]).node(0.);
+        assert_eq!(noded.0.len(), 3);
+        assert_eq!(noded.0[1], vec![(1., 1.), (2., 2.), (2., 0.), (1., 1.)].into());
+        assert!(MultiLineString::<f64>(vec![]).node(0.).0.is_empty());
+    }
+}
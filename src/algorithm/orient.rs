@@ -1,6 +1,8 @@
 use num_traits::Float;
 use types::{LineString, Polygon, MultiPolygon};
+use algorithm::area::Winding;
 
+/// Orients the rings of a geometry according to convention.
 pub trait Orient<T> {
     /// Orients a Polygon's exterior and interior rings according to convention
     ///
@@ -50,6 +52,27 @@ impl<T> Orient<T> for MultiPolygon<T>
     }
 }
 
+impl<T> Polygon<T>
+    where T: Float
+{
+    /// Creates a new polygon, with its exterior ring oriented counter-clockwise, and its
+    /// interior rings clockwise.
+    ///
+    /// Unlike `new`, which keeps the rings as they are given, this reverses any ring not
+    /// oriented by convention, as `orient` does, so the signs of ring areas can be relied on.
+    ///
+    /// ```
+    /// use geo::Polygon;
+    ///
+    /// // a clockwise exterior
+    /// let p = Polygon::new_oriented(vec![(0., 0.), (0., 1.), (1., 1.), (1., 0.), (0., 0.)].into(), vec![]);
+    /// assert_eq!(p.exterior, vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into());
+    /// ```
+    pub fn new_oriented(exterior: LineString<T>, interiors: Vec<LineString<T>>) -> Polygon<T> {
+        Polygon::new(exterior, interiors).orient(Direction::Default)
+    }
+}
+
 /// By default, a properly-oriented Polygon has its outer ring oriented counter-clockwise,
 /// and its inner ring(s) oriented clockwise. Selecting `Reversed` will result in a Polygon
 /// with a clockwise-oriented exterior ring, and counter-clockwise interior ring(s)
@@ -61,18 +84,32 @@ pub enum Direction {
     Reversed,
 }
 
-// the signed area of a linear ring
-fn signed_ring_area<T>(linestring: &LineString<T>) -> T
+/// Flips the orientation of every ring of a geometry.
+pub trait ReverseWinding<T> {
+    /// Reverses the order of the vertices of each of a Polygon's rings
+    ///
+    /// This flips the orientation of every ring at once, whatever it was, which is cheaper than
+    /// `orient` when the rings are already consistently oriented and just need the opposite
+    /// convention. Each ring keeps its starting vertex.
+    ///
+    /// ```
+    /// use geo::Polygon;
+    /// use geo::algorithm::orient::ReverseWinding;
+    ///
+    /// let poly = Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 0.)].into(), vec![]);
+    ///
+    /// assert_eq!(poly.reverse_winding().exterior, vec![(0., 0.), (1., 1.), (1., 0.), (0., 0.)].into());
+    /// ```
+    fn reverse_winding(&self) -> Polygon<T> where T: Float;
+}
+
+impl<T> ReverseWinding<T> for Polygon<T>
     where T: Float
 {
-    if linestring.0.is_empty() || linestring.0.len() == 1 {
-        return T::zero();
-    }
-    let mut tmp = T::zero();
-    for line in linestring.lines() {
-        tmp = tmp + (line.start.x() * line.end.y() - line.end.x() * line.start.y());
+    fn reverse_winding(&self) -> Polygon<T> {
+        let reverse = |ring: &LineString<T>| LineString(ring.0.iter().rev().cloned().collect());
+        Polygon::new(reverse(&self.exterior), self.interiors.iter().map(reverse).collect())
     }
-    tmp / (T::one() + T::one())
 }
 
 // orient a Polygon according to convention
@@ -88,13 +125,13 @@ fn orient<T>(poly: &Polygon<T>, direction: Direction) -> Polygon<T>
     let mut rings = vec![];
     // process interiors first, so push and pop work
     for ring in &poly.interiors {
-        if signed_ring_area(&ring) / sign <= T::zero() {
+        if ring.signed_area() / sign <= T::zero() {
             rings.push(LineString(ring.0.iter().cloned().collect()));
         } else {
             rings.push(LineString(ring.0.iter().rev().cloned().collect()));
         }
     }
-    if signed_ring_area(&poly.exterior) / sign >= T::zero() {
+    if poly.exterior.signed_area() / sign >= T::zero() {
         rings.push(LineString(poly.exterior.0.iter().cloned().collect()));
     } else {
         rings.push(LineString(poly.exterior.0.iter().rev().cloned().collect()));
@@ -104,7 +141,8 @@ fn orient<T>(poly: &Polygon<T>, direction: Direction) -> Polygon<T>
 
 #[cfg(test)]
 mod test {
-    use types::{Polygon, LineString, Point};
+    use types::{Polygon, MultiPolygon, LineString, Point};
+    use algorithm::area::Area;
     use super::*;
     #[test]
     fn test_polygon_orientation() {
@@ -138,4 +176,44 @@ mod test {
         assert_eq!(oriented.exterior.0, oriented_ext_ls.0);
         assert_eq!(oriented.interiors[0].0, oriented_int_ls.0);
     }
+    #[test]
+    fn reverse_winding_test() {
+        let exterior: LineString<f64> = vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into();
+        let hole: LineString<f64> = vec![(1., 1.), (1., 2.), (2., 2.), (2., 1.), (1., 1.)].into();
+        let poly = Polygon::new(exterior, vec![hole]);
+        let reversed = poly.reverse_winding();
+        let signed_exterior = |poly: &Polygon<f64>| poly.exterior.signed_area();
+        assert_eq!(signed_exterior(&reversed), -signed_exterior(&poly));
+        assert_eq!(reversed.interiors[0].signed_area(), -poly.interiors[0].signed_area());
+        assert_eq!(reversed.area(), poly.area());
+        assert_eq!(reversed.reverse_winding(), poly);
+    }
+    #[test]
+    fn orient_reversed_test() {
+        // a counter-clockwise exterior, and two holes wound either way
+        let exterior: LineString<f64> = vec![(0., 0.), (6., 0.), (6., 4.), (0., 4.), (0., 0.)].into();
+        let cw_hole: LineString<f64> = vec![(1., 1.), (1., 2.), (2., 2.), (2., 1.), (1., 1.)].into();
+        let ccw_hole: LineString<f64> = vec![(4., 1.), (5., 1.), (5., 2.), (4., 2.), (4., 1.)].into();
+        let poly = Polygon::new(exterior, vec![cw_hole, ccw_hole]);
+        let reversed = poly.orient(Direction::Reversed);
+        assert!(reversed.exterior.is_cw());
+        assert!(reversed.interiors.iter().all(|ring| ring.is_ccw()));
+        let default = reversed.orient(Direction::Default);
+        assert!(default.exterior.is_ccw());
+        assert!(default.interiors.iter().all(|ring| ring.is_cw()));
+        // every member of a MultiPolygon is oriented
+        let multi = MultiPolygon(vec![poly.clone(), reversed.clone()]).orient(Direction::Default);
+        assert_eq!(multi.0, vec![default.clone(), default]);
+    }
+    #[test]
+    fn polygon_new_oriented_test() {
+        let cw: LineString<f64> = vec![(0., 0.), (0., 4.), (4., 4.), (4., 0.), (0., 0.)].into();
+        let ccw: LineString<f64> = vec![(1., 1.), (2., 1.), (2., 2.), (1., 2.), (1., 1.)].into();
+        let p = Polygon::new_oriented(cw.clone(), vec![ccw.clone()]);
+        assert_eq!(p.exterior, vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into());
+        assert_eq!(p.interiors, vec![vec![(1., 1.), (1., 2.), (2., 2.), (2., 1.), (1., 1.)].into()]);
+        // already oriented rings are kept, and new keeps the rings as they are
+        assert_eq!(Polygon::new_oriented(p.exterior.clone(), p.interiors.clone()), p);
+        assert_eq!(Polygon::new(cw.clone(), vec![ccw.clone()]).exterior, cw);
+    }
 }
@@ -0,0 +1,70 @@
+use num_traits::Float;
+use types::{Point, LineString};
+use algorithm::distance::Distance;
+use algorithm::kernels::project_onto_segment;
+
+/// Projects a Point onto a geometry.
+pub trait ProjectOnto<T: Float> {
+    /// Returns the nearest point on a LineString, along with the index of the segment it lies
+    /// on, and its position along that segment, from `0` at the segment's start to `1` at its end
+    ///
+    /// If several segments are equally near, the first of them is used, so a Point nearest to a
+    /// vertex is projected onto the end of the segment leading to it. Returns `None` if the
+    /// LineString has no segments. This is the basic step of map matching a position onto a path.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::project::ProjectOnto;
+    ///
+    /// let ls: LineString<f64> = vec![(0., 0.), (4., 0.)].into();
+    /// let (nearest, segment, fraction) = Point::new(1., 3.).project_onto(&ls).unwrap();
+    ///
+    /// assert_eq!(nearest, Point::new(1., 0.));
+    /// assert_eq!(segment, 0);
+    /// assert_eq!(fraction, 0.25);
+    /// ```
+    fn project_onto(&self, ls: &LineString<T>) -> Option<(Point<T>, usize, T)>;
+}
+
+impl<T> ProjectOnto<T> for Point<T>
+    where T: Float
+{
+    fn project_onto(&self, ls: &LineString<T>) -> Option<(Point<T>, usize, T)> {
+        let mut nearest: Option<(Point<T>, usize, T, T)> = None;
+        for (idx, line) in ls.lines().enumerate() {
+            let (p, fraction) = project_onto_segment(self, &line);
+            let distance = self.distance(&p);
+            match nearest {
+                Some((_, _, _, closest)) if closest <= distance => {}
+                _ => nearest = Some((p, idx, fraction, distance)),
+            }
+        }
+        nearest.map(|(p, idx, fraction, _)| (p, idx, fraction))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString};
+    use super::*;
+
+    #[test]
+    fn project_onto_test() {
+        let ls: LineString<f64> = vec![(0., 0.), (2., 0.), (2., 4.), (5., 4.)].into();
+        let (nearest, segment, fraction) = Point::new(2.5, 3.).project_onto(&ls).unwrap();
+        assert_eq!(nearest, Point::new(2., 3.));
+        assert_eq!(segment, 1);
+        assert_relative_eq!(fraction, 0.75);
+        // beyond the end of the final segment
+        assert_eq!(Point::new(7., 5.).project_onto(&ls), Some((Point::new(5., 4.), 2, 1.)));
+        // equidistant from the first two segments, at the vertex between them
+        assert_eq!(Point::new(3., -1.).project_onto(&ls), Some((Point::new(2., 0.), 0, 1.)));
+    }
+    #[test]
+    fn project_onto_degenerate_test() {
+        assert!(Point::new(1., 1.).project_onto(&LineString(vec![])).is_none());
+        assert!(Point::new(1., 1.).project_onto(&LineString(vec![Point::new(0., 0.)])).is_none());
+        let repeated = LineString(vec![Point::new(0., 0.), Point::new(0., 0.)]);
+        assert_eq!(Point::new(1., 1.).project_onto(&repeated), Some((Point::new(0., 0.), 0, 0.)));
+    }
+}
@@ -0,0 +1,119 @@
+use num_traits::Float;
+use types::{Point, Line, LineString, Polygon};
+use algorithm::distance::Distance;
+
+// Drops the interior vertices of a path which lie within tolerance of the segment joining the
+// previous vertex kept and the next vertex.
+fn remove_from_path<T>(points: &[Point<T>], tolerance: T) -> Vec<Point<T>>
+    where T: Float
+{
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let mut kept = vec![points[0]];
+    for window in points[1..].windows(2) {
+        let segment = Line::new(kept[kept.len() - 1], window[1]);
+        if window[0].distance(&segment) > tolerance {
+            kept.push(window[0]);
+        }
+    }
+    kept.push(points[points.len() - 1]);
+    kept
+}
+
+// Removes collinear vertices from a closed ring, including at its start, unless fewer than three
+// would be left.
+fn remove_from_ring<T>(ring: &LineString<T>, tolerance: T) -> LineString<T>
+    where T: Float
+{
+    let points = &ring.0;
+    if points.first() != points.last() {
+        return LineString(remove_from_path(points, tolerance));
+    }
+    let mut kept = remove_from_path(points, tolerance);
+    kept.pop();
+    if kept.len() < 3 {
+        return ring.clone();
+    }
+    // the path always keeps its endpoints, so check the vertex at the start of the ring too
+    let n = kept.len();
+    if n > 3 && kept[0].distance(&Line::new(kept[n - 1], kept[1])) <= tolerance {
+        kept.remove(0);
+    }
+    let first = kept[0];
+    kept.push(first);
+    LineString(kept)
+}
+
+/// Removes redundant vertices from a geometry.
+pub trait RemoveCollinear<T> {
+    /// Returns a copy of a geometry without the vertices which lie within `tolerance` of the
+    /// straight line between their neighbours
+    ///
+    /// This reduces the number of vertices without visibly changing the shape: unlike
+    /// simplification, a vertex is only dropped if the segment joining the previous vertex kept
+    /// and the next one passes within `tolerance` of it. Repeated vertices are dropped too, and
+    /// the endpoints of a LineString are always kept. Polygon rings stay closed, and may lose
+    /// their starting vertex. A ring which would be left with fewer than three vertices is
+    /// returned unchanged.
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::remove_collinear::RemoveCollinear;
+    ///
+    /// let ls: LineString<f64> = vec![(0., 0.), (1., 0.), (2., 0.), (2., 2.)].into();
+    ///
+    /// assert_eq!(ls.remove_collinear(0.), vec![(0., 0.), (2., 0.), (2., 2.)].into());
+    /// ```
+    fn remove_collinear(&self, tolerance: T) -> Self where T: Float;
+}
+
+impl<T> RemoveCollinear<T> for LineString<T>
+    where T: Float
+{
+    fn remove_collinear(&self, tolerance: T) -> Self {
+        LineString(remove_from_path(&self.0, tolerance))
+    }
+}
+
+impl<T> RemoveCollinear<T> for Polygon<T>
+    where T: Float
+{
+    fn remove_collinear(&self, tolerance: T) -> Self {
+        Polygon::new(remove_from_ring(&self.exterior, tolerance),
+                     self.interiors.iter().map(|ring| remove_from_ring(ring, tolerance)).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{LineString, Polygon};
+    use super::*;
+
+    #[test]
+    fn remove_collinear_linestring_test() {
+        // a redundant vertex half way along the first segment, and one just off the second
+        let ls: LineString<f64> = vec![(0., 0.), (1., 0.), (2., 0.), (2., 1.), (2.001, 2.), (2., 3.), (0., 3.)].into();
+        let correct: LineString<f64> = vec![(0., 0.), (2., 0.), (2., 3.), (0., 3.)].into();
+        assert_eq!(ls.remove_collinear(0.01), correct);
+        assert_eq!(ls.remove_collinear(0.), vec![(0., 0.), (2., 0.), (2., 1.), (2.001, 2.), (2., 3.), (0., 3.)].into());
+        // a spike doubling back isn't collinear
+        let spike: LineString<f64> = vec![(0., 0.), (2., 0.), (1., 0.)].into();
+        assert_eq!(spike.remove_collinear(0.01), spike);
+    }
+    #[test]
+    fn remove_collinear_polygon_test() {
+        // the ring starts half way along its bottom edge
+        let exterior: LineString<f64> = vec![(1., 0.), (2., 0.), (2., 2.), (1., 2.), (0., 2.), (0., 0.), (1., 0.)].into();
+        let hole: LineString<f64> = vec![(0.5, 0.5), (0.5, 1.), (0.5, 1.5), (1.5, 1.5), (0.5, 0.5)].into();
+        let poly = Polygon::new(exterior, vec![hole]);
+        let cleaned = poly.remove_collinear(1e-10);
+        assert_eq!(cleaned.exterior, vec![(2., 0.), (2., 2.), (0., 2.), (0., 0.), (2., 0.)].into());
+        assert_eq!(cleaned.interiors[0], vec![(0.5, 0.5), (0.5, 1.5), (1.5, 1.5), (0.5, 0.5)].into());
+        let triangle = Polygon::new(vec![(0., 0.), (1., 0.), (0., 1.), (0., 0.)].into(), vec![]);
+        assert_eq!(triangle.remove_collinear(10.), triangle);
+        // a tolerance wider than the square would collapse it
+        let square = Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into(), vec![]);
+        assert_eq!(square.remove_collinear(10.), square);
+    }
+}
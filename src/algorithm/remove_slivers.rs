@@ -0,0 +1,143 @@
+use num_traits::Float;
+use types::{Polygon, MultiPolygon};
+use algorithm::area::{Area, Winding};
+use algorithm::convexhull::ConvexHull;
+
+// The minimum caliper width of a Polygon: the smallest distance between two parallel lines
+// enclosing it. The minimum is always attained with one of the lines flush against an edge
+// of the convex hull, so try each hull edge and measure the farthest hull vertex from it.
+fn minimum_width<T>(poly: &Polygon<T>) -> T
+    where T: Float
+{
+    let hull = poly.convex_hull();
+    let vertices = &hull.exterior.0;
+    hull.exterior
+        .lines()
+        .filter(|edge| edge.start != edge.end)
+        .map(|edge| {
+            let dx = edge.end.x() - edge.start.x();
+            let dy = edge.end.y() - edge.start.y();
+            let length = dx.hypot(dy);
+            vertices.iter()
+                .map(|p| ((p.x() - edge.start.x()) * dy - (p.y() - edge.start.y()) * dx).abs() / length)
+                .fold(T::zero(), |accum, val| accum.max(val))
+        })
+        .fold(None, |accum: Option<T>, val| Some(accum.map_or(val, |min| min.min(val))))
+        .unwrap_or_else(T::zero)
+}
+
+/// Removes thin or tiny members from a geometry collection.
+pub trait RemoveSlivers<T> {
+    /// Returns a new MultiPolygon without its sliver members
+    ///
+    /// A member is considered a sliver, and dropped, if its area is below `min_area`, or if its
+    /// minimum width (the smallest distance between two parallel lines enclosing it) is below
+    /// `min_width`. Thin slivers commonly appear along shared boundaries after boolean operations.
+    /// The remaining members keep their original order.
+    ///
+    /// ```
+    /// use geo::{Polygon, MultiPolygon};
+    /// use geo::algorithm::remove_slivers::RemoveSlivers;
+    ///
+    /// let square = Polygon::new(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)].into(), vec![]);
+    /// // long, but only 0.01 units wide
+    /// let sliver = Polygon::new(vec![(0., 3.), (400., 3.), (400., 3.01), (0., 3.01), (0., 3.)].into(), vec![]);
+    /// let mp = MultiPolygon(vec![square.clone(), sliver]);
+    ///
+    /// assert_eq!(mp.remove_slivers(0.5, 0.1), MultiPolygon(vec![square]));
+    /// ```
+    fn remove_slivers(&self, min_area: T, min_width: T) -> Self where T: Float;
+}
+
+impl<T> RemoveSlivers<T> for MultiPolygon<T>
+    where T: Float
+{
+    fn remove_slivers(&self, min_area: T, min_width: T) -> MultiPolygon<T> {
+        MultiPolygon(self.0
+                         .iter()
+                         .filter(|poly| poly.area() >= min_area && minimum_width(poly) >= min_width)
+                         .cloned()
+                         .collect())
+    }
+}
+
+/// Removes tiny holes from a geometry.
+pub trait RemoveSmallHoles<T> {
+    /// Returns a new Polygon without the interior rings whose area is below `min_hole_area`
+    ///
+    /// Pinhole holes like these are often left behind by rasterization. The exterior and the
+    /// remaining interior rings are kept as they were, whatever their orientation.
+    ///
+    /// ```
+    /// use geo::Polygon;
+    /// use geo::algorithm::remove_slivers::RemoveSmallHoles;
+    ///
+    /// let pinhole = vec![(1., 1.), (1.01, 1.), (1.01, 1.01), (1., 1.01), (1., 1.)].into();
+    /// let poly = Polygon::new(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)].into(), vec![pinhole]);
+    ///
+    /// assert!(poly.remove_small_holes(0.01).interiors.is_empty());
+    /// ```
+    fn remove_small_holes(&self, min_hole_area: T) -> Self where T: Float;
+}
+
+impl<T> RemoveSmallHoles<T> for Polygon<T>
+    where T: Float
+{
+    fn remove_small_holes(&self, min_hole_area: T) -> Polygon<T> {
+        Polygon::new(self.exterior.clone(),
+                     self.interiors
+                         .iter()
+                         .filter(|ring| ring.signed_area().abs() >= min_hole_area)
+                         .cloned()
+                         .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon, MultiPolygon};
+    use super::*;
+
+    #[test]
+    fn minimum_width_test() {
+        let rect = Polygon::new(vec![(0., 0.), (10., 0.), (10., 3.), (0., 3.), (0., 0.)].into(), vec![]);
+        assert_relative_eq!(minimum_width(&rect), 3.0);
+        // a diamond is narrowest across its edges, not its diagonals
+        let diamond = Polygon::new(vec![(1., 0.), (2., 1.), (1., 2.), (0., 1.), (1., 0.)].into(), vec![]);
+        assert_relative_eq!(minimum_width(&diamond), 2.0.sqrt());
+        let empty = Polygon::<f64>::new(LineString(vec![]), vec![]);
+        assert_eq!(minimum_width(&empty), 0.0);
+    }
+    #[test]
+    fn remove_sliver_keep_compact_test() {
+        // both members have an area of 1.0
+        let compact = Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into(), vec![]);
+        let sliver = Polygon::new(vec![(0., 2.), (100., 2.), (100., 2.01), (0., 2.01), (0., 2.)].into(), vec![]);
+        assert_relative_eq!(compact.area(), 1.0);
+        assert_relative_eq!(sliver.area(), 1.0, epsilon = 1e-9);
+        let mp = MultiPolygon(vec![sliver, compact.clone()]);
+        assert_eq!(mp.remove_slivers(0.5, 0.1), MultiPolygon(vec![compact]));
+    }
+    #[test]
+    fn remove_small_members_test() {
+        let big = Polygon::new(vec![(0., 0.), (5., 0.), (5., 5.), (0., 5.), (0., 0.)].into(), vec![]);
+        let tiny = Polygon::new(vec![(10., 10.), (10.1, 10.), (10.1, 10.1), (10., 10.1), (10., 10.)].into(), vec![]);
+        let degenerate = Polygon::new(LineString(vec![Point::new(20., 20.)]), vec![]);
+        let mp = MultiPolygon(vec![tiny, big.clone(), degenerate]);
+        assert_eq!(mp.remove_slivers(0.5, 0.0), MultiPolygon(vec![big]));
+    }
+    #[test]
+    fn remove_small_holes_test() {
+        let exterior: LineString<f64> = vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)].into();
+        let large: LineString<f64> = vec![(2., 2.), (2., 5.), (5., 5.), (5., 2.), (2., 2.)].into();
+        // a counter-clockwise pinhole, with an area of 0.01
+        let small: LineString<f64> = vec![(7., 7.), (7.1, 7.), (7.1, 7.1), (7., 7.1), (7., 7.)].into();
+        let poly = Polygon::new(exterior, vec![small.clone(), large.clone()]);
+        let filled = poly.remove_small_holes(1.);
+        assert_eq!(filled.interiors, vec![large]);
+        assert_relative_eq!(filled.area() - poly.area(), 0.01, epsilon = 1e-10);
+        // a hole exactly at the threshold is kept
+        assert_eq!(poly.remove_small_holes(9.).interiors.len(), 1);
+        assert_eq!(poly.remove_small_holes(0.).interiors.len(), 2);
+    }
+}
@@ -0,0 +1,122 @@
+use std::cmp::Ordering;
+use num_traits::Float;
+use types::{Point, Polygon, MultiPolygon};
+use algorithm::largest::Largest;
+
+/// Finds a point guaranteed to lie inside a geometry.
+pub trait RepresentativePoint<T: Float> {
+    /// Returns a Point lying strictly inside a geometry, or `None` if it has no interior
+    ///
+    /// Unlike the centroid, which may fall outside a concave Polygon or inside one of its holes,
+    /// the Point is always contained, which makes it suitable for placing a label. A horizontal
+    /// line is drawn across the Polygon, between the two vertex heights nearest the middle of
+    /// its bounding box so that it passes through no vertex, and the middle of the widest
+    /// interval it spends inside the Polygon is returned. A MultiPolygon returns the Point of
+    /// its largest member. The Polygon should be valid: its rings mustn't cross.
+    ///
+    /// ```
+    /// use geo::{Point, Polygon};
+    /// use geo::algorithm::contains::Contains;
+    /// use geo::algorithm::representative_point::RepresentativePoint;
+    ///
+    /// // a U shape, whose centroid lies in the gap between its arms
+    /// let u = Polygon::new(vec![(0., 0.), (3., 0.), (3., 3.), (2., 3.), (2., 1.), (1., 1.), (1., 3.), (0., 3.),
+    ///                           (0., 0.)].into(),
+    ///                      vec![]);
+    /// let p = u.representative_point().unwrap();
+    ///
+    /// assert!(u.contains(&p));
+    /// ```
+    fn representative_point(&self) -> Option<Point<T>>;
+}
+
+impl<T> RepresentativePoint<T> for Polygon<T>
+    where T: Float
+{
+    fn representative_point(&self) -> Option<Point<T>> {
+        let rings = || ::std::iter::once(&self.exterior).chain(&self.interiors);
+        let (mut ymin, mut ymax) = (T::infinity(), T::neg_infinity());
+        for p in rings().flat_map(|ring| &ring.0) {
+            ymin = ymin.min(p.y());
+            ymax = ymax.max(p.y());
+        }
+        let two = T::one() + T::one();
+        let centre = (ymin + ymax) / two;
+        // the nearest vertex heights either side of the centre
+        let (mut below, mut above) = (ymin, ymax);
+        for p in rings().flat_map(|ring| &ring.0) {
+            if p.y() <= centre {
+                below = below.max(p.y());
+            } else {
+                above = above.min(p.y());
+            }
+        }
+        if self.exterior.0.is_empty() || above <= centre {
+            return None;
+        }
+        let y = (below + above) / two;
+        let mut xs: Vec<T> = rings()
+            .flat_map(|ring| ring.lines())
+            .filter(|line| (line.start.y() < y) != (line.end.y() < y))
+            .map(|line| {
+                line.start.x() + (y - line.start.y()) * (line.end.x() - line.start.x()) / (line.end.y() - line.start.y())
+            })
+            .collect();
+        xs.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        // the line is inside the Polygon between each odd-numbered crossing and the next
+        xs.chunks(2)
+            .filter(|pair| pair.len() == 2)
+            .max_by(|a, b| (a[1] - a[0]).partial_cmp(&(b[1] - b[0])).unwrap_or(Ordering::Equal))
+            .map(|pair| Point::new((pair[0] + pair[1]) / two, y))
+    }
+}
+
+impl<T> RepresentativePoint<T> for MultiPolygon<T>
+    where T: Float
+{
+    fn representative_point(&self) -> Option<Point<T>> {
+        self.largest().and_then(|polygon| polygon.representative_point())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{LineString, Polygon, MultiPolygon};
+    use algorithm::centroid::Centroid;
+    use algorithm::contains::Contains;
+    use super::*;
+
+    #[test]
+    fn representative_point_polygon_test() {
+        // a ring, whose centroid lies in its hole
+        let exterior: LineString<f64> = vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into();
+        let hole: LineString<f64> = vec![(1., 1.), (1., 3.), (3., 3.), (3., 1.), (1., 1.)].into();
+        let ring = Polygon::new(exterior, vec![hole]);
+        assert!(!ring.contains(&ring.centroid().unwrap()));
+        let p = ring.representative_point().unwrap();
+        assert!(ring.contains(&p));
+        // the scan line passes between the vertex heights either side of the middle
+        assert_eq!(p.y(), 2.);
+        // a C shape, with its vertices at the middle height
+        let c = Polygon::new(vec![(0., 0.), (3., 0.), (3., 1.), (1., 1.), (1., 2.), (3., 2.), (3., 3.), (0., 3.),
+                                  (0., 0.)]
+                                 .into(),
+                             vec![]);
+        assert!(c.contains(&c.representative_point().unwrap()));
+    }
+    #[test]
+    fn representative_point_multipolygon_test() {
+        let small = Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into(), vec![]);
+        let big = Polygon::new(vec![(5., 5.), (9., 5.), (7., 9.), (5., 5.)].into(), vec![]);
+        let p = MultiPolygon(vec![small.clone(), big.clone()]).representative_point().unwrap();
+        assert!(big.contains(&p));
+        assert!(!small.contains(&p));
+    }
+    #[test]
+    fn representative_point_degenerate_test() {
+        assert!(Polygon::<f64>::new(LineString(vec![]), vec![]).representative_point().is_none());
+        let flat = Polygon::new(vec![(0., 0.), (1., 0.), (2., 0.), (0., 0.)].into(), vec![]);
+        assert!(flat.representative_point().is_none());
+        assert!(MultiPolygon::<f64>(vec![]).representative_point().is_none());
+    }
+}
@@ -129,8 +129,13 @@ where
     T: Float,
 {
     /// Rotate the LineString about its centroid by the given number of degrees
+    ///
+    /// An empty LineString has no centroid, and is returned unchanged
     fn rotate(&self, angle: T) -> Self {
-        LineString(rotation_matrix(angle, &self.centroid().unwrap(), &self.0))
+        match self.centroid() {
+            Some(centroid) => LineString(rotation_matrix(angle, &centroid, &self.0)),
+            None => self.clone(),
+        }
     }
 }
 
@@ -139,11 +144,17 @@ where
     T: Float + FromPrimitive,
 {
     /// Rotate the Polygon about its centroid by the given number of degrees
+    ///
+    /// A Polygon without a centroid is returned unchanged
     fn rotate(&self, angle: T) -> Self {
         // if a polygon has holes, use the centroid of its outer shell as the rotation origin
         let centroid = match self.interiors.is_empty() {
-            false => self.exterior.centroid().unwrap(),
-            true => self.centroid().unwrap(),
+            false => self.exterior.centroid(),
+            true => self.centroid(),
+        };
+        let centroid = match centroid {
+            Some(centroid) => centroid,
+            None => return self.clone(),
         };
         Polygon::new(
             LineString(rotation_matrix(angle, &centroid, &self.exterior.0)),
@@ -161,9 +172,14 @@ impl<T> Rotate<T> for MultiPolygon<T>
 where
     T: Float + FromPrimitive,
 {
-    /// Rotate the contained Polygons about their centroids by the given number of degrees
+    /// Rotate the MultiPolygon about its centroid by the given number of degrees
+    ///
+    /// An empty MultiPolygon has no centroid, and is returned unchanged
     fn rotate(&self, angle: T) -> Self {
-        MultiPolygon(self.0.iter().map(|poly| poly.rotate(angle)).collect())
+        match self.centroid() {
+            Some(centroid) => self.rotate_around_point(angle, &centroid),
+            None => self.clone(),
+        }
     }
 }
 
@@ -171,9 +187,14 @@ impl<T> Rotate<T> for MultiLineString<T>
 where
     T: Float + FromPrimitive,
 {
-    /// Rotate the contained LineStrings about their centroids by the given number of degrees
+    /// Rotate the MultiLineString about its centroid by the given number of degrees
+    ///
+    /// An empty MultiLineString has no centroid, and is returned unchanged
     fn rotate(&self, angle: T) -> Self {
-        MultiLineString(self.0.iter().map(|ls| ls.rotate(angle)).collect())
+        match self.centroid() {
+            Some(centroid) => self.rotate_around_point(angle, &centroid),
+            None => self.clone(),
+        }
     }
 }
 
@@ -181,17 +202,45 @@ impl<T> Rotate<T> for MultiPoint<T>
 where
     T: Float + FromPrimitive,
 {
-    /// Rotate the contained Points about their centroids by the given number of degrees
+    /// Rotate the MultiPoint about its centroid by the given number of degrees
+    ///
+    /// An empty MultiPoint has no centroid, and is returned unchanged
     fn rotate(&self, angle: T) -> Self {
-        MultiPoint(self.0.iter().map(|p| p.rotate(angle)).collect())
+        match self.centroid() {
+            Some(centroid) => MultiPoint(rotation_matrix(angle, &centroid, &self.0)),
+            None => self.clone(),
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use types::{Point, LineString, Polygon};
+    use types::{Point, LineString, Polygon, MultiPoint, MultiLineString, MultiPolygon};
     use super::*;
     #[test]
+    fn test_rotate_unit_square() {
+        let square = Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into(), vec![]);
+        let rotated = square.rotate(90.);
+        // each corner moves onto the next one counter-clockwise, about the centre
+        let correct = [(1., 0.), (1., 1.), (0., 1.), (0., 0.), (1., 0.)];
+        for (p, &(x, y)) in rotated.exterior.0.iter().zip(correct.iter()) {
+            assert_relative_eq!(p.x(), x, epsilon = 1e-12);
+            assert_relative_eq!(p.y(), y, epsilon = 1e-12);
+        }
+        let around_origin = square.rotate_around_point(90., &Point::new(0., 0.));
+        assert_relative_eq!(around_origin.exterior.0[2].x(), -1., epsilon = 1e-12);
+        assert_relative_eq!(around_origin.exterior.0[2].y(), 1., epsilon = 1e-12);
+    }
+    #[test]
+    fn test_rotate_empty() {
+        let empty = LineString::<f64>(vec![]);
+        assert_eq!(empty.rotate(45.), empty);
+        let poly = Polygon::new(LineString::<f64>(vec![]), vec![]);
+        assert_eq!(poly.rotate(45.), poly);
+        let holed = Polygon::new(LineString::<f64>(vec![]), vec![vec![(0., 0.), (1., 0.), (0., 1.), (0., 0.)].into()]);
+        assert_eq!(holed.rotate(45.), holed);
+    }
+    #[test]
     fn test_rotate_around_point() {
         let p = Point::new(1.0, 5.0);
         let rotated = p.rotate(30.0);
@@ -332,4 +381,23 @@ mod test {
         );
         assert_eq!(line0.rotate_around_point(90., &Point::new(0., 0.)), line1);
     }
+    #[test]
+    fn test_rotate_multi() {
+        // about the centroid of the whole, rather than each member's own
+        let points = MultiPoint(vec![Point::new(0., 0.), Point::new(2., 0.)]);
+        let rotated = points.rotate(90.);
+        assert_relative_eq!(rotated.0[0].x(), 1., epsilon = 1e-12);
+        assert_relative_eq!(rotated.0[0].y(), -1., epsilon = 1e-12);
+        assert_relative_eq!(rotated.0[1].x(), 1., epsilon = 1e-12);
+        assert_relative_eq!(rotated.0[1].y(), 1., epsilon = 1e-12);
+        let lines = MultiLineString(vec![vec![(0., 0.), (0., 1.)].into(), vec![(2., 0.), (2., 1.)].into()]);
+        let rotated = lines.rotate(180.);
+        assert_relative_eq!(rotated.0[0].0[0].x(), 2., epsilon = 1e-12);
+        assert_relative_eq!(rotated.0[0].0[0].y(), 1., epsilon = 1e-12);
+        let square = |x: f64| Polygon::new(vec![(x, 0.), (x + 1., 0.), (x + 1., 1.), (x, 1.), (x, 0.)].into(), vec![]);
+        let rotated = MultiPolygon(vec![square(0.), square(2.)]).rotate(180.);
+        assert_relative_eq!(rotated.0[0].exterior.0[0].x(), 3., epsilon = 1e-12);
+        assert_relative_eq!(rotated.0[0].exterior.0[0].y(), 1., epsilon = 1e-12);
+        assert_eq!(MultiPoint::<f64>(vec![]).rotate(90.), MultiPoint(vec![]));
+    }
 }
@@ -0,0 +1,147 @@
+use num_traits::Float;
+use types::{Point, LineString, Polygon};
+
+// Replaces each corner of a closed ring with an arc of `segments` segments, tangent to the edges
+// either side of it. The radius of an arc is reduced where its tangent points would lie beyond
+// the middle of either edge, so the arcs of neighbouring corners can't overlap.
+fn round_ring<T>(ring: &LineString<T>, radius: T, segments: usize) -> LineString<T>
+    where T: Float
+{
+    let mut points = ring.0.clone();
+    if points.len() < 4 || points.first() != points.last() {
+        return ring.clone();
+    }
+    points.pop();
+    let n = points.len();
+    let two = T::one() + T::one();
+    let pi = T::from(::std::f64::consts::PI).unwrap();
+    let mut rounded = vec![];
+    for idx in 0..n {
+        let (a, v, b) = (points[(idx + n - 1) % n], points[idx], points[(idx + 1) % n]);
+        let (la, lb) = ((a.x() - v.x()).hypot(a.y() - v.y()), (b.x() - v.x()).hypot(b.y() - v.y()));
+        if la == T::zero() || lb == T::zero() {
+            rounded.push(v);
+            continue;
+        }
+        let u1 = ((a.x() - v.x()) / la, (a.y() - v.y()) / la);
+        let u2 = ((b.x() - v.x()) / lb, (b.y() - v.y()) / lb);
+        // the angle between the edges at the corner
+        let angle = (u1.0 * u2.0 + u1.1 * u2.1).max(-T::one()).min(T::one()).acos();
+        let half_tan = (angle / two).tan();
+        if half_tan == T::zero() || angle >= pi - T::epsilon() {
+            // a straight or fully folded corner has no arc
+            rounded.push(v);
+            continue;
+        }
+        // the distance from the corner to each tangent point
+        let tangent = (radius / half_tan).min(la / two).min(lb / two);
+        let r = tangent * half_tan;
+        let bisector = (u1.0 + u2.0, u1.1 + u2.1);
+        let length = bisector.0.hypot(bisector.1);
+        let offset = r / (angle / two).sin();
+        let centre = (v.x() + bisector.0 / length * offset, v.y() + bisector.1 / length * offset);
+        let start = (v.y() + u1.1 * tangent - centre.1).atan2(v.x() + u1.0 * tangent - centre.0);
+        let end = (v.y() + u2.1 * tangent - centre.1).atan2(v.x() + u2.0 * tangent - centre.0);
+        // the shorter way round, which is the arc facing the corner
+        let mut sweep = end - start;
+        if sweep > pi {
+            sweep = sweep - two * pi;
+        } else if sweep < -pi {
+            sweep = sweep + two * pi;
+        }
+        let steps = segments.max(1);
+        for step in 0..steps + 1 {
+            let theta = start + sweep * T::from(step).unwrap() / T::from(steps).unwrap();
+            rounded.push(Point::new(centre.0 + r * theta.cos(), centre.1 + r * theta.sin()));
+        }
+    }
+    let first = rounded[0];
+    rounded.push(first);
+    LineString(rounded)
+}
+
+/// Rounds the corners of a geometry.
+pub trait RoundCorners<T: Float> {
+    /// Returns a copy of a Polygon with each vertex replaced by a circular arc of `radius`,
+    /// tangent to the edges either side of it
+    ///
+    /// Each arc is approximated by `segments_per_corner` segments. Where a corner is too sharp,
+    /// or its edges too short, for an arc of `radius` to fit within the first half of each
+    /// edge, its radius is reduced until it does. Convex corners are cut off, and reflex ones
+    /// filled in, on every ring. The rings keep their orientation, but start at the arc
+    /// replacing their first vertex. If `radius` isn't positive, the Polygon is returned
+    /// unchanged.
+    ///
+    /// ```
+    /// use geo::Polygon;
+    /// use geo::algorithm::area::Area;
+    /// use geo::algorithm::round_corners::RoundCorners;
+    ///
+    /// let square = Polygon::new(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into(), vec![]);
+    /// let rounded = square.round_corners(1., 4);
+    ///
+    /// // five points on each corner, and the closing point
+    /// assert_eq!(rounded.exterior.0.len(), 21);
+    /// assert!(rounded.area() < square.area());
+    /// ```
+    fn round_corners(&self, radius: T, segments_per_corner: usize) -> Polygon<T>;
+}
+
+impl<T> RoundCorners<T> for Polygon<T>
+    where T: Float
+{
+    fn round_corners(&self, radius: T, segments_per_corner: usize) -> Polygon<T> {
+        if radius.is_nan() || radius <= T::zero() {
+            return self.clone();
+        }
+        Polygon::new(round_ring(&self.exterior, radius, segments_per_corner),
+                     self.interiors.iter().map(|ring| round_ring(ring, radius, segments_per_corner)).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::f64::consts::PI;
+    use types::{Point, LineString, Polygon};
+    use algorithm::area::Area;
+    use algorithm::contains::Contains;
+    use algorithm::distance::Distance;
+    use super::*;
+
+    #[test]
+    fn round_corners_square_test() {
+        let square = Polygon::new(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)].into(), vec![]);
+        let rounded = square.round_corners(1., 8);
+        assert_eq!(rounded.exterior.0.len(), 4 * 9 + 1);
+        // every point of the first arc is on the circle of radius 1 around (1, 1)
+        let centre = Point::new(1., 1.);
+        for p in &rounded.exterior.0[..9] {
+            assert_relative_eq!(p.distance(&centre), 1., epsilon = 1e-10);
+        }
+        assert_relative_eq!(rounded.exterior.0[0].x(), 0., epsilon = 1e-10);
+        assert_relative_eq!(rounded.exterior.0[8].y(), 0., epsilon = 1e-10);
+        // each corner loses the square inside the arc, less the polygon approximating the quarter
+        // circle, which is slightly more than a true arc would lose
+        let lost = 4. * (1. - 4. * (PI / 16.).sin());
+        assert_relative_eq!(rounded.area(), 100. - lost, epsilon = 1e-10);
+        assert!(lost > 4. - PI);
+        assert_eq!(square.round_corners(0., 8), square);
+    }
+    #[test]
+    fn round_corners_clamped_test() {
+        // a thin triangle, whose short edge leaves little room for the arcs either end
+        let triangle = Polygon::new(vec![(0., 0.), (10., 0.), (10., 1.), (0., 0.)].into(), vec![]);
+        let rounded = triangle.round_corners(5., 4);
+        assert!(rounded.area() > 0. && rounded.area() < triangle.area());
+        for p in &rounded.exterior.0 {
+            assert!(p.distance(&triangle.exterior) < 1e-10 || triangle.contains(p));
+        }
+        // reflex corners are filled in
+        let notched = Polygon::new(vec![(0., 0.), (4., 0.), (4., 4.), (2., 2.), (0., 4.), (0., 0.)].into(), vec![]);
+        let filled = notched.round_corners(0.5, 4);
+        assert!(!notched.contains(&Point::new(2., 2.1)));
+        assert!(filled.contains(&Point::new(2., 2.1)));
+        let empty = Polygon::<f64>::new(LineString(vec![]), vec![]);
+        assert_eq!(empty.round_corners(1., 4), empty);
+    }
+}
@@ -0,0 +1,131 @@
+use num_traits::Float;
+use types::{Point, Polygon};
+use algorithm::boundingbox::BoundingBox;
+use algorithm::contains::Contains;
+
+/// An iterator over the centres of the grid cells inside a Polygon.
+///
+/// Created by [`SampleGrid::sample_grid`](trait.SampleGrid.html#tymethod.sample_grid).
+pub struct GridSamples<'a, T>
+    where T: 'a + Float
+{
+    polygon: &'a Polygon<T>,
+    xmin: T,
+    ymin: T,
+    cell_size: T,
+    cols: usize,
+    rows: usize,
+    col: usize,
+    row: usize,
+}
+
+impl<'a, T> Iterator for GridSamples<'a, T>
+    where T: Float
+{
+    type Item = Point<T>;
+
+    fn next(&mut self) -> Option<Point<T>> {
+        let half = self.cell_size / (T::one() + T::one());
+        while self.row < self.rows {
+            let x = self.xmin + T::from(self.col).unwrap() * self.cell_size + half;
+            let y = self.ymin + T::from(self.row).unwrap() * self.cell_size + half;
+            self.col += 1;
+            if self.col == self.cols {
+                self.col = 0;
+                self.row += 1;
+            }
+            let centre = Point::new(x, y);
+            if self.polygon.contains(&centre) {
+                return Some(centre);
+            }
+        }
+        None
+    }
+}
+
+/// Samples a geometry on a regular grid.
+pub trait SampleGrid<T: Float> {
+    /// Returns an iterator over the centres of the grid cells which lie inside a Polygon
+    ///
+    /// The grid starts at the bottom left corner of the Polygon's bounding box, and its square
+    /// cells are `cell_size` wide. Cells are visited row by row, from the bottom row to the top
+    /// and from left to right within each row, and only the centres lying strictly inside the
+    /// Polygon (and outside its holes) are yielded. This is a simple way of rasterizing a
+    /// Polygon. If `cell_size` isn't positive, nothing is yielded.
+    ///
+    /// ```
+    /// use geo::{Point, Polygon};
+    /// use geo::algorithm::sample_grid::SampleGrid;
+    ///
+    /// let square = Polygon::new(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)].into(), vec![]);
+    /// let centres: Vec<Point<f64>> = square.sample_grid(1.).collect();
+    ///
+    /// assert_eq!(centres, vec![Point::new(0.5, 0.5), Point::new(1.5, 0.5),
+    ///                          Point::new(0.5, 1.5), Point::new(1.5, 1.5)]);
+    /// ```
+    fn sample_grid(&self, cell_size: T) -> GridSamples<'_, T>;
+}
+
+impl<T> SampleGrid<T> for Polygon<T>
+    where T: Float
+{
+    fn sample_grid(&self, cell_size: T) -> GridSamples<'_, T> {
+        let (xmin, ymin, cols, rows) = match self.bbox() {
+            Some(bbox) if cell_size > T::zero() => {
+                let cols = ((bbox.xmax - bbox.xmin) / cell_size).ceil().to_usize().unwrap_or(0);
+                let rows = ((bbox.ymax - bbox.ymin) / cell_size).ceil().to_usize().unwrap_or(0);
+                // a grid without any columns has no cells in its rows either
+                (bbox.xmin, bbox.ymin, cols, if cols == 0 { 0 } else { rows })
+            }
+            _ => (T::zero(), T::zero(), 0, 0),
+        };
+        GridSamples {
+            polygon: self,
+            xmin,
+            ymin,
+            cell_size,
+            cols,
+            rows,
+            col: 0,
+            row: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon};
+    use super::*;
+
+    #[test]
+    fn sample_grid_square_test() {
+        let square = Polygon::new(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)].into(), vec![]);
+        let centres: Vec<Point<f64>> = square.sample_grid(1.).collect();
+        let correct = vec![Point::new(0.5, 0.5), Point::new(1.5, 0.5), Point::new(0.5, 1.5), Point::new(1.5, 1.5)];
+        assert_eq!(centres, correct);
+    }
+    #[test]
+    fn sample_grid_triangle_hole_test() {
+        // none of the cell centres lie on the slanted edge
+        let triangle = Polygon::new(vec![(0., 0.), (4., 0.), (0., 3.), (0., 0.)].into(), vec![]);
+        let centres: Vec<Point<f64>> = triangle.sample_grid(1.).collect();
+        let correct = vec![Point::new(0.5, 0.5), Point::new(1.5, 0.5), Point::new(2.5, 0.5),
+                           Point::new(0.5, 1.5), Point::new(1.5, 1.5), Point::new(0.5, 2.5)];
+        assert_eq!(centres, correct);
+        let exterior: LineString<f64> = vec![(0., 0.), (3., 0.), (3., 3.), (0., 3.), (0., 0.)].into();
+        let hole: LineString<f64> = vec![(1., 1.), (2., 1.), (2., 2.), (1., 2.), (1., 1.)].into();
+        let poly = Polygon::new(exterior, vec![hole]);
+        assert_eq!(poly.sample_grid(1.).count(), 8);
+        assert!(poly.sample_grid(1.).all(|p| p != Point::new(1.5, 1.5)));
+    }
+    #[test]
+    fn sample_grid_degenerate_test() {
+        let empty = Polygon::<f64>::new(LineString(vec![]), vec![]);
+        assert_eq!(empty.sample_grid(1.).count(), 0);
+        let square = Polygon::new(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)].into(), vec![]);
+        assert_eq!(square.sample_grid(0.).count(), 0);
+        assert_eq!(square.sample_grid(-1.).count(), 0);
+        // a partial column and row of cells overhangs the far edges, with their centres on them
+        assert_eq!(square.sample_grid(0.8).count(), 4);
+    }
+}
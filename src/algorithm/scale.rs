@@ -0,0 +1,98 @@
+use num_traits::Float;
+use types::Point;
+use algorithm::map_coords::MapCoords;
+
+/// Scales a geometry about a fixed point.
+pub trait Scale<T> {
+    /// Scales a Geometry about the origin, by the given factors along each axis
+    ///
+    /// A factor of 1 leaves that axis unchanged, and a negative factor mirrors the Geometry
+    /// across the other axis.
+    ///
+    /// ```
+    /// use geo::Polygon;
+    /// use geo::algorithm::scale::Scale;
+    ///
+    /// let square = Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into(), vec![]);
+    /// let scaled = square.scale(2., 3.);
+    ///
+    /// assert_eq!(scaled.exterior, vec![(0., 0.), (2., 0.), (2., 3.), (0., 3.), (0., 0.)].into());
+    /// ```
+    fn scale(&self, xfact: T, yfact: T) -> Self where T: Float;
+
+    /// Scales a Geometry about a Point, by the given factors along each axis
+    ///
+    /// Each coordinate's offset from `origin` is multiplied by the factors, so `origin` itself
+    /// stays where it is. An axis with a factor of 1 is left exactly unchanged.
+    ///
+    /// ```
+    /// use geo::{Point, Line};
+    /// use geo::algorithm::scale::Scale;
+    ///
+    /// let line = Line::new(Point::new(1., 1.), Point::new(3., 1.));
+    /// let scaled = line.scale_around_point(2., 2., Point::new(2., 1.));
+    ///
+    /// assert_eq!(scaled, Line::new(Point::new(0., 1.), Point::new(4., 1.)));
+    /// ```
+    fn scale_around_point(&self, xfact: T, yfact: T, origin: Point<T>) -> Self where T: Float;
+}
+
+impl<T, G> Scale<T> for G
+    where T: Float,
+        G: MapCoords<T, T, Output=G>
+{
+    fn scale(&self, xfact: T, yfact: T) -> Self {
+        self.map_coords(&|&(x, y)| (x * xfact, y * yfact))
+    }
+
+    fn scale_around_point(&self, xfact: T, yfact: T, origin: Point<T>) -> Self {
+        let (x0, y0) = (origin.x(), origin.y());
+        // an axis with a factor of 1 is left as it was, as the offset from origin needn't round-trip
+        let scaled = |v: T, v0: T, fact: T| if fact == T::one() { v } else { v0 + (v - v0) * fact };
+        self.map_coords(&|&(x, y)| (scaled(x, x0, xfact), scaled(y, y0, yfact)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon, MultiPoint};
+    use algorithm::centroid::Centroid;
+    use super::*;
+
+    fn unit_square() -> Polygon<f64> {
+        Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into(), vec![])
+    }
+
+    #[test]
+    fn scale_origin_test() {
+        let scaled = unit_square().scale(2., 2.);
+        assert_eq!(scaled.exterior, vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)].into());
+        assert_eq!(unit_square().scale(1., 1.), unit_square());
+        // a negative factor mirrors across the other axis
+        let mirrored = unit_square().scale(-1., 1.);
+        assert_eq!(mirrored.exterior, vec![(0., 0.), (-1., 0.), (-1., 1.), (0., 1.), (0., 0.)].into());
+        let points = MultiPoint(vec![Point::new(1., 2.), Point::new(-3., 4.)]);
+        assert_eq!(points.scale(0.5, -1.), MultiPoint(vec![Point::new(0.5, -2.), Point::new(-1.5, -4.)]));
+    }
+    #[test]
+    fn scale_around_centroid_test() {
+        let square = unit_square();
+        let centroid = square.centroid().unwrap();
+        let scaled = square.scale_around_point(2., 2., centroid);
+        assert_eq!(scaled.exterior, vec![(-0.5, -0.5), (1.5, -0.5), (1.5, 1.5), (-0.5, 1.5), (-0.5, -0.5)].into());
+        assert_eq!(scaled.centroid(), Some(centroid));
+        assert_eq!(square.scale_around_point(1., 1., centroid), square);
+        let empty = LineString::<f64>(vec![]);
+        assert_eq!(empty.scale_around_point(2., 2., centroid), empty);
+    }
+    #[test]
+    fn scale_around_point_identity_test() {
+        // these offsets don't round-trip exactly through subtraction and addition
+        let p = Point::new(0.3, 0.7);
+        let origin = Point::new(1.7, 3.3);
+        assert_eq!(p.scale_around_point(1., 1., origin), p);
+        let stretched = p.scale_around_point(2., 1., origin);
+        assert_eq!(stretched.y(), 0.7);
+        assert_relative_eq!(stretched.x(), -1.1);
+    }
+}
@@ -1,8 +1,10 @@
 use num_traits::Float;
 use types::{Point, LineString, Polygon, MultiLineString, MultiPolygon};
-use algorithm::distance::Distance;
+use algorithm::distance::{Distance, line_segment_distance};
 
-// perpendicular distance from a point to a line
+// perpendicular distance from a point to a line, which rdp has replaced with the distance to the
+// segment, as that counts vertices lying beyond either end of it as far away
+#[cfg(test)]
 fn point_line_distance<T>(point: &Point<T>, start: &Point<T>, end: &Point<T>) -> T
     where T: Float
 {
@@ -21,7 +23,7 @@ fn point_line_distance<T>(point: &Point<T>, start: &Point<T>, end: &Point<T>) ->
 fn rdp<T>(points: &[Point<T>], epsilon: &T) -> Vec<Point<T>>
     where T: Float
 {
-    if points.is_empty() {
+    if points.len() < 3 {
         return points.to_vec();
     }
     let mut dmax = T::zero();
@@ -29,9 +31,9 @@ fn rdp<T>(points: &[Point<T>], epsilon: &T) -> Vec<Point<T>>
     let mut distance: T;
 
     for (i, _) in points.iter().enumerate().take(points.len() - 1).skip(1) {
-        distance = point_line_distance(&points[i],
-                                       &points[0],
-                                       &*points.last().unwrap());
+        distance = line_segment_distance(&points[i],
+                                          &points[0],
+                                          points.last().unwrap());
         if distance > dmax {
             index = i;
             dmax = distance;
@@ -47,6 +49,28 @@ fn rdp<T>(points: &[Point<T>], epsilon: &T) -> Vec<Point<T>>
     }
 }
 
+// Ramer–Douglas-Peucker with a separate tolerance for each point: the point furthest beyond its
+// own tolerance is kept, if any lie beyond theirs
+fn rdp_adaptive<T>(points: &[Point<T>], epsilons: &[T]) -> Vec<Point<T>>
+    where T: Float
+{
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let (first, last) = (points[0], points[points.len() - 1]);
+    let (index, excess) = (1..points.len() - 1)
+        .map(|i| (i, line_segment_distance(&points[i], &first, &last) - epsilons[i]))
+        .fold((0, T::zero()), |(index, excess), (i, e)| if e > excess { (i, e) } else { (index, excess) });
+    if excess > T::zero() {
+        let mut intermediate = rdp_adaptive(&points[..index + 1], &epsilons[..index + 1]);
+        intermediate.pop();
+        intermediate.extend_from_slice(&rdp_adaptive(&points[index..], &epsilons[index..]));
+        intermediate
+    } else {
+        vec![first, last]
+    }
+}
+
 /// Simplifies a geometry.
 ///
 /// The [Ramer–Douglas–Peucker
@@ -58,6 +82,10 @@ fn rdp<T>(points: &[Point<T>], epsilon: &T) -> Vec<Point<T>>
 pub trait Simplify<T, Epsilon = T> {
     /// Returns the simplified representation of a geometry, using the [Ramer–Douglas–Peucker](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm) algorithm
     ///
+    /// Distances are measured to the segment joining the ends of each stretch being simplified,
+    /// so a vertex where the LineString doubles back is kept. The first and last vertices are
+    /// always kept, so the rings of a Polygon stay closed.
+    ///
     /// ```
     /// use geo::{Point, LineString};
     /// use geo::algorithm::simplify::{Simplify};
@@ -113,10 +141,157 @@ impl<T> Simplify<T> for MultiPolygon<T>
     }
 }
 
+/// Simplifies a geometry, adapting the tolerance to how densely it is sampled.
+pub trait SimplifyAdaptive<T> {
+    /// Returns the simplified representation of a LineString, using a variant of the
+    /// [Ramer–Douglas–Peucker](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm)
+    /// algorithm whose tolerance varies along the LineString
+    ///
+    /// The local sampling density at each vertex is measured by the mean length of the two
+    /// segments meeting there, compared to the mean segment length of the whole LineString. The
+    /// tolerance at a vertex is `base_epsilon * (mean / local) ^ density_weight`, so it's looser
+    /// where vertices are closely spaced, and tighter where they're sparse. This removes noise
+    /// from densely sampled stretches while preserving the detail of sparsely sampled ones. A
+    /// `density_weight` of zero gives the same result as `simplify`.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::simplify::SimplifyAdaptive;
+    ///
+    /// // closely spaced jitter, followed by a sparsely sampled bump of the same height
+    /// let ls: LineString<f64> = vec![(0., 0.), (0.2, 0.2), (0.4, 0.), (0.6, 0.2), (0.8, 0.),
+    ///                                (5., 0.2), (10., 0.)].into();
+    /// let simplified = ls.simplify_adaptive(0.1, 1.);
+    ///
+    /// assert_eq!(simplified, vec![(0., 0.), (5., 0.2), (10., 0.)].into());
+    /// ```
+    fn simplify_adaptive(&self, base_epsilon: T, density_weight: T) -> Self where T: Float;
+}
+
+impl<T> SimplifyAdaptive<T> for LineString<T>
+    where T: Float
+{
+    fn simplify_adaptive(&self, base_epsilon: T, density_weight: T) -> LineString<T> {
+        let points = &self.0;
+        if points.len() < 3 {
+            return self.clone();
+        }
+        let lengths: Vec<T> = self.lines().map(|line| line.start.distance(&line.end)).collect();
+        let mean = lengths.iter().fold(T::zero(), |total, &length| total + length) /
+                   T::from(lengths.len()).unwrap();
+        if mean == T::zero() {
+            return LineString(rdp(points, &base_epsilon));
+        }
+        let two = T::one() + T::one();
+        // the end points are always kept, so their tolerance is never used
+        let epsilons: Vec<T> = (0..points.len())
+            .map(|i| if i == 0 || i == points.len() - 1 {
+                base_epsilon
+            } else {
+                let local = (lengths[i - 1] + lengths[i]) / two;
+                base_epsilon * (mean / local).powf(density_weight)
+            })
+            .collect();
+        LineString(rdp_adaptive(points, &epsilons))
+    }
+}
+
+/// Simplifies a geometry, while keeping some of its vertices.
+pub trait SimplifyKeeping<T> {
+    /// Returns the simplified representation of a LineString, using the
+    /// [Ramer–Douglas–Peucker](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm)
+    /// algorithm, without removing the vertices at the `pinned` indices
+    ///
+    /// The LineString is split at each pinned vertex, and the pieces between them are simplified
+    /// separately, so the pinned vertices are kept whatever the tolerance. This is useful when
+    /// some of the vertices have been locked in place by a user. Indices past the end of the
+    /// LineString are ignored.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::simplify::SimplifyKeeping;
+    ///
+    /// let ls: LineString<f64> = vec![(0., 0.), (1., 0.1), (2., 0.), (3., 0.1), (4., 0.)].into();
+    /// let simplified = ls.simplify_keeping(&1.0, &[3]);
+    ///
+    /// assert_eq!(simplified, vec![(0., 0.), (3., 0.1), (4., 0.)].into());
+    /// ```
+    fn simplify_keeping(&self, epsilon: &T, pinned: &[usize]) -> Self where T: Float;
+}
+
+impl<T> SimplifyKeeping<T> for LineString<T>
+    where T: Float
+{
+    fn simplify_keeping(&self, epsilon: &T, pinned: &[usize]) -> LineString<T> {
+        if self.0.is_empty() {
+            return self.clone();
+        }
+        let last = self.0.len() - 1;
+        let mut splits: Vec<usize> = pinned.iter().cloned().filter(|&idx| idx < last).collect();
+        splits.push(0);
+        splits.push(last);
+        splits.sort();
+        splits.dedup();
+        let mut points = vec![self.0[0]];
+        for pair in splits.windows(2) {
+            points.extend_from_slice(&rdp(&self.0[pair[0]..pair[1] + 1], epsilon)[1..]);
+        }
+        LineString(points)
+    }
+}
+
+/// Simplifies a geometry, while keeping its sharp spikes.
+pub trait SimplifyKeepSpikes<T> {
+    /// Returns the simplified representation of a LineString, using the
+    /// [Ramer–Douglas–Peucker](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm)
+    /// algorithm, without removing the tips of sharp spikes
+    ///
+    /// A vertex whose two edges meet at an angle smaller than `min_spike_angle`, in degrees, is
+    /// kept however close it lies to the simplified line, as with `simplify_keeping`. The angles
+    /// are those of the original LineString. Vertices with a zero-length edge have no angle, and
+    /// may be removed.
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::simplify::SimplifyKeepSpikes;
+    ///
+    /// // a short, narrow spike
+    /// let ls: LineString<f64> = vec![(0., 0.), (5., 0.), (5.2, 0.8), (5.4, 0.), (10., 0.)].into();
+    /// let simplified = ls.simplify_keep_spikes(&1.0, 30.);
+    ///
+    /// assert_eq!(simplified, vec![(0., 0.), (5.2, 0.8), (10., 0.)].into());
+    /// ```
+    fn simplify_keep_spikes(&self, epsilon: &T, min_spike_angle: T) -> Self where T: Float;
+}
+
+impl<T> SimplifyKeepSpikes<T> for LineString<T>
+    where T: Float
+{
+    fn simplify_keep_spikes(&self, epsilon: &T, min_spike_angle: T) -> LineString<T> {
+        let spikes: Vec<usize> = self.0
+            .windows(3)
+            .enumerate()
+            .filter(|&(_, w)| {
+                let (ax, ay) = (w[0].x() - w[1].x(), w[0].y() - w[1].y());
+                let (bx, by) = (w[2].x() - w[1].x(), w[2].y() - w[1].y());
+                let (la, lb) = (ax.hypot(ay), bx.hypot(by));
+                if la == T::zero() || lb == T::zero() {
+                    return false;
+                }
+                let cos = ((ax * bx + ay * by) / (la * lb)).max(-T::one()).min(T::one());
+                cos.acos().to_degrees() < min_spike_angle
+            })
+            .map(|(idx, _)| idx + 1)
+            .collect();
+        self.simplify_keeping(epsilon, &spikes)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use types::{Point, LineString, Polygon, MultiLineString, MultiPolygon};
-    use super::{point_line_distance, rdp, Simplify};
+    use super::{point_line_distance, line_segment_distance, rdp, Simplify, SimplifyAdaptive, SimplifyKeeping,
+                SimplifyKeepSpikes};
 
     #[test]
     fn perpdistance_test() {
@@ -125,6 +300,9 @@ mod test {
         let p = Point::new(1.0, 1.0);
         let dist = point_line_distance(&p, &start, &end);
         assert_relative_eq!(dist, 0.7071067811865475);
+        // beyond the start of the segment, though on the line through it
+        assert_eq!(point_line_distance(&Point::new(-1., 0.), &start, &end), 0.);
+        assert_relative_eq!(line_segment_distance(&Point::new(-1., 0.), &start, &end), 8f64.sqrt());
     }
     #[test]
     fn rdp_test() {
@@ -160,6 +338,25 @@ mod test {
         let simplified = rdp(&vec, &1.0);
         assert_eq!(simplified, compare);
     }
+    #[test]
+    fn rdp_test_one_point_linestring() {
+        let vec = vec![Point::new(1.0, 2.0)];
+        assert_eq!(rdp(&vec, &1.0), vec);
+    }
+    #[test]
+    fn rdp_zigzag_test() {
+        let zigzag: LineString<f64> = vec![(0., 0.), (1., 0.1), (2., -0.1), (3., 0.1), (4., 0.)].into();
+        assert_eq!(zigzag.simplify(&0.5), vec![(0., 0.), (4., 0.)].into());
+        assert_eq!(zigzag.simplify(&0.), zigzag);
+        // a spike doubling back along the line lies far from the segment, if not from the line
+        let spike: LineString<f64> = vec![(0., 0.), (10., 0.), (4.5, 0.1), (5., 0.)].into();
+        assert_eq!(spike.simplify(&1.).0.len(), 3);
+        assert!(spike.simplify(&1.).0.contains(&Point::new(10., 0.)));
+        // a closed ring stays closed
+        let ring: LineString<f64> = vec![(0., 0.), (4., 0.), (4.1, 2.), (4., 4.), (0., 4.), (0., 0.)].into();
+        let poly = Polygon::new(ring, vec![]).simplify(&0.5);
+        assert_eq!(poly.exterior, vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into());
+    }
 
     #[test]
     fn multilinestring() {
@@ -227,4 +424,65 @@ mod test {
               ]), vec![])])
         );
     }
+
+    #[test]
+    fn simplify_adaptive_test() {
+        // 50 closely spaced segments of 0.2 high jitter, then 6 widely spaced bumps of the same height
+        let mut points: Vec<Point<f64>> = (0..51)
+            .map(|i| Point::new(i as f64 * 0.2, if i % 2 == 1 { 0.2 } else { 0. }))
+            .collect();
+        points.extend((1..7).map(|k| Point::new(10. + 5. * k as f64, if k % 2 == 1 { 0.2 } else { 0. })));
+        let ls = LineString(points);
+        let count = |simplified: &LineString<f64>| {
+            let dense = simplified.0.iter().filter(|p| p.x() < 10.).count();
+            (dense, simplified.0.len() - dense)
+        };
+        // plain Douglas-Peucker keeps all of the jitter, while losing some of the bumps,
+        assert_eq!(count(&ls.simplify(&0.15)), (50, 5));
+        // or loses all of the bumps along with the jitter
+        assert_eq!(count(&ls.simplify(&0.25)), (1, 1));
+        // whereas the adaptive tolerance keeps every bump, and drops the jitter
+        assert_eq!(count(&ls.simplify_adaptive(0.15, 1.)), (1, 7));
+        // with no weight given to density, it's plain Douglas-Peucker
+        assert_eq!(ls.simplify_adaptive(0.15, 0.), ls.simplify(&0.15));
+    }
+    #[test]
+    fn simplify_adaptive_degenerate_test() {
+        let empty = LineString::<f64>(vec![]);
+        assert_eq!(empty.simplify_adaptive(1., 1.), empty);
+        let two: LineString<f64> = vec![(0., 0.), (1., 1.)].into();
+        assert_eq!(two.simplify_adaptive(1., 1.), two);
+        let same: LineString<f64> = vec![(1., 1.), (1., 1.), (1., 1.)].into();
+        assert_eq!(same.simplify_adaptive(1., 1.), vec![(1., 1.), (1., 1.)].into());
+    }
+    #[test]
+    fn simplify_keeping_test() {
+        let ls: LineString<f64> = vec![(0., 0.), (5., 4.), (11., 5.5), (17.3, 3.2), (27.8, 0.1)].into();
+        // Douglas-Peucker drops the vertex at index 3
+        assert_eq!(ls.simplify(&1.0).0.len(), 4);
+        assert!(!ls.simplify(&1.0).0.contains(&Point::new(17.3, 3.2)));
+        let kept = ls.simplify_keeping(&1.0, &[3]);
+        assert_eq!(kept, vec![(0., 0.), (5., 4.), (11., 5.5), (17.3, 3.2), (27.8, 0.1)].into());
+        // even with a tolerance large enough to drop every other vertex
+        let kept = ls.simplify_keeping(&100.0, &[3, 99]);
+        assert_eq!(kept, vec![(0., 0.), (17.3, 3.2), (27.8, 0.1)].into());
+        // pinning the endpoints changes nothing
+        assert_eq!(ls.simplify_keeping(&1.0, &[0, 4]), ls.simplify(&1.0));
+        assert!(LineString::<f64>(vec![]).simplify_keeping(&1.0, &[0]).0.is_empty());
+    }
+    #[test]
+    fn simplify_keep_spikes_test() {
+        // a gentle bump, and a thin spike no taller than it
+        let ls: LineString<f64> = vec![(0., 0.), (2., 0.5), (4., 0.), (6., 0.), (6.1, 0.6), (6.2, 0.), (10., 0.)].into();
+        assert_eq!(ls.simplify(&1.0), vec![(0., 0.), (10., 0.)].into());
+        // the spike's edges meet at about 19 degrees, and the bump's at about 152
+        assert_eq!(ls.simplify_keep_spikes(&1.0, 30.), vec![(0., 0.), (6.1, 0.6), (10., 0.)].into());
+        assert_eq!(ls.simplify_keep_spikes(&1.0, 10.), ls.simplify(&1.0));
+        assert_eq!(ls.simplify_keep_spikes(&1.0, 170.), vec![(0., 0.), (2., 0.5), (4., 0.), (6., 0.), (6.1, 0.6),
+                                                             (6.2, 0.), (10., 0.)].into());
+        // a repeated vertex has no angle
+        let repeated: LineString<f64> = vec![(0., 0.), (1., 0.), (1., 0.), (2., 0.)].into();
+        assert_eq!(repeated.simplify_keep_spikes(&1.0, 90.), vec![(0., 0.), (2., 0.)].into());
+        assert!(LineString::<f64>(vec![]).simplify_keep_spikes(&1.0, 30.).0.is_empty());
+    }
 }
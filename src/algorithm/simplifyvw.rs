@@ -92,7 +92,9 @@ struct GeomSettings {
 // then recalculate the new triangle area and push it onto the heap
 // based on Huon Wilson's original implementation:
 // https://github.com/huonw/isrustfastyet/blob/25e7a68ff26673a8556b170d3c9af52e1c818288/mem/line_simplify.rs
-fn visvalingam<T>(orig: &[Point<T>], epsilon: &T) -> Vec<Point<T>>
+//
+// min_points: stop removing points once only this many remain, so that rings stay closed
+fn visvalingam<T>(orig: &[Point<T>], epsilon: &T, min_points: usize) -> Vec<Point<T>>
 where
     T: Float,
 {
@@ -102,6 +104,7 @@ where
     }
 
     let max = orig.len();
+    let mut remaining = orig.len();
 
     // Adjacent retained points. Simulating the points in a
     // linked list with indices into `orig`. Big number (larger than or equal to
@@ -148,8 +151,12 @@ where
         if left as i32 != smallest.left as i32 || right as i32 != smallest.right as i32 {
             continue;
         }
+        if remaining <= min_points {
+            break;
+        }
         // We've got a valid triangle, and its area is smaller than epsilon, so
         // remove it from the simulated "linked list"
+        remaining -= 1;
         let (ll, _) = adjacent[left as usize];
         let (_, rr) = adjacent[right as usize];
         adjacent[left as usize] = (ll, right);
@@ -403,9 +410,10 @@ where
 
 /// Simplifies a geometry.
 ///
-/// Polygons are simplified by running the algorithm on all their constituent rings.  This may
-/// result in invalid Polygons, and has no guarantee of preserving topology. Multi* objects are
-/// simplified by simplifying all their constituent geometries individually.
+/// Polygons are simplified by running the algorithm on all their constituent rings, each of which
+/// keeps at least four points. This may result in invalid Polygons, and has no guarantee of
+/// preserving topology. Multi* objects are simplified by simplifying all their constituent
+/// geometries individually.
 pub trait SimplifyVW<T, Epsilon = T> {
     /// Returns the simplified representation of a geometry, using the [Visvalingam-Whyatt](http://www.tandfonline.com/doi/abs/10.1179/000870493786962263) algorithm
     ///
@@ -554,7 +562,7 @@ where
     T: Float,
 {
     fn simplifyvw(&self, epsilon: &T) -> LineString<T> {
-        LineString(visvalingam(&self.0, epsilon))
+        LineString(visvalingam(&self.0, epsilon, 2))
     }
 }
 
@@ -572,11 +580,12 @@ where
     T: Float,
 {
     fn simplifyvw(&self, epsilon: &T) -> Polygon<T> {
+        // a closed ring needs at least four points
         Polygon::new(
-            self.exterior.simplifyvw(epsilon),
+            LineString(visvalingam(&self.exterior.0, epsilon, 4)),
             self.interiors
                 .iter()
-                .map(|l| l.simplifyvw(epsilon))
+                .map(|l| LineString(visvalingam(&l.0, epsilon, 4)))
                 .collect(),
         )
     }
@@ -612,7 +621,7 @@ mod test {
         let correct = vec![(5.0, 2.0), (7.0, 25.0), (10.0, 10.0)];
         let correct_ls: Vec<_> = correct.iter().map(|e| Point::new(e.0, e.1)).collect();
 
-        let simplified = visvalingam(&points_ls, &30.);
+        let simplified = visvalingam(&points_ls, &30., 2);
         assert_eq!(simplified, correct_ls);
     }
     #[test]
@@ -744,7 +753,7 @@ mod test {
         let points_ls: Vec<_> = points.iter().map(|e| Point::new(e[0], e[1])).collect();
         let correct = include!("test_fixtures/vw_simplified.rs");
         let correct_ls: Vec<_> = correct.iter().map(|e| Point::new(e[0], e[1])).collect();
-        let simplified = visvalingam(&points_ls, &0.0005);
+        let simplified = visvalingam(&points_ls, &0.0005, 2);
         assert_eq!(simplified, correct_ls);
     }
     #[test]
@@ -761,7 +770,7 @@ mod test {
     fn visvalingam_test_empty_linestring() {
         let vec = Vec::new();
         let compare = Vec::new();
-        let simplified = visvalingam(&vec, &1.0);
+        let simplified = visvalingam(&vec, &1.0, 2);
         assert_eq!(simplified, compare);
     }
     #[test]
@@ -772,7 +781,7 @@ mod test {
         let mut compare = Vec::new();
         compare.push(Point::new(0.0, 0.0));
         compare.push(Point::new(27.8, 0.1));
-        let simplified = visvalingam(&vec, &1.0);
+        let simplified = visvalingam(&vec, &1.0, 2);
         assert_eq!(simplified, compare);
     }
 
@@ -863,4 +872,29 @@ mod test {
             ])
         );
     }
+    #[test]
+    fn simplifyvw_ring_minimum_test() {
+        // a thin triangle, all of whose vertex triangles are smaller than the tolerance
+        let poly = Polygon::new(vec![(0., 0.), (10., 0.), (10., 0.1), (5., 0.2), (0., 0.)].into(),
+                                vec![vec![(1., 0.05), (2., 0.05), (1.5, 0.1), (1., 0.05)].into()]);
+        let simplified = poly.simplifyvw(&100.);
+        assert_eq!(simplified.exterior.0.len(), 4);
+        assert_eq!(simplified.exterior.0.first(), simplified.exterior.0.last());
+        assert_eq!(simplified.interiors[0], poly.interiors[0]);
+        // a LineString can go down to its endpoints
+        assert_eq!(poly.exterior.simplifyvw(&100.).0.len(), 2);
+    }
+    #[test]
+    fn simplifyvw_against_rdp_test() {
+        use algorithm::simplify::Simplify;
+        // a tall, narrow spike: far from the line, so Douglas-Peucker keeps it, but enclosing little
+        // area, so Visvalingam-Whyatt drops it
+        let ls: LineString<f64> = vec![(0., 0.), (5., 0.), (5.1, 10.), (5.2, 0.), (10., 0.)].into();
+        assert!(ls.simplify(&1.).0.contains(&Point::new(5.1, 10.)));
+        assert_eq!(ls.simplifyvw(&2.), vec![(0., 0.), (10., 0.)].into());
+        // and a wide, shallow bump the other way round
+        let bump: LineString<f64> = vec![(0., 0.), (5., 0.8), (10., 0.)].into();
+        assert_eq!(bump.simplify(&1.), vec![(0., 0.), (10., 0.)].into());
+        assert_eq!(bump.simplifyvw(&2.), bump);
+    }
 }
@@ -0,0 +1,128 @@
+use num_traits::Float;
+use types::{Point, LineString, Polygon, MultiPolygon};
+
+// the grid point nearest to p
+fn snap<T>(p: &Point<T>, cell_size: T) -> Point<T>
+    where T: Float
+{
+    Point::new((p.x() / cell_size).round() * cell_size, (p.y() / cell_size).round() * cell_size)
+}
+
+// Snaps a Polygon ring, keeping it closed. The first and last vertices of a ring which is only
+// closed to within floating point error can round to different grid points, so such a ring is
+// closed again by setting its last vertex to its first. A ring whose endpoints are further apart
+// than that was left open, and is closed by appending its first vertex, so no vertex is lost.
+fn snap_ring<T>(ring: &LineString<T>, cell_size: T) -> LineString<T>
+    where T: Float
+{
+    let mut snapped = ring.snap_to_grid(cell_size);
+    if let (Some(start), Some(end)) = (ring.0.first(), ring.0.last()) {
+        let (first, last) = (snapped.0[0], snapped.0.len() - 1);
+        if snapped.0[last] == first {
+            return snapped;
+        }
+        if (end.x() - start.x()).hypot(end.y() - start.y()) < cell_size / (T::one() + T::one()) {
+            snapped.0[last] = first;
+        } else {
+            snapped.0.push(first);
+        }
+    }
+    snapped
+}
+
+/// Reduces the precision of a geometry by snapping it to a grid.
+pub trait SnapToGrid<T> {
+    /// Moves each coordinate of a geometry to the nearest point of a square grid
+    ///
+    /// The grid has its origin at `(0, 0)`, and its cells are `cell_size` wide. The rings of
+    /// Polygons are kept closed, even if their endpoints differ slightly and would round to
+    /// different grid points. If `cell_size` isn't positive, the geometry is returned unchanged.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::snap_to_grid::SnapToGrid;
+    ///
+    /// let ls = LineString(vec![Point::new(0.2, 1.9), Point::new(2.6, 3.4)]);
+    /// let snapped = ls.snap_to_grid(0.5);
+    ///
+    /// assert_eq!(snapped, LineString(vec![Point::new(0., 2.), Point::new(2.5, 3.5)]));
+    /// ```
+    fn snap_to_grid(&self, cell_size: T) -> Self where T: Float;
+}
+
+impl<T> SnapToGrid<T> for Point<T>
+    where T: Float
+{
+    fn snap_to_grid(&self, cell_size: T) -> Self {
+        if cell_size.is_nan() || cell_size <= T::zero() {
+            return *self;
+        }
+        snap(self, cell_size)
+    }
+}
+
+impl<T> SnapToGrid<T> for LineString<T>
+    where T: Float
+{
+    fn snap_to_grid(&self, cell_size: T) -> Self {
+        LineString(self.0.iter().map(|p| p.snap_to_grid(cell_size)).collect())
+    }
+}
+
+impl<T> SnapToGrid<T> for Polygon<T>
+    where T: Float
+{
+    fn snap_to_grid(&self, cell_size: T) -> Self {
+        if cell_size.is_nan() || cell_size <= T::zero() {
+            return self.clone();
+        }
+        Polygon::new(snap_ring(&self.exterior, cell_size),
+                     self.interiors.iter().map(|ring| snap_ring(ring, cell_size)).collect())
+    }
+}
+
+impl<T> SnapToGrid<T> for MultiPolygon<T>
+    where T: Float
+{
+    fn snap_to_grid(&self, cell_size: T) -> Self {
+        MultiPolygon(self.0.iter().map(|poly| poly.snap_to_grid(cell_size)).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon, MultiPolygon};
+    use super::*;
+
+    #[test]
+    fn snap_to_grid_test() {
+        let poly = Polygon::new(vec![(0.1, 0.1), (3.9, 0.2), (4.2, 2.8), (0.1, 0.1)].into(), vec![]);
+        let snapped = poly.snap_to_grid(1.);
+        assert_eq!(snapped.exterior, vec![(0., 0.), (4., 0.), (4., 3.), (0., 0.)].into());
+        assert_eq!(Point::new(1.3, -1.3).snap_to_grid(0.5), Point::new(1.5, -1.5));
+        assert_eq!(poly.snap_to_grid(0.), poly);
+    }
+    #[test]
+    fn snap_to_grid_keeps_rings_closed_test() {
+        // the endpoints differ by floating point error, and naively round to 0.3 and 0.4
+        // respectively, opening the ring
+        let start = Point::new(0.35, 1.);
+        let end = Point::new(0.35000000000000003, 1.);
+        assert!(start != end);
+        assert!(start.snap_to_grid(0.1) != end.snap_to_grid(0.1));
+        let ring = LineString(vec![start, Point::new(2., 1.), Point::new(2., 2.), end]);
+        let poly = Polygon::new(ring.clone(), vec![ring]);
+        let snapped = MultiPolygon(vec![poly]).snap_to_grid(0.1);
+        for ring in Some(&snapped.0[0].exterior).into_iter().chain(snapped.0[0].interiors.iter()) {
+            assert_eq!(ring.0.first(), ring.0.last());
+            assert_eq!(ring.0.len(), 4);
+        }
+    }
+    #[test]
+    fn snap_to_grid_open_ring_test() {
+        // an open ring is closed by appending its first vertex, rather than losing its last one
+        let ring: LineString<f64> = vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.)].into();
+        let snapped = Polygon::new(ring, vec![]).snap_to_grid(1.);
+        assert_eq!(snapped.exterior, vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into());
+    }
+}
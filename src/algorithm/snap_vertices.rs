@@ -0,0 +1,106 @@
+use num_traits::Float;
+use types::{Point, MultiPoint, LineString, MultiLineString, Polygon, MultiPolygon};
+use algorithm::distance::Distance;
+
+/// Conflates a geometry with a set of control points.
+pub trait SnapVerticesTo<T> {
+    /// Moves each vertex of a geometry which lies within `tolerance` of a target Point onto
+    /// the nearest such target
+    ///
+    /// Vertices further than `tolerance` from every target are left where they are. If two
+    /// targets are equally near, the vertex moves onto the first. This can be used to make
+    /// geometries from different datasets share a common set of nodes. As a ring's first and
+    /// last vertices coincide, they move onto the same target, so rings stay closed.
+    ///
+    /// ```
+    /// use geo::{Point, MultiPoint, LineString};
+    /// use geo::algorithm::snap_vertices::SnapVerticesTo;
+    ///
+    /// let targets = MultiPoint(vec![Point::new(0., 0.), Point::new(10., 0.)]);
+    /// let ls = LineString(vec![Point::new(0.1, -0.1), Point::new(5., 0.), Point::new(9.9, 0.)]);
+    ///
+    /// assert_eq!(ls.snap_vertices_to(&targets, 0.5),
+    ///            LineString(vec![Point::new(0., 0.), Point::new(5., 0.), Point::new(10., 0.)]));
+    /// ```
+    fn snap_vertices_to(&self, targets: &MultiPoint<T>, tolerance: T) -> Self where T: Float;
+}
+
+impl<T> SnapVerticesTo<T> for Point<T>
+    where T: Float
+{
+    fn snap_vertices_to(&self, targets: &MultiPoint<T>, tolerance: T) -> Self {
+        let mut nearest: Option<(Point<T>, T)> = None;
+        for target in &targets.0 {
+            let distance = self.distance(target);
+            let nearer = match nearest {
+                Some((_, best)) => distance < best,
+                None => true,
+            };
+            if distance <= tolerance && nearer {
+                nearest = Some((*target, distance));
+            }
+        }
+        nearest.map_or(*self, |(target, _)| target)
+    }
+}
+
+impl<T> SnapVerticesTo<T> for LineString<T>
+    where T: Float
+{
+    fn snap_vertices_to(&self, targets: &MultiPoint<T>, tolerance: T) -> Self {
+        LineString(self.0.iter().map(|p| p.snap_vertices_to(targets, tolerance)).collect())
+    }
+}
+
+impl<T> SnapVerticesTo<T> for MultiLineString<T>
+    where T: Float
+{
+    fn snap_vertices_to(&self, targets: &MultiPoint<T>, tolerance: T) -> Self {
+        MultiLineString(self.0.iter().map(|ls| ls.snap_vertices_to(targets, tolerance)).collect())
+    }
+}
+
+impl<T> SnapVerticesTo<T> for Polygon<T>
+    where T: Float
+{
+    fn snap_vertices_to(&self, targets: &MultiPoint<T>, tolerance: T) -> Self {
+        Polygon::new(self.exterior.snap_vertices_to(targets, tolerance),
+                     self.interiors.iter().map(|ring| ring.snap_vertices_to(targets, tolerance)).collect())
+    }
+}
+
+impl<T> SnapVerticesTo<T> for MultiPolygon<T>
+    where T: Float
+{
+    fn snap_vertices_to(&self, targets: &MultiPoint<T>, tolerance: T) -> Self {
+        MultiPolygon(self.0.iter().map(|poly| poly.snap_vertices_to(targets, tolerance)).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, MultiPoint, Polygon};
+    use super::*;
+
+    #[test]
+    fn snap_vertices_to_test() {
+        // control points every 10 units
+        let targets: MultiPoint<f64> = (0..3)
+            .flat_map(|x| (0..3).map(move |y| Point::new(x as f64 * 10., y as f64 * 10.)))
+            .collect();
+        let poly = Polygon::new(vec![(0.3, -0.2), (9.8, 0.4), (10.2, 9.7), (5., 10.), (-0.1, 10.1), (0.3, -0.2)].into(),
+                                vec![]);
+        let snapped = poly.snap_vertices_to(&targets, 0.5);
+        assert_eq!(snapped.exterior,
+                   vec![(0., 0.), (10., 0.), (10., 10.), (5., 10.), (0., 10.), (0., 0.)].into());
+        // nothing is near enough
+        assert_eq!(poly.snap_vertices_to(&targets, 0.1), poly);
+        assert_eq!(poly.snap_vertices_to(&MultiPoint(vec![]), 1.), poly);
+    }
+    #[test]
+    fn snap_vertices_to_nearest_test() {
+        let targets = MultiPoint(vec![Point::new(0., 0.), Point::new(1., 0.)]);
+        assert_eq!(Point::new(0.6, 0.).snap_vertices_to(&targets, 1.), Point::new(1., 0.));
+        assert_eq!(Point::new(0.5, 0.).snap_vertices_to(&targets, 1.), Point::new(0., 0.));
+    }
+}
@@ -0,0 +1,100 @@
+use num_traits::Float;
+use types::{LineString, Polygon};
+
+// The signed spherical excess of a ring of lon/lat vertices, joined by great circle arcs:
+// positive if it runs counter-clockwise. Each edge contributes the excess of the spherical
+// trapezoid between it and the equator, given by tan(E / 2) = tan(Δλ / 2)(t1 + t2) / (1 + t1 t2),
+// where t = tan(φ / 2) at either end.
+fn ring_excess<T>(ring: &LineString<T>) -> T
+    where T: Float
+{
+    let two = T::one() + T::one();
+    let half_turn = T::from(180.).unwrap();
+    ring.lines().fold(T::zero(), |total, line| {
+        let mut delta = line.end.x() - line.start.x();
+        // take the shorter way around, for edges crossing the antimeridian
+        if delta > half_turn {
+            delta = delta - two * half_turn;
+        } else if delta < -half_turn {
+            delta = delta + two * half_turn;
+        }
+        let t1 = (line.start.y().to_radians() / two).tan();
+        let t2 = (line.end.y().to_radians() / two).tan();
+        total - two * ((delta.to_radians() / two).tan() * (t1 + t2)).atan2(T::one() + t1 * t2)
+    })
+}
+
+/// Calculates the spherical excess of a geometry.
+pub trait SphericalExcess<T: Float> {
+    /// Returns the spherical excess of a Polygon, in steradians
+    ///
+    /// The vertices are longitudes and latitudes in degrees, joined by great circle arcs. The
+    /// excess is the area the Polygon covers on a unit sphere, so its area on a sphere of radius
+    /// `R` is `excess × R²`. The excess of each hole is subtracted from that of the exterior,
+    /// whatever the orientation of the rings. Each ring is taken to enclose the smaller of the
+    /// two regions it bounds, so a Polygon mustn't contain a pole.
+    ///
+    /// ```
+    /// # extern crate geo;
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use std::f64::consts::PI;
+    /// use geo::Polygon;
+    /// use geo::algorithm::spherical_excess::SphericalExcess;
+    ///
+    /// # fn main() {
+    /// // an eighth of the sphere
+    /// let octant = Polygon::new(vec![(0., 0.), (90., 0.), (0., 90.), (0., 0.)].into(), vec![]);
+    ///
+    /// assert_relative_eq!(octant.spherical_excess(), PI / 2., epsilon = 1e-12);
+    /// # }
+    /// ```
+    fn spherical_excess(&self) -> T;
+}
+
+impl<T> SphericalExcess<T> for Polygon<T>
+    where T: Float
+{
+    fn spherical_excess(&self) -> T {
+        self.interiors
+            .iter()
+            .fold(ring_excess(&self.exterior).abs(), |total, ring| total - ring_excess(ring).abs())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::f64::consts::PI;
+    use types::{LineString, Polygon};
+    use super::*;
+
+    #[test]
+    fn spherical_excess_octant_test() {
+        let octant: LineString<f64> = vec![(0., 0.), (90., 0.), (0., 90.), (0., 0.)].into();
+        assert_relative_eq!(ring_excess(&octant), PI / 2., epsilon = 1e-12);
+        let reversed: LineString<f64> = vec![(0., 0.), (0., 90.), (90., 0.), (0., 0.)].into();
+        assert_relative_eq!(ring_excess(&reversed), -PI / 2., epsilon = 1e-12);
+        assert_relative_eq!(Polygon::new(reversed, vec![]).spherical_excess(), PI / 2., epsilon = 1e-12);
+        // across the antimeridian
+        let across: LineString<f64> = vec![(135., 0.), (-135., 0.), (-135., 90.), (135., 0.)].into();
+        assert_relative_eq!(ring_excess(&across), PI / 2., epsilon = 1e-12);
+    }
+    #[test]
+    fn spherical_excess_small_test() {
+        // a tiny triangle is nearly flat, so its excess is close to its planar area in radians
+        let d = 0.01;
+        let triangle = Polygon::new(vec![(10., 20.), (10. + d, 20.), (10., 20. + d), (10., 20.)].into(), vec![]);
+        let flat = (d.to_radians() * d.to_radians() * 20f64.to_radians().cos()) / 2.;
+        assert_relative_eq!(triangle.spherical_excess(), flat, max_relative = 1e-4);
+    }
+    #[test]
+    fn spherical_excess_hole_test() {
+        let exterior: LineString<f64> = vec![(0., 0.), (90., 0.), (0., 90.), (0., 0.)].into();
+        let hole: LineString<f64> = vec![(10., 10.), (10., 30.), (30., 10.), (10., 10.)].into();
+        let poly = Polygon::new(exterior.clone(), vec![hole.clone()]);
+        let expected = Polygon::new(exterior, vec![]).spherical_excess() -
+                       Polygon::new(hole, vec![]).spherical_excess();
+        assert_relative_eq!(poly.spherical_excess(), expected, epsilon = 1e-12);
+        assert_eq!(Polygon::<f64>::new(LineString(vec![]), vec![]).spherical_excess(), 0.);
+    }
+}
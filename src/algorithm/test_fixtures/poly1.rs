@@ -103,4 +103,4 @@ vec![
     (-158.47293006129595, 61.12284789161923),
     (-150.3871025524086, 66.37789008984335),
     (-75.57274028771249, 110.01960141091608)
-];
+]
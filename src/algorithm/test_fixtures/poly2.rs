@@ -102,4 +102,4 @@ vec![
     (509.801714032956, -99.5184726672197),
     (500.0, -100.0),
     (0.0, -100.0)
-];
+]
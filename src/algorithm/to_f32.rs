@@ -0,0 +1,62 @@
+use algorithm::map_coords::MapCoords;
+
+/// Converts the coordinates of a geometry from `f64` to `f32`.
+pub trait ToF32 {
+    type Output;
+
+    /// Returns a copy of the geometry with its coordinates cast to `f32`
+    ///
+    /// Each coordinate is converted using `as f32`, so precision is lost, and values which are
+    /// too large for an `f32` become infinite. This is mostly useful for filling GPU buffers,
+    /// which typically expect single-precision floats.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::to_f32::ToF32;
+    ///
+    /// let linestring: LineString<f64> = vec![(0.5, 1.25), (2., 3.)].into();
+    /// let single: LineString<f32> = linestring.to_f32();
+    ///
+    /// assert_eq!(single.0, vec![Point::new(0.5f32, 1.25f32), Point::new(2f32, 3f32)]);
+    /// ```
+    fn to_f32(&self) -> Self::Output;
+}
+
+impl<G> ToF32 for G
+    where G: MapCoords<f64, f32>
+{
+    type Output = G::Output;
+
+    fn to_f32(&self) -> Self::Output {
+        self.map_coords(&|&(x, y)| (x as f32, y as f32))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon, Geometry};
+    use super::*;
+
+    #[test]
+    fn polygon_to_f32_test() {
+        let exterior: LineString<f64> = vec![(0., 0.), (10.5, 0.), (10.5, 7.25), (0., 0.)].into();
+        let interiors: Vec<LineString<f64>> = vec![vec![(1., 1.), (2., 1.), (2., 1.5), (1., 1.)].into()];
+        let poly = Polygon::new(exterior, interiors);
+        let single: Polygon<f32> = poly.to_f32();
+        let correct = Polygon::new(vec![(0f32, 0f32), (10.5, 0.), (10.5, 7.25), (0., 0.)].into(),
+                                   vec![vec![(1f32, 1f32), (2., 1.), (2., 1.5), (1., 1.)].into()]);
+        assert_eq!(single, correct);
+    }
+    #[test]
+    fn geometry_to_f32_test() {
+        // precision beyond that of an f32 is lost
+        let geometry = Geometry::Point(Point::new(0.1f64, 1e40));
+        match geometry.to_f32() {
+            Geometry::Point(p) => {
+                assert_eq!(p.x(), 0.1f32);
+                assert!(p.y().is_infinite());
+            }
+            _ => panic!("expected a Point"),
+        }
+    }
+}
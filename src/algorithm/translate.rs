@@ -1,5 +1,8 @@
 use num_traits::Float;
+use types::Point;
 use algorithm::map_coords::MapCoords;
+use algorithm::centroid::Centroid;
+use algorithm::boundingbox::BboxCenter;
 
 pub trait Translate<T> {
     /// Translate a Geometry along its axes by the given offsets
@@ -23,6 +26,19 @@ pub trait Translate<T> {
     /// assert_eq!(translated, correct_ls);
     /// ```
     fn translate(&self, xoff: T, yoff: T) -> Self where T: Float;
+
+    /// Translate a Geometry in place, along its axes by the given offsets
+    ///
+    /// ```
+    /// use geo::{Point, Polygon};
+    /// use geo::algorithm::translate::Translate;
+    ///
+    /// let mut square = Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into(), vec![]);
+    /// square.translate_inplace(2., -1.);
+    ///
+    /// assert_eq!(square.exterior.0[2], Point::new(3., 0.));
+    /// ```
+    fn translate_inplace(&mut self, xoff: T, yoff: T) where T: Float;
 }
 
 impl<T, G> Translate<T> for G
@@ -32,11 +48,61 @@ impl<T, G> Translate<T> for G
     fn translate(&self, xoff: T, yoff: T) -> Self {
         self.map_coords(&|&(x, y)| (x + xoff, y + yoff))
     }
+
+    fn translate_inplace(&mut self, xoff: T, yoff: T) {
+        *self = self.translate(xoff, yoff);
+    }
+}
+
+/// The point of a geometry to move onto the origin
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Center {
+    /// the centroid of the geometry
+    Centroid,
+    /// the centre of the geometry's bounding box
+    Bbox,
+}
+
+/// Moves a geometry onto the origin.
+pub trait CenterOnOrigin<T> {
+    /// Translates a Geometry so that its centroid, or the centre of its bounding box, lies at
+    /// the origin
+    ///
+    /// This normalizes the position of geometries before comparing their shapes, or rotating
+    /// them. An empty Geometry is returned as it is.
+    ///
+    /// ```
+    /// use geo::{Point, Polygon};
+    /// use geo::algorithm::centroid::Centroid;
+    /// use geo::algorithm::translate::{CenterOnOrigin, Center};
+    ///
+    /// let triangle = Polygon::new(vec![(3., 3.), (6., 3.), (3., 6.), (3., 3.)].into(), vec![]);
+    ///
+    /// assert_eq!(triangle.center_on_origin(Center::Centroid).centroid(), Some(Point::new(0., 0.)));
+    /// assert_eq!(triangle.center_on_origin(Center::Bbox).exterior.0[0], Point::new(-1.5, -1.5));
+    /// ```
+    fn center_on_origin(&self, center: Center) -> Self where T: Float;
+}
+
+impl<T, G> CenterOnOrigin<T> for G
+    where T: Float,
+        G: MapCoords<T, T, Output=G> + Centroid<T, Output=Option<Point<T>>> + BboxCenter<T>
+{
+    fn center_on_origin(&self, center: Center) -> Self {
+        let p = match center {
+            Center::Centroid => self.centroid(),
+            Center::Bbox => self.bbox_center(),
+        };
+        match p {
+            Some(p) => self.translate(-p.x(), -p.y()),
+            None => self.translate(T::zero(), T::zero()),
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use types::{Point, LineString, Polygon};
+    use types::{Point, Line, LineString, Polygon, MultiPoint, MultiLineString, MultiPolygon};
     use super::*;
     #[test]
     fn test_translate_point() {
@@ -124,4 +190,56 @@ mod test {
         assert_eq!(rotated.exterior.0, correct_outside);
         assert_eq!(rotated.interiors[0].0, correct_inside);
     }
+    #[test]
+    fn test_translate_inplace_round_trip() {
+        let exterior: LineString<f64> = vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into();
+        let hole: LineString<f64> = vec![(1., 1.), (1., 2.), (2., 2.), (1., 1.)].into();
+        let poly = Polygon::new(exterior, vec![hole]);
+        let mut moved = poly.clone();
+        moved.translate_inplace(3.25, -7.5);
+        assert_eq!(moved.interiors[0].0[1], Point::new(4.25, -5.5));
+        moved.translate_inplace(-3.25, 7.5);
+        assert_eq!(moved, poly);
+
+        let mut point = Point::new(1., 2.);
+        point.translate_inplace(0.5, 0.5);
+        assert_eq!(point, Point::new(1.5, 2.5));
+        point.translate_inplace(-0.5, -0.5);
+        assert_eq!(point, Point::new(1., 2.));
+
+        let line = Line::new(Point::new(0., 0.), Point::new(1., 1.));
+        assert_eq!(line.translate(2., 2.).translate(-2., -2.), line);
+        let points = MultiPoint(vec![Point::new(0., 0.), Point::new(1., 1.)]);
+        assert_eq!(points.translate(2., 2.).translate(-2., -2.), points);
+        let lines = MultiLineString(vec![vec![(0., 0.), (1., 1.)].into()]);
+        assert_eq!(lines.translate(2., 2.).translate(-2., -2.), lines);
+        let polys = MultiPolygon(vec![poly.clone()]);
+        assert_eq!(polys.translate(2., 2.).translate(-2., -2.), polys);
+    }
+    #[test]
+    fn test_translate_empty() {
+        let mut empty = Polygon::<f64>::new(LineString(vec![]), vec![]);
+        empty.translate_inplace(1., 1.);
+        assert_eq!(empty, Polygon::new(LineString(vec![]), vec![]));
+        assert_eq!(MultiPoint::<f64>(vec![]).translate(1., 1.), MultiPoint(vec![]));
+    }
+    #[test]
+    fn center_on_origin_test() {
+        let square = Polygon::new(vec![(10., 5.), (12., 5.), (12., 7.), (10., 7.), (10., 5.)].into(), vec![]);
+        let centered = square.center_on_origin(Center::Centroid);
+        let centroid = centered.centroid().unwrap();
+        assert_relative_eq!(centroid.x(), 0., epsilon = 1e-10);
+        assert_relative_eq!(centroid.y(), 0., epsilon = 1e-10);
+        assert_eq!(centered.exterior.0[0], Point::new(-1., -1.));
+        // the centroid of an L shape is off the centre of its bounding box
+        let l: LineString<f64> = vec![(0., 0.), (4., 0.), (4., 1.), (1., 1.), (1., 4.), (0., 4.), (0., 0.)].into();
+        let l = Polygon::new(l, vec![]).translate(3., -2.);
+        assert_eq!(l.center_on_origin(Center::Bbox).bbox_center(), Some(Point::new(0., 0.)));
+        let centroid = l.center_on_origin(Center::Centroid).centroid().unwrap();
+        assert_relative_eq!(centroid.x(), 0., epsilon = 1e-10);
+        assert_relative_eq!(centroid.y(), 0., epsilon = 1e-10);
+        assert!(l.center_on_origin(Center::Centroid) != l.center_on_origin(Center::Bbox));
+        let empty = Polygon::<f64>::new(LineString(vec![]), vec![]);
+        assert_eq!(empty.center_on_origin(Center::Centroid), empty);
+    }
 }
@@ -0,0 +1,157 @@
+use num_traits::Float;
+use types::{Point, Line, LineString, Polygon, Triangle};
+use algorithm::area::{Area, Winding};
+use algorithm::contains::Contains;
+use algorithm::kernels::{cross, crosses};
+
+// true if the point lies strictly inside the triangle
+fn strictly_inside<T>(p: &Point<T>, triangle: &Triangle<T>) -> bool
+    where T: Float
+{
+    let d1 = cross(&triangle.0, &triangle.1, p);
+    let d2 = cross(&triangle.1, &triangle.2, p);
+    let d3 = cross(&triangle.2, &triangle.0, p);
+    (d1 > T::zero() && d2 > T::zero() && d3 > T::zero()) ||
+        (d1 < T::zero() && d2 < T::zero() && d3 < T::zero())
+}
+
+// the mean of the three vertices
+fn centroid<T>(triangle: &Triangle<T>) -> Point<T>
+    where T: Float
+{
+    let three = T::from(3.).unwrap();
+    Point::new((triangle.0.x() + triangle.1.x() + triangle.2.x()) / three,
+               (triangle.0.y() + triangle.1.y() + triangle.2.y()) / three)
+}
+
+// the edges of the triangle, as Lines
+fn edges<T>(triangle: &Triangle<T>) -> [Line<T>; 3]
+    where T: Float
+{
+    [Line::new(triangle.0, triangle.1), Line::new(triangle.1, triangle.2), Line::new(triangle.2, triangle.0)]
+}
+
+// A triangle lies within the polygon if its centroid does, and the polygon's boundary doesn't
+// pass through the triangle's interior: no ring vertex lies inside it, and no ring edge crosses
+// one of its edges.
+fn within<T>(triangle: &Triangle<T>, polygon: &Polygon<T>) -> bool
+    where T: Float
+{
+    if !polygon.contains(&centroid(triangle)) {
+        return false;
+    }
+    let edges = edges(triangle);
+    let rings: Vec<&LineString<T>> = Some(&polygon.exterior).into_iter().chain(polygon.interiors.iter()).collect();
+    rings.iter().all(|ring| {
+        !ring.0.iter().any(|p| strictly_inside(p, triangle)) &&
+            !ring.lines().any(|line| edges.iter().any(|edge| crosses(edge, &line)))
+    })
+}
+
+// Two triangles overlap if the boundary of one passes through the interior of the other, or if
+// one lies entirely inside the other, in which case its centroid does too.
+fn overlap<T>(a: &Triangle<T>, b: &Triangle<T>) -> bool
+    where T: Float
+{
+    let (edges_a, edges_b) = (edges(a), edges(b));
+    edges_a.iter().any(|ea| edges_b.iter().any(|eb| crosses(ea, eb))) ||
+        [a.0, a.1, a.2, centroid(a)].iter().any(|p| strictly_inside(p, b)) ||
+        [b.0, b.1, b.2, centroid(b)].iter().any(|p| strictly_inside(p, a))
+}
+
+/// Validates a triangulation of a geometry.
+pub trait TrianglesCover<T: Float> {
+    /// Checks that a set of triangles partitions a Polygon
+    ///
+    /// Returns `true` if every triangle lies within the Polygon (touching its boundary is
+    /// allowed, but covering part of a hole is not), no two triangles overlap, and the
+    /// triangles' areas add up to the Polygon's area, to within `epsilon`. Together, these mean
+    /// that the triangles cover the whole Polygon. The orientation of the triangles and of the
+    /// Polygon's rings doesn't matter. Each pair of triangles is compared, so this takes time
+    /// quadratic in the number of triangles.
+    ///
+    /// This is useful for testing the output of a triangulation, such as ear clipping.
+    ///
+    /// ```
+    /// use geo::{Point, Polygon, Triangle};
+    /// use geo::algorithm::triangles_cover::TrianglesCover;
+    ///
+    /// let square = Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into(), vec![]);
+    /// let triangles = [Triangle(Point::new(0., 0.), Point::new(1., 0.), Point::new(1., 1.)),
+    ///                  Triangle(Point::new(0., 0.), Point::new(1., 1.), Point::new(0., 1.))];
+    ///
+    /// assert!(square.triangles_cover(&triangles, 1e-10));
+    /// // half of the square is left uncovered
+    /// assert!(!square.triangles_cover(&triangles[..1], 1e-10));
+    /// ```
+    fn triangles_cover(&self, triangles: &[Triangle<T>], epsilon: T) -> bool;
+}
+
+impl<T> TrianglesCover<T> for Polygon<T>
+    where T: Float
+{
+    fn triangles_cover(&self, triangles: &[Triangle<T>], epsilon: T) -> bool {
+        let area = self.interiors
+            .iter()
+            .fold(self.exterior.signed_area().abs(), |total, ring| total - ring.signed_area().abs());
+        let covered = triangles.iter().fold(T::zero(), |total, triangle| total + triangle.area());
+        if (area - covered).abs() > epsilon || !triangles.iter().all(|triangle| within(triangle, self)) {
+            return false;
+        }
+        // degenerate triangles have no interior to overlap with
+        let solid: Vec<&Triangle<T>> = triangles.iter().filter(|triangle| triangle.area() != T::zero()).collect();
+        solid.iter()
+            .enumerate()
+            .all(|(idx, a)| solid[idx + 1..].iter().all(|b| !overlap(a, b)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon, Triangle};
+    use super::*;
+
+    fn t(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> Triangle<f64> {
+        Triangle(Point::new(a.0, a.1), Point::new(b.0, b.1), Point::new(c.0, c.1))
+    }
+
+    #[test]
+    fn triangles_cover_concave_test() {
+        // an L shape, with a reflex vertex at (1, 1)
+        let poly = Polygon::new(vec![(0., 0.), (2., 0.), (2., 1.), (1., 1.), (1., 2.), (0., 2.), (0., 0.)].into(),
+                                vec![]);
+        let correct = [t((0., 0.), (2., 0.), (2., 1.)),
+                       t((0., 0.), (2., 1.), (1., 1.)),
+                       t((0., 0.), (1., 1.), (0., 2.)),
+                       t((1., 1.), (1., 2.), (0., 2.))];
+        assert!(poly.triangles_cover(&correct, 1e-10));
+        // the areas add up, but the second triangle bridges the notch of the L
+        let escaping = [t((0., 0.), (2., 0.), (2., 1.)), t((0., 0.), (2., 1.), (0., 2.))];
+        let covered: f64 = escaping.iter().map(|tri| tri.area()).sum();
+        assert_relative_eq!(covered, 3.);
+        assert!(!poly.triangles_cover(&escaping, 1e-10));
+    }
+    #[test]
+    fn triangles_cover_overlap_test() {
+        let square = Polygon::new(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)].into(), vec![]);
+        // both triangles lie inside, but overlap, leaving a corner uncovered
+        let overlapping = [t((0., 0.), (2., 0.), (2., 2.)), t((0., 0.), (2., 0.), (0., 2.))];
+        assert!(!square.triangles_cover(&overlapping, 1e-10));
+        let correct = [t((0., 0.), (2., 0.), (2., 2.)), t((2., 2.), (0., 2.), (0., 0.))];
+        assert!(square.triangles_cover(&correct, 1e-10));
+    }
+    #[test]
+    fn triangles_cover_hole_test() {
+        let exterior: LineString<f64> = vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into();
+        let hole: LineString<f64> = vec![(1., 1.), (1., 3.), (3., 3.), (3., 1.), (1., 1.)].into();
+        let poly = Polygon::new(exterior, vec![hole]);
+        let correct = [t((0., 0.), (4., 0.), (3., 1.)), t((0., 0.), (3., 1.), (1., 1.)),
+                       t((4., 0.), (4., 4.), (3., 3.)), t((4., 0.), (3., 3.), (3., 1.)),
+                       t((4., 4.), (0., 4.), (1., 3.)), t((4., 4.), (1., 3.), (3., 3.)),
+                       t((0., 4.), (0., 0.), (1., 1.)), t((0., 4.), (1., 1.), (1., 3.))];
+        assert!(poly.triangles_cover(&correct, 1e-10));
+        // covers the 12 units of area, but partly over the hole
+        let over_hole = [t((0., 0.), (4., 0.), (4., 4.)), t((0., 0.), (4., 4.), (0., 2.))];
+        assert!(!poly.triangles_cover(&over_hole, 1e-10));
+    }
+}
@@ -0,0 +1,370 @@
+use std::cmp::Ordering;
+use num_traits::Float;
+use types::{Point, LineString, Polygon, Triangle};
+use algorithm::orient::{Orient, Direction};
+use algorithm::kernels::{cross, in_triangle};
+
+// the vertices of a ring, without consecutive duplicates or the repeated closing vertex
+fn open_ring<T>(ring: &LineString<T>) -> Vec<Point<T>>
+    where T: Float
+{
+    let mut points: Vec<Point<T>> = vec![];
+    for p in &ring.0 {
+        if points.last() != Some(p) {
+            points.push(*p);
+        }
+    }
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    points
+}
+
+// Splits the edges of each ring at the vertices of the other rings lying on them, so that where a
+// hole touches the exterior, or another hole, in the middle of an edge, the rings share a vertex.
+fn split_at_touches<T>(rings: &mut [Vec<Point<T>>])
+    where T: Float
+{
+    for i in 0..rings.len() {
+        let others: Vec<Point<T>> = rings.iter()
+            .enumerate()
+            .filter(|&(j, _)| j != i)
+            .flat_map(|(_, ring)| ring.iter().cloned())
+            .collect();
+        let ring = &rings[i];
+        let n = ring.len();
+        let mut split = vec![];
+        for idx in 0..n {
+            let (a, b) = (ring[idx], ring[(idx + 1) % n]);
+            let along = |p: &Point<T>| (p.x() - a.x()) * (b.x() - a.x()) + (p.y() - a.y()) * (b.y() - a.y());
+            let mut touches: Vec<Point<T>> = others.iter()
+                .filter(|p| cross(&a, &b, p) == T::zero() && along(p) > T::zero() && along(p) < along(&b))
+                .cloned()
+                .collect();
+            touches.sort_by(|p, q| along(p).partial_cmp(&along(q)).unwrap_or(Ordering::Equal));
+            touches.dedup();
+            split.push(a);
+            split.extend(touches);
+        }
+        rings[i] = split;
+    }
+}
+
+// the index of the rightmost vertex
+fn rightmost<T>(points: &[Point<T>]) -> usize
+    where T: Float
+{
+    (0..points.len())
+        .max_by(|&a, &b| points[a].x().partial_cmp(&points[b].x()).unwrap_or(Ordering::Equal))
+        .unwrap_or(0)
+}
+
+// true if p lies strictly within the interior angle of a counter-clockwise ring at points[idx]
+fn locally_inside<T>(points: &[Point<T>], idx: usize, p: &Point<T>) -> bool
+    where T: Float
+{
+    let n = points.len();
+    let (prev, v, next) = (&points[(idx + n - 1) % n], &points[idx], &points[(idx + 1) % n]);
+    if cross(prev, v, next) >= T::zero() {
+        cross(prev, v, p) > T::zero() && cross(v, next, p) > T::zero()
+    } else {
+        cross(prev, v, p) > T::zero() || cross(v, next, p) > T::zero()
+    }
+}
+
+// Joins a clockwise hole into a counter-clockwise outer ring, by a pair of coincident edges
+// bridging the rightmost vertex of the hole, m, to a vertex of the ring visible from it. This is
+// Eberly's method: cast a ray from m in the positive x direction, and take the nearest edge of
+// the ring it hits. The endpoint of that edge furthest along the ray is visible, unless a reflex
+// vertex of the ring lies inside the triangle formed by m, the hit and that endpoint, in which
+// case the reflex vertex at the smallest angle to the ray is. If the ring passes through that
+// vertex more than once, as where an earlier bridge or a touching hole meets it, the bridge
+// leaves from the pass whose interior angle faces m.
+fn bridge<T>(outer: &mut Vec<Point<T>>, hole: &[Point<T>])
+    where T: Float
+{
+    let m_idx = rightmost(hole);
+    let m = hole[m_idx];
+    let n = outer.len();
+    let mut hit: Option<(T, usize)> = None;
+    for i in 0..n {
+        let (a, b) = (outer[i], outer[(i + 1) % n]);
+        // only edges running upwards, with the ring's interior to their left, face m
+        if a.y() > m.y() || b.y() < m.y() || a.y() == b.y() {
+            continue;
+        }
+        let x = a.x() + (m.y() - a.y()) * (b.x() - a.x()) / (b.y() - a.y());
+        let nearer = match hit {
+            Some((nearest, _)) => x < nearest,
+            None => true,
+        };
+        if x >= m.x() && nearer {
+            hit = Some((x, i));
+        }
+    }
+    let (x, i) = match hit {
+        Some(hit) => hit,
+        // the hole doesn't lie inside the ring
+        None => return,
+    };
+    let hit = Point::new(x, m.y());
+    let (a, b) = (i, (i + 1) % n);
+    let mut visible = if outer[a] == hit {
+        a
+    } else if outer[b] == hit || outer[b].x() >= outer[a].x() {
+        b
+    } else {
+        a
+    };
+    if outer[visible] != hit {
+        let p = outer[visible];
+        let angle = |v: &Point<T>| ((v.y() - m.y()).abs() / (v.x() - m.x()), (v.x() - m.x()).hypot(v.y() - m.y()));
+        let mut best = angle(&p);
+        for idx in 0..n {
+            let v = outer[idx];
+            let reflex = cross(&outer[(idx + n - 1) % n], &v, &outer[(idx + 1) % n]) <= T::zero();
+            if idx == visible || !reflex || v.x() <= m.x() || v == p || !in_triangle(&v, &m, &hit, &p) {
+                continue;
+            }
+            let candidate = angle(&v);
+            if candidate < best {
+                best = candidate;
+                visible = idx;
+            }
+        }
+    }
+    let v = outer[visible];
+    // where the hole touches the ring at m, the bridge has no length, and leaves from the pass
+    // whose interior angle the hole lies in
+    let towards = if v == m { hole[(m_idx + 1) % hole.len()] } else { m };
+    let visible = (0..n).filter(|&idx| outer[idx] == v).find(|&idx| locally_inside(outer, idx, &towards)).unwrap_or(visible);
+    let mut joined = outer[..visible + 1].to_vec();
+    joined.extend_from_slice(&hole[m_idx..]);
+    joined.extend_from_slice(&hole[..m_idx + 1]);
+    joined.push(v);
+    joined.extend_from_slice(&outer[visible + 1..]);
+    *outer = joined;
+}
+
+// Whether the triangle (a, b, c) cut off at points[cur] is an ear: a convex vertex whose triangle
+// no other part of the ring enters. A reflex vertex inside the triangle is part of the ring
+// reaching into it. A vertex coinciding with a corner, such as either end of a bridge to a hole, or
+// a point where a hole touches the exterior, only enters it if one of its edges leaves the corner
+// into the triangle.
+fn is_ear<T>(points: &[Point<T>], prev: usize, cur: usize, next: usize) -> bool
+    where T: Float
+{
+    let n = points.len();
+    let (a, b, c) = (points[prev], points[cur], points[next]);
+    if cross(&a, &b, &c) <= T::zero() {
+        return false;
+    }
+    // the corners, each with the sides of the triangle leaving it, counter-clockwise
+    let corners = [(a, b, c), (b, c, a), (c, a, b)];
+    !(0..n).filter(|&i| i != prev && i != cur && i != next).any(|i| {
+        let (p, before, after) = (points[i], points[(i + n - 1) % n], points[(i + 1) % n]);
+        match corners.iter().find(|corner| corner.0 == p) {
+            Some(&(q, first, second)) => {
+                [before, after].iter().any(|r| cross(&q, &first, r) > T::zero() && cross(&q, r, &second) > T::zero())
+            }
+            None => in_triangle(&p, &a, &b, &c) && cross(&before, &p, &after) <= T::zero(),
+        }
+    })
+}
+
+// Drops repeated vertices from a ring, and spikes running out to a vertex and straight back,
+// neither of which encloses anything. Cutting ears from a ring whose holes touch it can leave
+// such spikes behind, and clipping them as ears would cover the same area twice.
+fn drop_spikes<T>(points: &mut Vec<Point<T>>)
+    where T: Float
+{
+    while points.len() > 2 {
+        let n = points.len();
+        let repeated = (0..n).find(|&i| points[i] == points[(i + 1) % n]);
+        if let Some(i) = repeated {
+            points.remove(i);
+            continue;
+        }
+        match (0..n).find(|&i| points[(i + n - 1) % n] == points[(i + 1) % n]) {
+            Some(i) => {
+                let next = (i + 1) % n;
+                points.remove(i.max(next));
+                points.remove(i.min(next));
+            }
+            None => return,
+        }
+    }
+}
+
+// Triangulates a counter-clockwise ring by ear clipping: repeatedly cutting off an ear.
+// Collinear vertices are dropped without adding a triangle.
+fn ear_clip<T>(mut points: Vec<Point<T>>) -> Vec<Triangle<T>>
+    where T: Float
+{
+    let mut triangles = vec![];
+    let (mut idx, mut stalled) = (0, 0);
+    drop_spikes(&mut points);
+    while points.len() > 3 {
+        let n = points.len();
+        let (prev, cur, next) = ((idx + n - 1) % n, idx % n, (idx + 1) % n);
+        let (a, b, c) = (points[prev], points[cur], points[next]);
+        let turn = cross(&a, &b, &c);
+        let is_ear = is_ear(&points, prev, cur, next);
+        if is_ear || (stalled > n && turn == T::zero()) {
+            if is_ear {
+                triangles.push(Triangle(a, b, c));
+            }
+            points.remove(cur);
+            drop_spikes(&mut points);
+            idx = if cur == 0 { 0 } else { cur - 1 };
+            stalled = 0;
+        } else if stalled > 2 * n {
+            // no ear can be found, so the ring must cross itself
+            return triangles;
+        } else {
+            idx = next;
+            stalled += 1;
+        }
+    }
+    if points.len() == 3 && cross(&points[0], &points[1], &points[2]) > T::zero() {
+        triangles.push(Triangle(points[0], points[1], points[2]));
+    }
+    triangles
+}
+
+/// Divides a geometry into triangles.
+pub trait Triangulate<T: Float> {
+    /// Returns a set of counter-clockwise triangles covering a Polygon, without overlapping
+    ///
+    /// Each hole is joined to the exterior by a bridge, and the resulting ring is triangulated
+    /// by ear clipping. The Polygon should be valid: its rings mustn't cross. Whatever the
+    /// orientation of the rings, the triangles are counter-clockwise.
+    ///
+    /// ```
+    /// use geo::Polygon;
+    /// use geo::algorithm::triangulate::Triangulate;
+    /// use geo::algorithm::triangles_cover::TrianglesCover;
+    ///
+    /// let poly = Polygon::new(vec![(0., 0.), (2., 0.), (2., 1.), (1., 1.), (1., 2.), (0., 2.), (0., 0.)].into(),
+    ///                         vec![]);
+    /// let triangles = poly.triangulate();
+    ///
+    /// assert_eq!(triangles.len(), 4);
+    /// assert!(poly.triangles_cover(&triangles, 1e-10));
+    /// ```
+    fn triangulate(&self) -> Vec<Triangle<T>>;
+
+    /// Returns the vertices of the triangles covering a Polygon, three per triangle, ready to be
+    /// uploaded to a GPU vertex buffer
+    ///
+    /// The triangles are those returned by `triangulate`, so the vertices of each one run
+    /// counter-clockwise.
+    ///
+    /// ```
+    /// use geo::Polygon;
+    /// use geo::algorithm::triangulate::Triangulate;
+    ///
+    /// let square = Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into(), vec![]);
+    /// let buffer = square.to_triangle_vertex_buffer();
+    ///
+    /// assert_eq!(buffer.len(), 6);
+    /// assert_eq!(&buffer[..3], &[[0., 1.], [0., 0.], [1., 0.]]);
+    /// ```
+    fn to_triangle_vertex_buffer(&self) -> Vec<[T; 2]>;
+}
+
+impl<T> Triangulate<T> for Polygon<T>
+    where T: Float
+{
+    fn triangulate(&self) -> Vec<Triangle<T>> {
+        let oriented = self.orient(Direction::Default);
+        let outer = open_ring(&oriented.exterior);
+        if outer.len() < 3 {
+            return vec![];
+        }
+        let mut rings = vec![outer];
+        rings.extend(oriented.interiors.iter().map(open_ring).filter(|ring| ring.len() > 2));
+        split_at_touches(&mut rings);
+        let mut outer = rings.remove(0);
+        let mut holes = rings;
+        // bridging the holes from right to left means each bridge crosses no hole yet to be joined
+        holes.sort_by(|a, b| b[rightmost(b)].x().partial_cmp(&a[rightmost(a)].x()).unwrap_or(Ordering::Equal));
+        for hole in &holes {
+            bridge(&mut outer, hole);
+        }
+        ear_clip(outer)
+    }
+
+    fn to_triangle_vertex_buffer(&self) -> Vec<[T; 2]> {
+        self.triangulate()
+            .iter()
+            .flat_map(|triangle| vec![[triangle.0.x(), triangle.0.y()], [triangle.1.x(), triangle.1.y()],
+                                      [triangle.2.x(), triangle.2.y()]])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{LineString, Polygon};
+    use algorithm::area::Area;
+    use algorithm::triangles_cover::TrianglesCover;
+    use super::*;
+
+    #[test]
+    fn triangulate_concave_test() {
+        // a comb, with three teeth, given clockwise
+        let comb = Polygon::new(vec![(0., 0.), (0., 3.), (1., 3.), (1., 1.), (2., 1.), (2., 3.), (3., 3.), (3., 1.),
+                                     (4., 1.), (4., 3.), (5., 3.), (5., 0.), (0., 0.)]
+                                    .into(),
+                                vec![]);
+        let triangles = comb.triangulate();
+        assert_eq!(triangles.len(), 10);
+        assert!(triangles.iter().all(|t| cross(&t.0, &t.1, &t.2) > 0.));
+        assert!(comb.triangles_cover(&triangles, 1e-10));
+    }
+    #[test]
+    fn triangulate_holes_test() {
+        let exterior: LineString<f64> = vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)].into();
+        let holes: Vec<LineString<f64>> = vec![vec![(2., 2.), (4., 2.), (4., 4.), (2., 4.), (2., 2.)].into(),
+                                               vec![(6., 5.), (8., 7.), (6., 8.), (6., 5.)].into(),
+                                               vec![(5., 1.), (6., 2.), (5., 3.), (5., 1.)].into()];
+        let poly = Polygon::new(exterior, holes);
+        let triangles = poly.triangulate();
+        // a ring of n vertices, after bridging h holes, gives n + 2h - 2 triangles
+        assert_eq!(triangles.len(), 4 + 4 + 3 + 3 + 3 * 2 - 2);
+        assert!(triangles.iter().all(|t| cross(&t.0, &t.1, &t.2) > 0.));
+        assert!(poly.triangles_cover(&triangles, 1e-10));
+    }
+    #[test]
+    fn vertex_buffer_test() {
+        let exterior: LineString<f64> = vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into();
+        let poly = Polygon::new(exterior, vec![vec![(1., 1.), (1., 3.), (3., 3.), (3., 1.), (1., 1.)].into()]);
+        let buffer = poly.to_triangle_vertex_buffer();
+        assert_eq!(buffer.len(), 3 * poly.triangulate().len());
+        for vertices in buffer.chunks(3) {
+            let (a, b, c) = (vertices[0], vertices[1], vertices[2]);
+            // counter-clockwise
+            assert!((b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]) > 0.);
+        }
+        assert!(Polygon::<f64>::new(LineString(vec![]), vec![]).to_triangle_vertex_buffer().is_empty());
+    }
+    #[test]
+    fn triangulate_touching_holes_test() {
+        let exterior: LineString<f64> = vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into();
+        // a hole touching the exterior at one of its vertices
+        let poly = Polygon::new(exterior.clone(), vec![vec![(0., 0.), (1., 2.), (2., 1.), (0., 0.)].into()]);
+        let triangles = poly.triangulate();
+        let area: f64 = triangles.iter().map(|triangle| triangle.area()).sum();
+        assert_relative_eq!(area, 14.5);
+        assert!(poly.triangles_cover(&triangles, 1e-10));
+        // in the middle of one of its edges, and touching another hole
+        let holes: Vec<LineString<f64>> = vec![vec![(2., 0.), (3., 1.), (1., 1.), (2., 0.)].into(),
+                                               vec![(1., 1.), (1., 2.), (0.5, 1.5), (1., 1.)].into()];
+        let poly = Polygon::new(exterior, holes);
+        let triangles = poly.triangulate();
+        let area: f64 = triangles.iter().map(|triangle| triangle.area()).sum();
+        assert_relative_eq!(area, poly.area());
+        assert!(poly.triangles_cover(&triangles, 1e-10));
+    }
+}
@@ -0,0 +1,323 @@
+use num_traits::Float;
+use types::{Point, Line, LineString, Polygon, MultiPolygon};
+use algorithm::orient::{Orient, Direction};
+use algorithm::kernels::{cross, crossed_edges, crosses, point_along};
+
+// true if p lies on the closed segment
+fn on_segment<T>(p: &Point<T>, line: &Line<T>) -> bool
+    where T: Float
+{
+    cross(&line.start, &line.end, p) == T::zero() &&
+        p.x() >= line.start.x().min(line.end.x()) && p.x() <= line.start.x().max(line.end.x()) &&
+        p.y() >= line.start.y().min(line.end.y()) && p.y() <= line.start.y().max(line.end.y())
+}
+
+// true if the segments are collinear, and share more than a single point
+fn overlaps<T>(a: &Line<T>, b: &Line<T>) -> bool
+    where T: Float
+{
+    if cross(&a.start, &a.end, &b.start) != T::zero() || cross(&a.start, &a.end, &b.end) != T::zero() {
+        return false;
+    }
+    // compare the extents of the segments along the axis `a` varies most in
+    let extent = |p: &Point<T>, q: &Point<T>| {
+        if (a.end.x() - a.start.x()).abs() >= (a.end.y() - a.start.y()).abs() {
+            (p.x().min(q.x()), p.x().max(q.x()))
+        } else {
+            (p.y().min(q.y()), p.y().max(q.y()))
+        }
+    };
+    let (min_a, max_a) = extent(&a.start, &a.end);
+    let (min_b, max_b) = extent(&b.start, &b.end);
+    min_a.max(min_b) < max_a.min(max_b)
+}
+
+// crossing number test for a point which is known not to lie on the ring
+fn inside_ring<T>(p: &Point<T>, ring: &LineString<T>) -> bool
+    where T: Float
+{
+    crossed_edges(p, ring).count() % 2 == 1
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum Position {
+    Inside,
+    OnBoundary,
+    Outside,
+}
+
+// the position of a point relative to a polygon's interior, allowing for its holes
+fn position<T>(p: &Point<T>, polygon: &Polygon<T>) -> Position
+    where T: Float
+{
+    if polygon.all_lines().any(|line| on_segment(p, &line)) {
+        Position::OnBoundary
+    } else if inside_ring(p, &polygon.exterior) && !polygon.interiors.iter().any(|ring| inside_ring(p, ring)) {
+        Position::Inside
+    } else {
+        Position::Outside
+    }
+}
+
+// true if the segments of a closed ring touch or cross each other anywhere other than at the
+// vertex shared by consecutive segments, or if consecutive segments double back over each other
+fn ring_meets_itself<T>(segments: &[Line<T>]) -> bool
+    where T: Float
+{
+    let n = segments.len();
+    segments.iter().enumerate().any(|(i, a)| {
+        segments[i + 1..].iter().enumerate().any(|(offset, b)| {
+            let j = i + 1 + offset;
+            if j == i + 1 || (i == 0 && j == n - 1) {
+                overlaps(a, b)
+            } else {
+                crosses(a, b) || on_segment(&b.start, a) || on_segment(&b.end, a) ||
+                    on_segment(&a.start, b) || on_segment(&a.end, b)
+            }
+        })
+    })
+}
+
+// A ring is valid if it's closed, has at least three distinct vertices, and doesn't touch or
+// cross itself: apart from the vertex shared by consecutive segments, no two segments meet.
+fn ring_is_valid<T>(ring: &LineString<T>) -> bool
+    where T: Float
+{
+    let points = &ring.0;
+    if points.len() < 4 || points.first() != points.last() ||
+       points.iter().any(|p| !p.x().is_finite() || !p.y().is_finite()) {
+        return false;
+    }
+    let segments: Vec<Line<T>> = ring.lines().collect();
+    segments.iter().all(|line| line.start != line.end) && !ring_meets_itself(&segments)
+}
+
+// The midpoints of the pieces of an edge between the points where the vertices of `other` lie on
+// it. In the absence of crossings, each piece lies entirely inside, on the boundary or outside of
+// `other`, so its midpoint shows which.
+fn piece_midpoints<T>(edge: &Line<T>, other: &Polygon<T>) -> Vec<Point<T>>
+    where T: Float
+{
+    let (dx, dy) = (edge.end.x() - edge.start.x(), edge.end.y() - edge.start.y());
+    let length2 = dx * dx + dy * dy;
+    let mut cuts: Vec<T> = Some(&other.exterior)
+        .into_iter()
+        .chain(other.interiors.iter())
+        .flat_map(|ring| ring.0.iter())
+        .filter(|p| on_segment(p, edge))
+        .map(|p| ((p.x() - edge.start.x()) * dx + (p.y() - edge.start.y()) * dy) / length2)
+        .collect();
+    cuts.push(T::zero());
+    cuts.push(T::one());
+    cuts.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let two = T::one() + T::one();
+    cuts.windows(2)
+        .filter(|pair| pair[0] < pair[1])
+        .map(|pair| {
+            let t = (pair[0] + pair[1]) / two;
+            point_along(edge, t)
+        })
+        .collect()
+}
+
+// The interiors of two oriented polygons overlap if their boundaries cross, if the boundary of one
+// runs through the interior of the other, or if they share a stretch of boundary with their
+// interiors on the same side of it. Both are oriented so that their interior lies to the left of
+// every edge, so the last case means the shared edges run in the same direction.
+fn interiors_overlap<T>(a: &Polygon<T>, b: &Polygon<T>) -> bool
+    where T: Float
+{
+    let (edges_a, edges_b): (Vec<Line<T>>, Vec<Line<T>>) = (a.all_lines().collect(), b.all_lines().collect());
+    let same_direction = |ea: &Line<T>, eb: &Line<T>| {
+        (ea.end.x() - ea.start.x()) * (eb.end.x() - eb.start.x()) +
+            (ea.end.y() - ea.start.y()) * (eb.end.y() - eb.start.y()) > T::zero()
+    };
+    edges_a.iter().any(|ea| edges_b.iter().any(|eb| crosses(ea, eb) || (overlaps(ea, eb) && same_direction(ea, eb)))) ||
+        edges_a.iter().any(|ea| piece_midpoints(ea, b).iter().any(|p| position(p, b) == Position::Inside)) ||
+        edges_b.iter().any(|eb| piece_midpoints(eb, a).iter().any(|p| position(p, a) == Position::Inside))
+}
+
+/// Checks whether a geometry is well-formed.
+pub trait Validity<T> {
+    /// Checks whether a geometry is valid
+    ///
+    /// A Polygon is valid if each of its rings is closed, has at least three distinct
+    /// vertices and doesn't touch or cross itself, if its rings don't cross each other, and if
+    /// its interior rings lie inside its exterior ring without lying inside each other.
+    ///
+    /// A MultiPolygon is valid if all of its members are valid, and no two members overlap.
+    /// Members may touch each other at points, or along edges.
+    ///
+    /// ```
+    /// use geo::{Polygon, MultiPolygon};
+    /// use geo::algorithm::validity::Validity;
+    ///
+    /// let a = Polygon::new(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)].into(), vec![]);
+    /// let b = Polygon::new(vec![(2., 0.), (4., 0.), (4., 2.), (2., 2.), (2., 0.)].into(), vec![]);
+    /// let c = Polygon::new(vec![(1., 1.), (3., 1.), (3., 3.), (1., 3.), (1., 1.)].into(), vec![]);
+    ///
+    /// // a and b share an edge
+    /// assert!(MultiPolygon(vec![a.clone(), b]).is_valid());
+    /// // a and c overlap
+    /// assert!(!MultiPolygon(vec![a, c]).is_valid());
+    /// ```
+    fn is_valid(&self) -> bool where T: Float;
+}
+
+impl<T> Validity<T> for Polygon<T>
+    where T: Float
+{
+    fn is_valid(&self) -> bool {
+        if !ring_is_valid(&self.exterior) || !self.interiors.iter().all(ring_is_valid) {
+            return false;
+        }
+        let exterior = Polygon::new(self.exterior.clone(), vec![]);
+        let holes: Vec<Polygon<T>> = self.interiors
+            .iter()
+            .map(|ring| Polygon::new(ring.clone(), vec![]).orient(Direction::Default))
+            .collect();
+        // interior rings only touch the exterior ring, and each other, at isolated points
+        let rings: Vec<&LineString<T>> = Some(&self.exterior).into_iter().chain(self.interiors.iter()).collect();
+        let rings_meet = rings.iter().enumerate().any(|(i, a)| {
+            rings[i + 1..].iter().any(|b| a.lines().any(|ea| b.lines().any(|eb| crosses(&ea, &eb) || overlaps(&ea, &eb))))
+        });
+        !rings_meet &&
+            self.interiors.iter().all(|ring| {
+                ring.0.iter().all(|p| position(p, &exterior) != Position::Outside)
+            }) &&
+            holes.iter().enumerate().all(|(i, a)| holes[i + 1..].iter().all(|b| !interiors_overlap(a, b)))
+    }
+}
+
+impl<T> Validity<T> for MultiPolygon<T>
+    where T: Float
+{
+    fn is_valid(&self) -> bool {
+        if !self.0.iter().all(|poly| poly.is_valid()) {
+            return false;
+        }
+        let oriented: Vec<Polygon<T>> = self.0.iter().map(|poly| poly.orient(Direction::Default)).collect();
+        oriented.iter().enumerate().all(|(i, a)| oriented[i + 1..].iter().all(|b| !interiors_overlap(a, b)))
+    }
+}
+
+/// Detects rings which fold over themselves.
+pub trait SelfOverlap<T> {
+    /// Checks whether a LineString, taken as a ring, touches, crosses or doubles back over itself
+    ///
+    /// The signed area of a ring which folds over itself, such as a bowtie, is meaningless: the
+    /// areas of the parts it winds around in opposite directions cancel out. This check reveals
+    /// such rings before their area is relied upon. Repeated consecutive vertices are ignored,
+    /// and if the ring isn't closed, it's treated as though its last vertex joined its first.
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::validity::SelfOverlap;
+    ///
+    /// let bowtie: LineString<f64> = vec![(0., 0.), (2., 2.), (2., 0.), (0., 2.), (0., 0.)].into();
+    /// let square: LineString<f64> = vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)].into();
+    ///
+    /// assert!(bowtie.ring_is_self_overlapping());
+    /// assert!(!square.ring_is_self_overlapping());
+    /// ```
+    fn ring_is_self_overlapping(&self) -> bool where T: Float;
+}
+
+impl<T> SelfOverlap<T> for LineString<T>
+    where T: Float
+{
+    fn ring_is_self_overlapping(&self) -> bool {
+        let mut segments: Vec<Line<T>> = self.lines().collect();
+        if let (Some(&first), Some(&last)) = (self.0.first(), self.0.last()) {
+            segments.push(Line::new(last, first));
+        }
+        segments.retain(|line| line.start != line.end);
+        ring_meets_itself(&segments)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{LineString, Polygon, MultiPolygon};
+    use algorithm::area::Area;
+    use algorithm::kernels::square;
+    use super::*;
+
+    #[test]
+    fn polygon_validity_test() {
+        assert!(square(0., 0., 1.).is_valid());
+        // a bowtie crosses itself
+        let bowtie = Polygon::new(vec![(0., 0.), (2., 2.), (2., 0.), (0., 2.), (0., 0.)].into(), vec![]);
+        assert!(!bowtie.is_valid());
+        // not closed
+        let open = Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.)].into(), vec![]);
+        assert!(!open.is_valid());
+        // a spike doubling back along an edge
+        let spike = Polygon::new(vec![(0., 0.), (2., 0.), (3., 0.), (2., 0.), (2., 2.), (0., 0.)].into(), vec![]);
+        assert!(!spike.is_valid());
+        let empty = Polygon::<f64>::new(LineString(vec![]), vec![]);
+        assert!(!empty.is_valid());
+    }
+    #[test]
+    fn polygon_interiors_validity_test() {
+        let exterior: LineString<f64> = vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)].into();
+        let hole = |x: f64, y: f64, size: f64| square(x, y, size).exterior;
+        assert!(Polygon::new(exterior.clone(), vec![hole(1., 1., 2.), hole(5., 5., 2.)]).is_valid());
+        // the holes may touch at a single point
+        assert!(Polygon::new(exterior.clone(), vec![hole(1., 1., 2.), hole(3., 3., 2.)]).is_valid());
+        // but not share an edge
+        assert!(!Polygon::new(exterior.clone(), vec![hole(1., 1., 2.), hole(3., 1., 2.)]).is_valid());
+        // or overlap
+        assert!(!Polygon::new(exterior.clone(), vec![hole(1., 1., 2.), hole(2., 2., 2.)]).is_valid());
+        // or nest
+        assert!(!Polygon::new(exterior.clone(), vec![hole(1., 1., 4.), hole(2., 2., 1.)]).is_valid());
+        // a hole outside the exterior
+        assert!(!Polygon::new(exterior.clone(), vec![hole(20., 20., 1.)]).is_valid());
+        // a hole crossing the exterior
+        assert!(!Polygon::new(exterior, vec![hole(9., 9., 2.)]).is_valid());
+    }
+    #[test]
+    fn multipolygon_overlap_validity_test() {
+        let a = square(0., 0., 2.);
+        // overlaps part of a
+        let overlapping = square(1., 1., 2.);
+        assert!(!MultiPolygon(vec![a.clone(), overlapping]).is_valid());
+        // shares an edge with a
+        let adjacent = square(2., 0., 2.);
+        assert!(MultiPolygon(vec![a.clone(), adjacent.clone()]).is_valid());
+        // touches a at a corner
+        let corner = square(2., 2., 1.);
+        assert!(MultiPolygon(vec![a.clone(), adjacent, corner]).is_valid());
+        // identical members overlap entirely, as does a member inside another
+        assert!(!MultiPolygon(vec![a.clone(), a.clone()]).is_valid());
+        assert!(!MultiPolygon(vec![a.clone(), square(0., 0., 1.)]).is_valid());
+        let clockwise = Polygon::new(vec![(0., 0.), (0., 1.), (1., 1.), (1., 0.), (0., 0.)].into(), vec![]);
+        assert!(!MultiPolygon(vec![a.clone(), clockwise]).is_valid());
+        // a member may sit inside another's hole
+        let ring = Polygon::new(square(0., 0., 10.).exterior, vec![square(2., 2., 4.).exterior]);
+        assert!(MultiPolygon(vec![ring.clone(), square(2., 2., 4.)]).is_valid());
+        assert!(MultiPolygon(vec![ring.clone(), square(3., 3., 1.)]).is_valid());
+        assert!(!MultiPolygon(vec![ring, square(1., 1., 2.)]).is_valid());
+        // an invalid member makes the whole collection invalid
+        let bowtie = Polygon::new(vec![(10., 10.), (12., 12.), (12., 10.), (10., 12.), (10., 10.)].into(), vec![]);
+        assert!(!MultiPolygon(vec![a, bowtie]).is_valid());
+        assert!(MultiPolygon::<f64>(vec![]).is_valid());
+    }
+    #[test]
+    fn ring_self_overlap_test() {
+        let bowtie: LineString<f64> = vec![(0., 0.), (2., 2.), (2., 0.), (0., 2.), (0., 0.)].into();
+        assert!(bowtie.ring_is_self_overlapping());
+        // and its area cancels out to nothing
+        assert_eq!(Polygon::new(bowtie, vec![]).area(), 0.);
+        assert!(!square(0., 0., 1.).exterior.ring_is_self_overlapping());
+        // folding back to touch itself at a vertex, or along an edge
+        let touching: LineString<f64> = vec![(0., 0.), (4., 0.), (4., 4.), (2., 0.), (0., 4.), (0., 0.)].into();
+        assert!(touching.ring_is_self_overlapping());
+        let spike: LineString<f64> = vec![(0., 0.), (2., 0.), (3., 0.), (2., 0.), (2., 2.), (0., 0.)].into();
+        assert!(spike.ring_is_self_overlapping());
+        // an open ring is closed implicitly, and repeated vertices are ignored
+        let open: LineString<f64> = vec![(0., 0.), (1., 0.), (1., 0.), (1., 1.), (0., 1.)].into();
+        assert!(!open.ring_is_self_overlapping());
+        assert!(!LineString::<f64>(vec![]).ring_is_self_overlapping());
+    }
+}
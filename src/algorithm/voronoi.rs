@@ -0,0 +1,151 @@
+use num_traits::Float;
+use spade::delaunay::FloatDelaunayTriangulation;
+use types::{Bbox, Point, Line, LineString, MultiPoint, Polygon};
+use algorithm::kernels::point_along;
+
+// Clips a convex ring, without its closing vertex, to the half-plane of points at least as close
+// to site as to neighbour, bounded by their perpendicular bisector.
+fn clip_to_bisector<T>(ring: &[Point<T>], site: &Point<T>, neighbour: &Point<T>) -> Vec<Point<T>>
+    where T: Float
+{
+    let two = T::one() + T::one();
+    let (nx, ny) = (neighbour.x() - site.x(), neighbour.y() - site.y());
+    let (mx, my) = ((site.x() + neighbour.x()) / two, (site.y() + neighbour.y()) / two);
+    // positive beyond the bisector, on the neighbour's side
+    let side = |p: &Point<T>| (p.x() - mx) * nx + (p.y() - my) * ny;
+    let mut clipped = vec![];
+    for (idx, a) in ring.iter().enumerate() {
+        let b = ring[(idx + 1) % ring.len()];
+        let (sa, sb) = (side(a), side(&b));
+        if sa <= T::zero() {
+            clipped.push(*a);
+        }
+        if (sa < T::zero() && sb > T::zero()) || (sa > T::zero() && sb < T::zero()) {
+            let t = sa / (sa - sb);
+            clipped.push(point_along(&Line::new(*a, b), t));
+        }
+    }
+    clipped
+}
+
+/// Divides the plane into the regions closest to each of a set of sites.
+pub trait Voronoi<T: Float> {
+    /// Returns the Voronoi cell of each Point of a MultiPoint, clipped to a bounding box
+    ///
+    /// The cell of a site is the region closer to it than to any other site. The neighbouring
+    /// sites, whose cells share an edge with it, are found by a Delaunay triangulation, and the
+    /// cell is the part of `bounds` on the site's side of the perpendicular bisectors between
+    /// it and each of them. The triangulation is computed in `f64`. The cells are returned in
+    /// the order of the sites, as counter-clockwise Polygons without holes; repeated sites share
+    /// the same cell. A site whose cell lies entirely outside `bounds` gets a Polygon with an
+    /// empty exterior.
+    ///
+    /// ```
+    /// use geo::{Bbox, Point, MultiPoint};
+    /// use geo::algorithm::voronoi::Voronoi;
+    /// use geo::algorithm::contains::Contains;
+    ///
+    /// let sites = MultiPoint(vec![Point::new(1., 1.), Point::new(3., 1.)]);
+    /// let cells = sites.voronoi(&Bbox { xmin: 0., xmax: 4., ymin: 0., ymax: 2. });
+    ///
+    /// assert_eq!(cells.len(), 2);
+    /// assert!(cells[0].contains(&Point::new(1.5, 0.5)));
+    /// assert!(cells[1].contains(&Point::new(2.5, 0.5)));
+    /// ```
+    fn voronoi(&self, bounds: &Bbox<T>) -> Vec<Polygon<T>>;
+}
+
+impl<T> Voronoi<T> for MultiPoint<T>
+    where T: Float
+{
+    fn voronoi(&self, bounds: &Bbox<T>) -> Vec<Polygon<T>> {
+        let mut delaunay = FloatDelaunayTriangulation::with_walk_locate();
+        // the position of each vertex of the triangulation, by its handle: a repeated site is
+        // given the handle of the vertex already there
+        let mut positions: Vec<Point<T>> = vec![];
+        let mut handles = vec![];
+        for p in &self.0 {
+            let handle = delaunay.insert([p.x().to_f64().unwrap(), p.y().to_f64().unwrap()]);
+            if handle == positions.len() {
+                positions.push(*p);
+            }
+            handles.push(handle);
+        }
+        let corners = vec![Point::new(bounds.xmin, bounds.ymin),
+                           Point::new(bounds.xmax, bounds.ymin),
+                           Point::new(bounds.xmax, bounds.ymax),
+                           Point::new(bounds.xmin, bounds.ymax)];
+        handles.iter()
+            .map(|&handle| {
+                let mut ring = corners.clone();
+                for edge in delaunay.vertex(handle).ccw_out_edges() {
+                    ring = clip_to_bisector(&ring, &positions[handle], &positions[edge.to().fix()]);
+                    if ring.is_empty() {
+                        break;
+                    }
+                }
+                if let Some(&first) = ring.first() {
+                    ring.push(first);
+                }
+                Polygon::new(LineString(ring), vec![])
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Bbox, Point, MultiPoint};
+    use algorithm::area::Area;
+    use algorithm::contains::Contains;
+    use algorithm::distance::Distance;
+    use super::*;
+
+    #[test]
+    fn voronoi_quadrants_test() {
+        let sites = MultiPoint(vec![Point::new(1., 1.), Point::new(3., 1.), Point::new(3., 3.), Point::new(1., 3.)]);
+        let bounds = Bbox { xmin: 0., xmax: 4., ymin: 0., ymax: 4. };
+        let cells = sites.voronoi(&bounds);
+        assert_eq!(cells.len(), 4);
+        for (cell, site) in cells.iter().zip(&sites.0) {
+            assert_relative_eq!(cell.area(), 4.);
+            assert!(cell.contains(site));
+            // every quadrant has a corner at the centre
+            assert!(cell.exterior.0.contains(&Point::new(2., 2.)));
+        }
+    }
+    #[test]
+    fn voronoi_irregular_test() {
+        let sites = MultiPoint(vec![Point::new(0.5, 0.5), Point::new(4., 1.), Point::new(2., 3.5),
+                                    Point::new(3.2, 2.), Point::new(1., 2.5)]);
+        let bounds = Bbox { xmin: 0., xmax: 5., ymin: 0., ymax: 4. };
+        let cells = sites.voronoi(&bounds);
+        // the cells tile the bounds
+        let total = cells.iter().fold(0., |total, cell| total + cell.area());
+        assert_relative_eq!(total, 20., epsilon = 1e-10);
+        // and every sample lies in the cell of its nearest site
+        for &(x, y) in &[(0.1, 3.9), (4.9, 3.9), (2.5, 0.2), (2.2, 2.2), (3.9, 2.8)] {
+            let p = Point::new(x, y);
+            let nearest = (0..sites.0.len())
+                .min_by(|&a, &b| p.distance(&sites.0[a]).partial_cmp(&p.distance(&sites.0[b])).unwrap())
+                .unwrap();
+            assert!(cells[nearest].contains(&p));
+        }
+    }
+    #[test]
+    fn voronoi_degenerate_test() {
+        let bounds = Bbox { xmin: 0., xmax: 4., ymin: 0., ymax: 2. };
+        // a single site gets the whole of the bounds
+        let single = MultiPoint(vec![Point::new(1., 1.)]).voronoi(&bounds);
+        assert_relative_eq!(single[0].area(), 8.);
+        // collinear sites divide it into strips
+        let collinear = MultiPoint(vec![Point::new(0.5, 1.), Point::new(1.5, 1.), Point::new(3.5, 1.)]).voronoi(&bounds);
+        let areas: Vec<f64> = collinear.iter().map(|cell| cell.area()).collect();
+        assert_eq!(areas, vec![2., 3., 3.]);
+        // a site far outside the bounds gets an empty cell
+        let outside = MultiPoint(vec![Point::new(1., 1.), Point::new(100., 1.), Point::new(1., 1.)]).voronoi(&bounds);
+        assert!(outside[1].exterior.0.is_empty());
+        assert_eq!(outside[0], outside[2]);
+        assert!(MultiPoint::<f64>(vec![]).voronoi(&bounds).is_empty());
+    }
+}
@@ -19,23 +19,64 @@ extern crate approx;
 /// A prelude which re-exports the traits for manipulating objects in this
 /// crate. Typically imported with `use geo::prelude::*`.
 pub mod prelude {
-    pub use algorithm::area::Area;
-    pub use algorithm::boundingbox::BoundingBox;
-    pub use algorithm::centroid::Centroid;
-    pub use algorithm::contains::Contains;
+    pub use algorithm::affine::Transform;
+    pub use algorithm::area::{Area, Winding};
+    pub use algorithm::bearing::Bearing;
+    pub use algorithm::boolean::{Difference, Intersection, SymmetricDifference, Union};
+    pub use algorithm::boundary::Boundary;
+    pub use algorithm::boundingbox::{BoundingBox, BboxCenter, CoordMinMax};
+    pub use algorithm::bytes::ToBytes;
+    pub use algorithm::canonicalize::{Canonicalize, Normalized};
+    pub use algorithm::centroid::{Centroid, WeightedCentroid};
+    pub use algorithm::clip::{ClipToBbox, ClipToPolygon};
+    pub use algorithm::closest_pair::ClosestPair;
+    pub use algorithm::closest_point::ClosestPoint;
+    pub use algorithm::contains::{Contains, ContainsAndDistance, ContainsBoundary, ContainsWithin};
+    pub use algorithm::convex_trim::ConvexTrim;
     pub use algorithm::convexhull::ConvexHull;
-    pub use algorithm::distance::Distance;
+    pub use algorithm::count_crossings::CountCrossings;
+    pub use algorithm::dbscan::Dbscan;
+    pub use algorithm::delaunay::DelaunayTriangulation;
+    pub use algorithm::densify::Densify;
+    pub use algorithm::distance::{Distance, DistanceMetric, VerticesWithin};
     pub use algorithm::extremes::ExtremePoints;
+    pub use algorithm::fill_rule::FillRule;
+    pub use algorithm::geographic_centroid::GeographicCentroid;
+    pub use algorithm::haversine_buffer::HaversineBuffer;
+    pub use algorithm::haversine_densify::HaversinePointsEvery;
     pub use algorithm::haversine_destination::HaversineDestination;
     pub use algorithm::haversine_distance::HaversineDistance;
+    pub use algorithm::indexed::ContainsFast;
+    pub use algorithm::inscribed_rect::InscribedRect;
     pub use algorithm::intersects::Intersects;
-    pub use algorithm::length::Length;
+    pub use algorithm::largest::Largest;
+    pub use algorithm::length::{Length, Perimeter};
+    pub use algorithm::lerp::Lerp;
+    pub use algorithm::line_interpolate_point::LineInterpolatePoint;
+    pub use algorithm::line_locate_point::LineLocatePoint;
     pub use algorithm::map_coords::MapCoords;
-    pub use algorithm::orient::Orient;
+    pub use algorithm::monotone::SplitIntoMonotone;
+    pub use algorithm::node::Node;
+    pub use algorithm::orient::{Orient, ReverseWinding};
+    pub use algorithm::project::ProjectOnto;
+    pub use algorithm::remove_collinear::RemoveCollinear;
+    pub use algorithm::remove_slivers::{RemoveSlivers, RemoveSmallHoles};
+    pub use algorithm::representative_point::RepresentativePoint;
     pub use algorithm::rotate::{Rotate, RotatePoint};
-    pub use algorithm::simplify::Simplify;
+    pub use algorithm::round_corners::RoundCorners;
+    pub use algorithm::sample_grid::SampleGrid;
+    pub use algorithm::scale::Scale;
+    pub use algorithm::simplify::{Simplify, SimplifyAdaptive, SimplifyKeepSpikes, SimplifyKeeping};
     pub use algorithm::simplifyvw::SimplifyVW;
-    pub use algorithm::translate::Translate;
+    pub use algorithm::snap_to_grid::SnapToGrid;
+    pub use algorithm::snap_vertices::SnapVerticesTo;
+    pub use algorithm::spherical_excess::SphericalExcess;
+    pub use algorithm::to_f32::ToF32;
+    pub use algorithm::translate::{CenterOnOrigin, Translate};
+    pub use algorithm::triangles_cover::TrianglesCover;
+    pub use algorithm::triangulate::Triangulate;
+    pub use algorithm::validity::{SelfOverlap, Validity};
+    pub use algorithm::voronoi::Voronoi;
 
 
 }
\ No newline at end of file
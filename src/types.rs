@@ -4,6 +4,7 @@ use std::ops::Neg;
 use std::ops::Sub;
 
 use std::fmt::Debug;
+use std::mem;
 
 use std::iter::{self, Iterator, FromIterator};
 
@@ -11,6 +12,8 @@ use num_traits::{Float, ToPrimitive};
 use spade::SpadeNum;
 use spade::PointN;
 
+use algorithm::kernels::point_along;
+
 pub static COORD_PRECISION: f32 = 1e-1; // 0.1m
 
 #[derive(PartialEq, Clone, Copy, Debug, Serialize, Deserialize)]
@@ -27,6 +30,28 @@ impl<T: Float> From<(T, T)> for Coordinate<T> {
     }
 }
 
+impl<T: Float> From<[T; 2]> for Coordinate<T> {
+    fn from(coords: [T; 2]) -> Self {
+        Coordinate{ x: coords[0], y: coords[1] }
+    }
+}
+
+// The orphan rules forbid implementing `From<Coordinate<T>>` for the foreign types `(T, T)` and
+// `[T; 2]`, so `Into` is implemented directly.
+#[allow(clippy::from_over_into)]
+impl<T: Float> Into<(T, T)> for Coordinate<T> {
+    fn into(self) -> (T, T) {
+        (self.x, self.y)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<T: Float> Into<[T; 2]> for Coordinate<T> {
+    fn into(self) -> [T; 2] {
+        [self.x, self.y]
+    }
+}
+
 #[derive(PartialEq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct Bbox<T>
     where T: Float
@@ -37,6 +62,57 @@ pub struct Bbox<T>
     pub ymax: T,
 }
 
+impl<T> Bbox<T>
+    where T: Float
+{
+    /// Creates the smallest Bbox containing all of the Points, or `None` if there aren't any.
+    ///
+    /// ```
+    /// use geo::{Bbox, Point};
+    ///
+    /// let bbox = Bbox::from_points(vec![Point::new(1., 5.), Point::new(-2., 3.)]).unwrap();
+    /// assert_eq!(bbox, Bbox { xmin: -2., xmax: 1., ymin: 3., ymax: 5. });
+    /// assert_eq!(Bbox::<f64>::from_points(vec![]), None);
+    /// ```
+    pub fn from_points<I: IntoIterator<Item = Point<T>>>(points: I) -> Option<Bbox<T>> {
+        let mut iter = points.into_iter();
+        let first = iter.next()?;
+        Some(iter.fold(Bbox { xmin: first.x(), xmax: first.x(), ymin: first.y(), ymax: first.y() },
+                       |bbox, p| Bbox {
+                           xmin: bbox.xmin.min(p.x()),
+                           xmax: bbox.xmax.max(p.x()),
+                           ymin: bbox.ymin.min(p.y()),
+                           ymax: bbox.ymax.max(p.y()),
+                       }))
+    }
+
+    /// Expands the Bbox outward by `amount` on every side, or shrinks it if `amount` is
+    /// negative.
+    ///
+    /// A side which would be shrunk past its opposite stops at the centre instead, so the Bbox
+    /// collapses to a line or a point rather than inverting.
+    ///
+    /// ```
+    /// use geo::Bbox;
+    ///
+    /// let bbox = Bbox { xmin: 0., xmax: 10., ymin: 0., ymax: 4. };
+    /// assert_eq!(bbox.pad(1.), Bbox { xmin: -1., xmax: 11., ymin: -1., ymax: 5. });
+    /// assert_eq!(bbox.pad(-3.), Bbox { xmin: 3., xmax: 7., ymin: 2., ymax: 2. });
+    /// ```
+    pub fn pad(&self, amount: T) -> Bbox<T> {
+        let two = T::one() + T::one();
+        let side = |min: T, max: T| if max - min + two * amount < T::zero() {
+            let centre = (min + max) / two;
+            (centre, centre)
+        } else {
+            (min - amount, max + amount)
+        };
+        let (xmin, xmax) = side(self.xmin, self.xmax);
+        let (ymin, ymax) = side(self.ymin, self.ymax);
+        Bbox { xmin, xmax, ymin, ymax }
+    }
+}
+
 #[derive(PartialEq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct Extremes {
     pub ymin: usize,
@@ -91,6 +167,26 @@ impl<T: Float> From<(T, T)> for Point<T> {
     }
 }
 
+impl<T: Float> From<[T; 2]> for Point<T> {
+    fn from(coords: [T; 2]) -> Point<T> {
+        Point::new(coords[0], coords[1])
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<T: Float> Into<(T, T)> for Point<T> {
+    fn into(self) -> (T, T) {
+        self.0.into()
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<T: Float> Into<[T; 2]> for Point<T> {
+    fn into(self) -> [T; 2] {
+        self.0.into()
+    }
+}
+
 impl<T> Point<T>
     where T: Float + ToPrimitive
 {
@@ -414,6 +510,23 @@ impl<T: Float, IP: Into<Point<T>>> FromIterator<IP> for MultiPoint<T> {
     }
 }
 
+impl<T: Float> MultiPoint<T> {
+    /// Releases the spare capacity of the `Vec` of Points.
+    ///
+    /// ```
+    /// use geo::{Point, MultiPoint};
+    ///
+    /// let mut points = MultiPoint(Vec::with_capacity(10));
+    /// points.0.push(Point::new(0., 0.));
+    /// points.shrink_to_fit();
+    ///
+    /// assert_eq!(points.0.capacity(), 1);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+}
+
 /// Iterate over the `Point`s in this `MultiPoint`.
 impl<T: Float> IntoIterator for MultiPoint<T> {
     type Item = Point<T>;
@@ -448,8 +561,95 @@ impl<T> Line<T>
     pub fn new(start: Point<T>, end: Point<T>) -> Line<T> {
         Line {start: start, end: end}
     }
+
+    /// Returns the foot of the perpendicular from a Point to the infinite line through this
+    /// segment.
+    ///
+    /// Unlike the distance to the segment, the result isn't clamped to lie between `start` and
+    /// `end`. If the segment has zero length, `start` is returned.
+    ///
+    /// ```
+    /// use geo::{Point, Line};
+    ///
+    /// let line = Line::new(Point::new(0., 0.), Point::new(2., 0.));
+    ///
+    /// assert_eq!(line.perpendicular_foot(&Point::new(1., 3.)), Point::new(1., 0.));
+    /// assert_eq!(line.perpendicular_foot(&Point::new(5., -1.)), Point::new(5., 0.));
+    /// ```
+    pub fn perpendicular_foot(&self, p: &Point<T>) -> Point<T> {
+        let dx = self.end.x() - self.start.x();
+        let dy = self.end.y() - self.start.y();
+        let length2 = dx * dx + dy * dy;
+        if length2 == T::zero() {
+            return self.start;
+        }
+        point_along(self, ((p.x() - self.start.x()) * dx + (p.y() - self.start.y()) * dy) / length2)
+    }
+
+    /// Returns the pair of points, the first on this segment and the second on the other, which
+    /// are closest to each other.
+    ///
+    /// The distance between them is the distance between the segments. If the segments cross or
+    /// touch, both points are the same point where they meet; if they overlap, any closest pair
+    /// may be returned.
+    ///
+    /// ```
+    /// use geo::{Point, Line};
+    ///
+    /// let first = Line::new(Point::new(0., 0.), Point::new(2., 0.));
+    /// let second = Line::new(Point::new(1., 1.), Point::new(3., 3.));
+    ///
+    /// assert_eq!(first.nearest_points(&second), (Point::new(1., 0.), Point::new(1., 1.)));
+    /// ```
+    pub fn nearest_points(&self, other: &Line<T>) -> (Point<T>, Point<T>) {
+        let (dx1, dy1) = (self.end.x() - self.start.x(), self.end.y() - self.start.y());
+        let (dx2, dy2) = (other.end.x() - other.start.x(), other.end.y() - other.start.y());
+        let (ox, oy) = (other.start.x() - self.start.x(), other.start.y() - self.start.y());
+        let denom = dx1 * dy2 - dy1 * dx2;
+        if denom != T::zero() {
+            let t = (ox * dy2 - oy * dx2) / denom;
+            let u = (ox * dy1 - oy * dx1) / denom;
+            if t >= T::zero() && t <= T::one() && u >= T::zero() && u <= T::one() {
+                let p = point_along(self, t);
+                return (p, p);
+            }
+        }
+        // otherwise, one of the points is an endpoint, and the other its nearest point on the
+        // other segment
+        let nearest = |line: &Line<T>, p: &Point<T>| {
+            let foot = line.perpendicular_foot(p);
+            let (dx, dy) = (line.end.x() - line.start.x(), line.end.y() - line.start.y());
+            let r = (foot.x() - line.start.x()) * dx + (foot.y() - line.start.y()) * dy;
+            if r <= T::zero() {
+                line.start
+            } else if r >= dx * dx + dy * dy {
+                line.end
+            } else {
+                foot
+            }
+        };
+        let candidates = [(self.start, nearest(other, &self.start)),
+                          (self.end, nearest(other, &self.end)),
+                          (nearest(self, &other.start), other.start),
+                          (nearest(self, &other.end), other.end)];
+        let distance = |pair: &(Point<T>, Point<T>)| (pair.0.x() - pair.1.x()).hypot(pair.0.y() - pair.1.y());
+        candidates.iter().skip(1).fold(candidates[0], |best, pair| {
+            if distance(pair) < distance(&best) { *pair } else { best }
+        })
+    }
 }
 
+/// A triangle, defined by its three vertices.
+///
+/// ```
+/// use geo::{Point, Triangle};
+///
+/// let triangle = Triangle(Point::new(0., 0.), Point::new(1., 0.), Point::new(0., 1.));
+/// assert_eq!(triangle.0, Point::new(0., 0.));
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Triangle<T>(pub Point<T>, pub Point<T>, pub Point<T>) where T: Float;
+
 /// A LineString, which is an ordered collection of [`Point`s](struct.Point.html).
 ///
 /// Create a LineString by calling it directly:
@@ -520,6 +720,58 @@ impl<T: Float> LineString<T> {
             Line::new(*w.get_unchecked(0), *w.get_unchecked(1))
         }))
     }
+
+    /// Returns the sum of the signed angles, in radians, by which the direction of the LineString
+    /// turns at each of its interior vertices; counter-clockwise turns are positive.
+    ///
+    /// If the LineString is closed, the turn at its closing vertex is included too, so a simple
+    /// convex ring gives 2π counter-clockwise, or -2π clockwise. Zero-length segments are
+    /// skipped, and a straight LineString gives 0.
+    ///
+    /// ```
+    /// # extern crate geo;
+    /// # #[macro_use] extern crate approx;
+    /// # fn main() {
+    /// use std::f64::consts::PI;
+    /// use geo::LineString;
+    ///
+    /// let hook: LineString<f64> = vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.)].into();
+    ///
+    /// assert_relative_eq!(hook.total_turning_angle(), PI);
+    /// # }
+    /// ```
+    pub fn total_turning_angle(&self) -> T {
+        let mut directions: Vec<(T, T)> = self.lines()
+            .map(|line| (line.end.x() - line.start.x(), line.end.y() - line.start.y()))
+            .filter(|&(dx, dy)| dx != T::zero() || dy != T::zero())
+            .collect();
+        if self.0.len() > 1 && self.0.first() == self.0.last() && !directions.is_empty() {
+            let first = directions[0];
+            directions.push(first);
+        }
+        directions.windows(2)
+            .map(|w| {
+                let ((x1, y1), (x2, y2)) = (w[0], w[1]);
+                (x1 * y2 - y1 * x2).atan2(x1 * x2 + y1 * y2)
+            })
+            .fold(T::zero(), |total, angle| total + angle)
+    }
+
+    /// Releases the spare capacity of the `Vec` of Points, which may be left behind after many
+    /// edits, to reduce the memory held by long-lived geometries.
+    ///
+    /// ```
+    /// use geo::{LineString, Point};
+    ///
+    /// let mut line: LineString<f64> = vec![(0., 0.), (5., 0.), (7., 9.)].into();
+    /// line.0.reserve(100);
+    /// line.shrink_to_fit();
+    ///
+    /// assert_eq!(line.0.capacity(), 3);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
 }
 
 /// Turn a `Vec` of `Point`-ish objects into a `LineString`.
@@ -554,6 +806,16 @@ impl<T: Float> IntoIterator for LineString<T> {
 #[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub struct MultiLineString<T>(pub Vec<LineString<T>>) where T: Float;
 
+impl<T: Float> MultiLineString<T> {
+    /// Releases the spare capacity of the `Vec` of LineStrings, and of each LineString.
+    pub fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+        for ls in &mut self.0 {
+            ls.shrink_to_fit();
+        }
+    }
+}
+
 impl<T: Float, ILS: Into<LineString<T>>> From<ILS> for MultiLineString<T> {
     fn from(ls: ILS) -> Self {
         MultiLineString(vec![ls.into()])
@@ -605,6 +867,46 @@ impl<T> Polygon<T>
     pub fn new(exterior: LineString<T>, interiors: Vec<LineString<T>>) -> Polygon<T> {
         Polygon { exterior: exterior, interiors: interiors }
     }
+
+    /// Return an iterator yielding one `Line` for each segment of the exterior ring.
+    ///
+    /// ```
+    /// use geo::{Line, Point, Polygon};
+    ///
+    /// let poly = Polygon::new(vec![(0., 0.), (1., 0.), (0., 1.), (0., 0.)].into(), vec![]);
+    /// let mut lines = poly.exterior_lines();
+    ///
+    /// assert_eq!(lines.next(), Some(Line::new(Point::new(0., 0.), Point::new(1., 0.))));
+    /// assert_eq!(lines.count(), 2);
+    /// ```
+    pub fn exterior_lines(&self) -> impl Iterator<Item = Line<T>> + '_ {
+        self.exterior.lines()
+    }
+
+    /// Return an iterator yielding one `Line` for each segment of the exterior ring, followed by
+    /// the segments of each interior ring in turn.
+    ///
+    /// ```
+    /// use geo::Polygon;
+    ///
+    /// let poly = Polygon::new(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into(),
+    ///                         vec![vec![(1., 1.), (2., 1.), (1., 2.), (1., 1.)].into()]);
+    ///
+    /// assert_eq!(poly.all_lines().count(), 7);
+    /// ```
+    pub fn all_lines(&self) -> impl Iterator<Item = Line<T>> + '_ {
+        self.exterior_lines().chain(self.interiors.iter().flat_map(|ring| ring.lines()))
+    }
+
+    /// Releases the spare capacity of the exterior, of the `Vec` of interiors, and of each
+    /// interior.
+    pub fn shrink_to_fit(&mut self) {
+        self.exterior.shrink_to_fit();
+        self.interiors.shrink_to_fit();
+        for ring in &mut self.interiors {
+            ring.shrink_to_fit();
+        }
+    }
 }
 
 /// A collection of [`Polygon`s](struct.Polygon.html).
@@ -615,6 +917,16 @@ impl<T> Polygon<T>
 #[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub struct MultiPolygon<T>(pub Vec<Polygon<T>>) where T: Float;
 
+impl<T: Float> MultiPolygon<T> {
+    /// Releases the spare capacity of the `Vec` of Polygons, and of each Polygon's rings.
+    pub fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+        for poly in &mut self.0 {
+            poly.shrink_to_fit();
+        }
+    }
+}
+
 impl<T: Float, IP: Into<Polygon<T>>> From<IP> for MultiPolygon<T> {
     fn from(x: IP) -> Self {
         MultiPolygon(vec![x.into()])
@@ -689,6 +1001,93 @@ impl<T: Float> From<MultiPoint<T>> for Geometry<T> { fn from(x: MultiPoint<T>) -
 impl<T: Float> From<MultiLineString<T>> for Geometry<T> { fn from(x: MultiLineString<T>) -> Geometry<T> { Geometry::MultiLineString(x) } }
 impl<T: Float> From<MultiPolygon<T>> for Geometry<T> { fn from(x: MultiPolygon<T>) -> Geometry<T> { Geometry::MultiPolygon(x) } }
 
+// the number of coordinates in the rings of a Polygon
+fn polygon_coord_count<T>(poly: &Polygon<T>) -> usize
+    where T: Float
+{
+    poly.exterior.0.len() + poly.interiors.iter().map(|ring| ring.0.len()).sum::<usize>()
+}
+
+// the bytes allocated on the heap for the Points of a LineString
+fn linestring_heap_bytes<T>(ls: &LineString<T>) -> usize
+    where T: Float
+{
+    ls.0.capacity() * mem::size_of::<Point<T>>()
+}
+
+// the bytes allocated on the heap for the rings of a Polygon
+fn polygon_heap_bytes<T>(poly: &Polygon<T>) -> usize
+    where T: Float
+{
+    linestring_heap_bytes(&poly.exterior) + poly.interiors.capacity() * mem::size_of::<LineString<T>>() +
+        poly.interiors.iter().map(linestring_heap_bytes).sum::<usize>()
+}
+
+impl<T> Geometry<T>
+    where T: Float
+{
+    /// Returns the number of coordinates in the geometry, counting each ring's closing vertex
+    /// and any repeated vertices.
+    ///
+    /// ```
+    /// use geo::{Geometry, Polygon};
+    ///
+    /// let square = Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into(), vec![]);
+    ///
+    /// assert_eq!(Geometry::Polygon(square).coord_count(), 5);
+    /// ```
+    pub fn coord_count(&self) -> usize {
+        match *self {
+            Geometry::Point(_) => 1,
+            Geometry::LineString(ref ls) => ls.0.len(),
+            Geometry::Polygon(ref poly) => polygon_coord_count(poly),
+            Geometry::MultiPoint(ref mp) => mp.0.len(),
+            Geometry::MultiLineString(ref mls) => mls.0.iter().map(|ls| ls.0.len()).sum(),
+            Geometry::MultiPolygon(ref mp) => mp.0.iter().map(polygon_coord_count).sum(),
+            Geometry::GeometryCollection(ref gc) => gc.0.iter().map(|g| g.coord_count()).sum(),
+        }
+    }
+
+    /// Returns an estimate of the memory held by the geometry, in bytes.
+    ///
+    /// This is the size of the `Geometry` itself, plus the heap space allocated by each of its
+    /// nested `Vec`s, counting their spare capacity. It doesn't include the allocator's own
+    /// bookkeeping.
+    ///
+    /// ```
+    /// use std::mem;
+    /// use geo::{Geometry, Point, LineString};
+    ///
+    /// let mut line: LineString<f64> = vec![(0., 0.), (5., 0.), (7., 9.)].into();
+    /// line.shrink_to_fit();
+    /// let size = Geometry::LineString(line).size_hint_bytes();
+    ///
+    /// assert_eq!(size, mem::size_of::<Geometry<f64>>() + 3 * mem::size_of::<Point<f64>>());
+    /// ```
+    pub fn size_hint_bytes(&self) -> usize {
+        mem::size_of::<Geometry<T>>() + self.heap_bytes()
+    }
+
+    // the bytes allocated on the heap by the geometry's Vecs
+    fn heap_bytes(&self) -> usize {
+        match *self {
+            Geometry::Point(_) => 0,
+            Geometry::LineString(ref ls) => linestring_heap_bytes(ls),
+            Geometry::Polygon(ref poly) => polygon_heap_bytes(poly),
+            Geometry::MultiPoint(ref mp) => mp.0.capacity() * mem::size_of::<Point<T>>(),
+            Geometry::MultiLineString(ref mls) => {
+                mls.0.capacity() * mem::size_of::<LineString<T>>() + mls.0.iter().map(linestring_heap_bytes).sum::<usize>()
+            }
+            Geometry::MultiPolygon(ref mp) => {
+                mp.0.capacity() * mem::size_of::<Polygon<T>>() + mp.0.iter().map(polygon_heap_bytes).sum::<usize>()
+            }
+            Geometry::GeometryCollection(ref gc) => {
+                gc.0.capacity() * mem::size_of::<Geometry<T>>() + gc.0.iter().map(|g| g.heap_bytes()).sum::<usize>()
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use ::types::*;
@@ -733,4 +1132,155 @@ mod test {
         let _: LineString<_> = vec![(0., 0.), (1., 2.)].into();
         let _: LineString<_> = vec![(0., 0.), (1., 2.)].into_iter().collect();
     }
+
+    #[test]
+    fn perpendicular_foot_test() {
+        let line = Line::new(Point::new(-1., 2.), Point::new(3., 2.));
+        // directly above the horizontal line
+        assert_eq!(line.perpendicular_foot(&Point::new(1.5, 7.)), Point::new(1.5, 2.));
+        // beyond the end of the segment
+        assert_eq!(line.perpendicular_foot(&Point::new(-4., 0.)), Point::new(-4., 2.));
+        let diagonal = Line::new(Point::new(0., 0.), Point::new(1., 1.));
+        assert_eq!(diagonal.perpendicular_foot(&Point::new(0., 2.)), Point::new(1., 1.));
+        let degenerate = Line::new(Point::new(1., 1.), Point::new(1., 1.));
+        assert_eq!(degenerate.perpendicular_foot(&Point::new(3., 0.)), Point::new(1., 1.));
+    }
+
+    #[test]
+    fn polygon_lines_test() {
+        let exterior: LineString<f64> = vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into();
+        let hole: LineString<f64> = vec![(1., 1.), (3., 1.), (2., 3.), (1., 1.)].into();
+        let poly = Polygon::new(exterior, vec![hole]);
+        assert_eq!(poly.exterior_lines().count(), 4);
+        assert_eq!(poly.all_lines().count(), 7);
+        // the interior ring's segments follow those of the exterior
+        let lines: Vec<Line<f64>> = poly.all_lines().collect();
+        assert_eq!(lines[3], Line::new(Point::new(0., 4.), Point::new(0., 0.)));
+        assert_eq!(lines[4], Line::new(Point::new(1., 1.), Point::new(3., 1.)));
+        let empty = Polygon::<f64>::new(LineString(vec![]), vec![]);
+        assert_eq!(empty.all_lines().count(), 0);
+    }
+
+    #[test]
+    fn bbox_from_points_test() {
+        let corners = vec![Point::new(4., 1.), Point::new(-1., 1.), Point::new(-1., 3.), Point::new(4., 3.)];
+        let bbox = Bbox::from_points(corners).unwrap();
+        assert_eq!(bbox.xmin, -1.);
+        assert_eq!(bbox.xmax, 4.);
+        assert_eq!(bbox.ymin, 1.);
+        assert_eq!(bbox.ymax, 3.);
+        assert!(Bbox::<f64>::from_points(vec![]).is_none());
+    }
+
+    #[test]
+    fn bbox_pad_test() {
+        let bbox = Bbox { xmin: 0., xmax: 10., ymin: 0., ymax: 10. };
+        let padded = bbox.pad(1.);
+        assert_eq!(padded, Bbox { xmin: -1., xmax: 11., ymin: -1., ymax: 11. });
+        assert_eq!((padded.xmax - padded.xmin, padded.ymax - padded.ymin), (12., 12.));
+        assert_eq!(padded.pad(-1.), bbox);
+        // shrinking by more than half the width would invert the box
+        let narrow = Bbox { xmin: 0., xmax: 2., ymin: 0., ymax: 10. };
+        assert_eq!(narrow.pad(-2.), Bbox { xmin: 1., xmax: 1., ymin: 2., ymax: 8. });
+        assert_eq!(narrow.pad(-6.), Bbox { xmin: 1., xmax: 1., ymin: 5., ymax: 5. });
+    }
+
+    #[test]
+    fn geometry_size_test() {
+        use std::mem;
+
+        let exterior: LineString<f64> = vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into();
+        let hole: LineString<f64> = vec![(1., 1.), (1., 2.), (2., 2.), (1., 1.)].into();
+        let mut poly = Polygon::new(exterior, vec![hole]);
+        poly.shrink_to_fit();
+        let geometry = Geometry::Polygon(poly.clone());
+        assert_eq!(geometry.coord_count(), 9);
+        // the Geometry, 9 coordinates of two f64s, and the header of the hole's Vec
+        let expected = mem::size_of::<Geometry<f64>>() + 9 * 2 * mem::size_of::<f64>() + mem::size_of::<LineString<f64>>();
+        assert_eq!(geometry.size_hint_bytes(), expected);
+        // spare capacity is counted
+        let mut reserved = poly.clone();
+        reserved.exterior.0.reserve_exact(10);
+        assert_eq!(Geometry::Polygon(reserved).size_hint_bytes(), expected + 10 * mem::size_of::<Point<f64>>());
+
+        let collection = Geometry::GeometryCollection(GeometryCollection(vec![geometry.clone(), Geometry::Point(Point::new(0., 0.))]));
+        assert_eq!(collection.coord_count(), 10);
+        assert!(collection.size_hint_bytes() > geometry.size_hint_bytes() + mem::size_of::<Geometry<f64>>());
+        assert_eq!(Geometry::MultiPolygon(MultiPolygon(vec![poly.clone(), poly])).coord_count(), 18);
+        assert_eq!(Geometry::Point(Point::new(1., 1.)).size_hint_bytes(), mem::size_of::<Geometry<f64>>());
+    }
+
+    #[test]
+    fn total_turning_angle_test() {
+        use std::f64::consts::PI;
+
+        let square: LineString<f64> = vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into();
+        assert_relative_eq!(square.total_turning_angle(), 2. * PI);
+        let clockwise = LineString(square.0.iter().rev().cloned().collect::<Vec<_>>());
+        assert_relative_eq!(clockwise.total_turning_angle(), -2. * PI);
+        // a repeated vertex doesn't turn
+        let straight: LineString<f64> = vec![(0., 0.), (1., 1.), (1., 1.), (3., 3.)].into();
+        assert_eq!(straight.total_turning_angle(), 0.);
+        // turns either way cancel out
+        let zigzag: LineString<f64> = vec![(0., 0.), (1., 1.), (2., 0.), (3., 1.)].into();
+        assert_relative_eq!(zigzag.total_turning_angle(), 0.);
+        let bend: LineString<f64> = vec![(0., 0.), (1., 1.), (2., 0.)].into();
+        assert_relative_eq!(bend.total_turning_angle(), -PI / 2.);
+        assert_eq!(LineString::<f64>(vec![]).total_turning_angle(), 0.);
+    }
+
+    #[test]
+    fn line_nearest_points_test() {
+        // skew segments, nearest between an endpoint of one and the interior of the other
+        let first = Line::new(Point::new(0., 0.), Point::new(4., 0.));
+        let second = Line::new(Point::new(1., 3.), Point::new(3., 1.));
+        assert_eq!(first.nearest_points(&second), (Point::new(3., 0.), Point::new(3., 1.)));
+        assert_eq!(second.nearest_points(&first), (Point::new(3., 1.), Point::new(3., 0.)));
+        // nearest between a pair of endpoints
+        let third = Line::new(Point::new(5., 1.), Point::new(6., 3.));
+        assert_eq!(first.nearest_points(&third), (Point::new(4., 0.), Point::new(5., 1.)));
+        // crossing
+        let crossing = Line::new(Point::new(1., -1.), Point::new(3., 1.));
+        assert_eq!(first.nearest_points(&crossing), (Point::new(2., 0.), Point::new(2., 0.)));
+        // parallel
+        let parallel = Line::new(Point::new(5., 2.), Point::new(9., 2.));
+        assert_eq!(first.nearest_points(&parallel), (Point::new(4., 0.), Point::new(5., 2.)));
+    }
+
+    #[test]
+    fn shrink_to_fit_test() {
+        let mut ls = LineString((0..100).map(|idx| Point::new(idx as f64, 0.)).collect());
+        ls.0.truncate(3);
+        assert!(ls.0.capacity() > ls.0.len());
+        ls.shrink_to_fit();
+        assert_eq!(ls.0.capacity(), ls.0.len());
+        // recursively, through the rings of a MultiPolygon
+        let mut ring: LineString<f64> = vec![(0., 0.), (1., 0.), (0., 1.), (0., 0.)].into();
+        ring.0.reserve(50);
+        let mut interiors = Vec::with_capacity(10);
+        interiors.push(ring.clone());
+        let mut mp = MultiPolygon(Vec::with_capacity(10));
+        mp.0.push(Polygon::new(ring, interiors));
+        mp.shrink_to_fit();
+        assert_eq!(mp.0.capacity(), 1);
+        assert_eq!(mp.0[0].exterior.0.capacity(), 4);
+        assert_eq!(mp.0[0].interiors.capacity(), 1);
+        assert_eq!(mp.0[0].interiors[0].0.capacity(), 4);
+    }
+
+    #[test]
+    fn array_tuple_conversion_test() {
+        let p: Point<f64> = [1.0, 2.0].into();
+        assert_eq!(p, Point::new(1.0, 2.0));
+        let (x, y): (f64, f64) = p.into();
+        assert_eq!((x, y), (1.0, 2.0));
+        let array: [f64; 2] = p.into();
+        assert_eq!(array, [1.0, 2.0]);
+        let c: Coordinate<f64> = [3.0, 4.0].into();
+        assert_eq!(c, Coordinate { x: 3.0, y: 4.0 });
+        assert_eq!(Into::<(f64, f64)>::into(c), (3.0, 4.0));
+        assert_eq!(Into::<[f64; 2]>::into(c), [3.0, 4.0]);
+        let ls: LineString<f64> = vec![[0., 0.], [1., 1.]].into();
+        assert_eq!(ls.0[1], Point::new(1., 1.));
+    }
 }